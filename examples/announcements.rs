@@ -1,5 +1,5 @@
 use okx::api::announcements::announcements_api::OkxAnnouncements;
-use okx::api::api_trait::OkxApiTrait;
+use okx::api::api_trait::OkxPublicApiTrait;
 use okx::Error;
 #[tokio::main]
 async fn main() -> Result<(), Error> {
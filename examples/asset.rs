@@ -1,5 +1,5 @@
 use okx::config::Credentials;
-use okx::{Error, OkxAsset, OkxClient};
+use okx::{Environment, Error, OkxAsset, OkxClient};
 use okx::api::api_trait::OkxApiTrait;
 #[tokio::main]
 async fn main() -> Result<(), Error> {
@@ -8,7 +8,7 @@ async fn main() -> Result<(), Error> {
         "xxx",
         "xxx",
         "xxx",
-        "0",
+        Environment::Production,
     ); // 初始化客户端
     let client: OkxClient = OkxClient::new(credentials).unwrap();
     //获取asset账户余额
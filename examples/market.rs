@@ -1,12 +1,10 @@
-use okx::api::api_trait::OkxApiTrait;
-use okx::config::Credentials;
-use okx::{Error, OkxClient, OkxMarket};
+use okx::api::api_trait::OkxPublicApiTrait;
+use okx::{Error, OkxMarket, OkxPublicClient};
 #[tokio::main]
 async fn main() -> Result<(), Error> {
     env_logger::init();
-    let credentials = Credentials::from_env().unwrap();
-
-    let client: OkxClient = OkxClient::new(credentials).unwrap();
+    // 行情接口不需要API密钥
+    let client = OkxPublicClient::new()?;
 
     let market = OkxMarket::new(client.clone());
     // 获取BTC-USDT的产品行情
@@ -1,11 +1,11 @@
 use okx::api::api_trait::OkxApiTrait;
 use okx::config::Credentials;
-use okx::{Error, OkxClient, OkxMarket, OkxTrade};
-use serde_json::json;
+use okx::dto::trade::trade_dto::CancelOrderReqDto;
+use okx::{Environment, Error, OkxClient, OkxTrade};
 #[tokio::main]
 async fn main() -> Result<(), Error> {
     env_logger::init();
-    let credentials = Credentials::new("", "", "", ""); // 初始化客户端
+    let credentials = Credentials::new("", "", "", Environment::Demo); // 初始化客户端
     let client: OkxClient = OkxClient::new(credentials).unwrap();
 
     let trade = OkxTrade::new(client.clone());
@@ -25,7 +25,11 @@ async fn main() -> Result<(), Error> {
 
     let okx_order_id = "2671792505662251008111";
     let ticker = trade
-        .cancel_order("BTC-USDT-SWAP", Some(okx_order_id), None)
+        .cancel_order(CancelOrderReqDto {
+            inst_id: "BTC-USDT-SWAP".to_string(),
+            ord_id: Some(okx_order_id.to_string()),
+            cl_ord_id: None,
+        })
         .await;
     println!("order 行情: {:#?}", ticker);
 
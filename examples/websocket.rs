@@ -7,7 +7,7 @@ use tokio::time::sleep;
 async fn main() -> Result<(), Error> {
     env_logger::init();
     let args = Args::new().with_inst_id("BTC-USDT".to_string());
-    let mut client = OkxWebsocketClient::new_public();
+    let mut client = OkxWebsocketClient::new_public()?;
     let mut rx = client.connect().await.unwrap();
     client.subscribe(ChannelType::Tickers, args).await.unwrap();
     tokio::spawn(async move {
@@ -0,0 +1,255 @@
+use crate::api::account::OkxAccount;
+use crate::api::api_trait::OkxApiTrait;
+use crate::api::asset::OkxAsset;
+use crate::api::trade::OkxTrade;
+use crate::client::OkxClient;
+use crate::config::{global_config, Config, Credentials};
+use crate::dto::account::account_dto::Balance;
+use crate::dto::asset::asset_dto::AssetBalance;
+use crate::dto::trade::trade_dto::PositionRespDto;
+use crate::enums::InstrumentType;
+use crate::error::Error;
+use crate::rate_limiter::RateLimiter;
+use futures::future::join_all;
+use std::collections::BTreeMap;
+use std::future::Future;
+
+/// 按账户标签汇总的结果
+pub type PoolResults<T> = BTreeMap<String, Result<T, Error>>;
+
+/// 多账户客户端池
+///
+/// 按标签管理主账户和子账户的客户端，每个账户使用自己的API凭证。通过 `add` 添加的账户共享同一个限速器：
+/// 按User ID限速的接口按各自的API Key分别计算额度，按IP限速的接口共享额度。克隆后共享限速状态和凭证。
+#[derive(Debug, Clone)]
+pub struct AccountPool {
+    accounts: BTreeMap<String, OkxClient>,
+    config: Config,
+    rate_limiter: RateLimiter,
+}
+
+impl AccountPool {
+    /// 创建账户池，使用全局 `CONFIG` 和默认的OKX限速规则，交易环境无效时返回错误
+    pub fn new() -> Result<Self, Error> {
+        Ok(Self::with_config(global_config()?))
+    }
+
+    /// 使用指定配置创建账户池，账户的交易环境以各自的凭证为准
+    pub fn with_config(config: Config) -> Self {
+        Self {
+            accounts: BTreeMap::new(),
+            config,
+            rate_limiter: RateLimiter::default(),
+        }
+    }
+
+    /// 使用配置中所有命名的凭证（`Config::credential_profiles`）创建账户池，标签为配置名
+    pub fn from_config(config: Config) -> Result<Self, Error> {
+        let profiles = config.credential_profiles.clone();
+        let mut pool = Self::with_config(config);
+        for (label, credentials) in profiles {
+            pool.add(label, credentials)?;
+        }
+        Ok(pool)
+    }
+
+    /// 设置之后添加的账户使用的限速器
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// 账户共享的限速器
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// 使用API凭证添加账户，同名账户会被替换
+    pub fn add(&mut self, label: impl Into<String>, credentials: Credentials) -> Result<(), Error> {
+        let client = OkxClient::builder()
+            .config(
+                self.config
+                    .clone()
+                    .with_environment(credentials.environment),
+            )
+            .credentials(credentials)
+            .rate_limiter(self.rate_limiter.clone())
+            .system_proxy(true)
+            .build()?;
+        self.accounts.insert(label.into(), client);
+        Ok(())
+    }
+
+    /// 添加自行构建的客户端（自定义代理、传输层、限速器等），同名账户会被替换
+    pub fn insert_client(&mut self, label: impl Into<String>, client: OkxClient) {
+        self.accounts.insert(label.into(), client);
+    }
+
+    /// 移除账户
+    pub fn remove(&mut self, label: &str) -> Option<OkxClient> {
+        self.accounts.remove(label)
+    }
+
+    /// 是否包含账户
+    pub fn contains(&self, label: &str) -> bool {
+        self.accounts.contains_key(label)
+    }
+
+    /// 所有账户标签，按字典序排列
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.accounts.keys().map(String::as_str)
+    }
+
+    /// 账户数量
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    /// 是否没有任何账户
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    /// 获取账户的客户端
+    pub fn client(&self, label: &str) -> Result<&OkxClient, Error> {
+        self.accounts
+            .get(label)
+            .ok_or_else(|| Error::ConfigError(format!("未找到账户: {}", label)))
+    }
+
+    /// 获取账户的交易API
+    pub fn trade(&self, label: &str) -> Result<OkxTrade, Error> {
+        self.client(label).cloned().map(OkxTrade::new)
+    }
+
+    /// 获取账户的账户API
+    pub fn account(&self, label: &str) -> Result<OkxAccount, Error> {
+        self.client(label).cloned().map(OkxAccount::new)
+    }
+
+    /// 获取账户的资金API
+    pub fn asset(&self, label: &str) -> Result<OkxAsset, Error> {
+        self.client(label).cloned().map(OkxAsset::new)
+    }
+
+    /// 对所有账户并发执行同一个操作，按标签汇总结果；单个账户失败不影响其他账户
+    pub async fn fan_out<T, F, Fut>(&self, f: F) -> PoolResults<T>
+    where
+        F: Fn(OkxClient) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let labels = self.accounts.keys().cloned();
+        let results = join_all(self.accounts.values().cloned().map(f)).await;
+        labels.zip(results).collect()
+    }
+
+    /// 并发查询所有账户的交易账户余额
+    pub async fn get_balances(&self, ccy: Option<&str>) -> PoolResults<Vec<Balance>> {
+        self.fan_out(|client| async move { OkxAccount::new(client).get_balance(ccy).await })
+            .await
+    }
+
+    /// 并发查询所有账户的资金账户余额
+    pub async fn get_asset_balances(
+        &self,
+        ccy: Option<&Vec<String>>,
+    ) -> PoolResults<Vec<AssetBalance>> {
+        self.fan_out(|client| async move { OkxAsset::new(client).get_balances(ccy).await })
+            .await
+    }
+
+    /// 并发查询所有账户的持仓
+    pub async fn get_positions(
+        &self,
+        inst_type: Option<InstrumentType>,
+        inst_id: Option<&str>,
+    ) -> PoolResults<Vec<PositionRespDto>> {
+        self.fan_out(|client| async move {
+            OkxAccount::new(client)
+                .get_positions(inst_type, inst_id, None)
+                .await
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::environment_enums::Environment;
+    use crate::transport::tests::mock_builder;
+    use crate::transport::MockTransport;
+    use reqwest::Method;
+
+    fn account_client(transport: &MockTransport, key: &str) -> OkxClient {
+        mock_builder(transport)
+            .credentials(Credentials::new(
+                key,
+                format!("{}-secret", key),
+                "passphrase",
+                Environment::Production,
+            ))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn fan_out_aggregates_results_per_label() {
+        let transport = MockTransport::new();
+        transport.on_data(
+            Method::GET,
+            "/api/v5/account/positions",
+            serde_json::json!([]),
+        );
+        let mut pool = AccountPool::new().unwrap();
+        pool.insert_client("master", account_client(&transport, "master-key"));
+        pool.insert_client("sub-1", account_client(&transport, "sub-1-key"));
+        pool.insert_client("sub-2", account_client(&MockTransport::new(), "sub-2-key"));
+
+        let results = pool.get_positions(Some(InstrumentType::Swap), None).await;
+
+        assert_eq!(
+            results.keys().map(String::as_str).collect::<Vec<_>>(),
+            vec!["master", "sub-1", "sub-2"]
+        );
+        assert!(results["master"].as_ref().unwrap().is_empty());
+        assert!(results["sub-1"].is_ok());
+        assert!(results["sub-2"].is_err());
+
+        let mut keys: Vec<_> = transport
+            .requests_to("/api/v5/account/positions")
+            .iter()
+            .map(|request| request.header("OK-ACCESS-KEY").unwrap().to_string())
+            .collect();
+        keys.sort();
+        assert_eq!(keys, vec!["master-key", "sub-1-key"]);
+    }
+
+    #[test]
+    fn accounts_are_created_from_credential_profiles() {
+        let config = Config::for_environment(Environment::Demo)
+            .with_credential_profile(
+                "main",
+                Credentials::new("k1", "s1", "p1", Environment::Demo),
+            )
+            .with_credential_profile(
+                "live",
+                Credentials::new("k2", "s2", "p2", Environment::Production),
+            );
+        let pool = AccountPool::from_config(config).unwrap();
+
+        assert_eq!(pool.labels().collect::<Vec<_>>(), vec!["live", "main"]);
+        assert_eq!(
+            pool.client("main").unwrap().environment(),
+            Environment::Demo
+        );
+        assert_eq!(
+            pool.trade("live").unwrap().client().credentials().api_key,
+            "k2"
+        );
+        assert!(matches!(
+            pool.account("missing"),
+            Err(Error::ConfigError(_))
+        ));
+    }
+}
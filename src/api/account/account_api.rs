@@ -1,12 +1,17 @@
 use crate::api::api_trait::OkxApiTrait;
 use crate::api::API_ACCOUNT_PATH;
-use crate::client::OkxClient;
+use crate::client::{OkxClient, ResponseMeta};
 use crate::dto::account::account_dto::{
-    AccountConfig, AccountRisk, Balance, Position, SetLeverageRequest, TradingSwapNumResponseData,
+    AccountConfig, AccountRisk, Balance, BillRespDto, BillsReqDto, Position, SetLeverageData,
+    SetLeverageRequest, TradingSwapNumResponseData,
 };
 use crate::dto::trade::trade_dto::PositionRespDto;
+use crate::enums::{InstrumentType, TdMode};
 use crate::error::Error;
+use crate::pagination::{paginate, PageOptions};
+use futures::stream::BoxStream;
 use reqwest::Method;
+use serde::Serialize;
 
 /// OKX账户API
 /// 提供账户相关的API访问
@@ -24,48 +29,63 @@ impl OkxApiTrait for OkxAccount {
         &self.client
     }
 }
+/// 账户查询参数
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct AccountQuery<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inst_type: Option<InstrumentType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inst_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pos_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    td_mode: Option<TdMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ccy: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    px: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    leverage: Option<&'a str>,
+}
+
 impl OkxAccount {
     /// 查询账户余额
     pub async fn get_balance(&self, ccy: Option<&str>) -> Result<Vec<Balance>, Error> {
-        let mut path = format!("{}/balance", API_ACCOUNT_PATH);
-
-        if let Some(currency) = ccy {
-            path.push_str(&format!("?ccy={}", currency));
-        }
+        self.get_balance_with_meta(ccy).await.map(|(data, _)| data)
+    }
 
+    /// 查询账户余额，同时返回响应元数据
+    pub async fn get_balance_with_meta(
+        &self,
+        ccy: Option<&str>,
+    ) -> Result<(Vec<Balance>, ResponseMeta), Error> {
+        let path = format!("{}/balance", API_ACCOUNT_PATH);
+        let query = AccountQuery {
+            ccy,
+            ..Default::default()
+        };
         self.client
-            .send_request::<Vec<Balance>>(Method::GET, &path, "")
+            .send_get_with_meta::<Vec<Balance>, _>(&path, &query)
             .await
     }
 
     /// 查询持仓信息
     pub async fn get_positions(
         &self,
-        inst_type: Option<&str>,
+        inst_type: Option<InstrumentType>,
         inst_id: Option<&str>,
         pos_id: Option<&str>,
     ) -> Result<Vec<PositionRespDto>, Error> {
-        let mut path = format!("{}/positions", API_ACCOUNT_PATH);
-        let mut query_params = vec![];
-
-        if let Some(it) = inst_type {
-            query_params.push(format!("instType={}", it));
-        }
-
-        if let Some(id) = inst_id {
-            query_params.push(format!("instId={}", id));
-        }
-
-        if let Some(pid) = pos_id {
-            query_params.push(format!("posId={}", pid));
-        }
-
-        if !query_params.is_empty() {
-            path.push_str(&format!("?{}", query_params.join("&")));
-        }
-
+        let path = format!("{}/positions", API_ACCOUNT_PATH);
+        let query = AccountQuery {
+            inst_type,
+            inst_id,
+            pos_id,
+            ..Default::default()
+        };
         self.client
-            .send_request::<Vec<PositionRespDto>>(Method::GET, &path, "")
+            .send_get::<Vec<PositionRespDto>, _>(&path, &query)
             .await
     }
 
@@ -81,12 +101,11 @@ impl OkxAccount {
     pub async fn set_leverage(
         &self,
         params: SetLeverageRequest,
-    ) -> Result<serde_json::Value, Error> {
+    ) -> Result<Vec<SetLeverageData>, Error> {
         let path = format!("{}/set-leverage", API_ACCOUNT_PATH);
-
         let body_str = serde_json::to_string(&params).map_err(Error::JsonError)?;
         self.client
-            .send_request::<serde_json::Value>(Method::POST, &path, &body_str)
+            .send_request::<Vec<SetLeverageData>>(Method::POST, &path, &body_str)
             .await
     }
 
@@ -94,30 +113,22 @@ impl OkxAccount {
     pub async fn get_max_size(
         &self,
         inst_id: &str,
-        td_mode: &str,
+        td_mode: TdMode,
         ccy: Option<&str>,
         px: Option<&str>,
         leverage: Option<&str>,
     ) -> Result<Vec<TradingSwapNumResponseData>, Error> {
-        let mut path = format!(
-            "{}/max-size?instId={}&tdMode={}",
-            API_ACCOUNT_PATH, inst_id, td_mode
-        );
-
-        if let Some(currency) = ccy {
-            path.push_str(&format!("&ccy={}", currency));
-        }
-
-        if let Some(price) = px {
-            path.push_str(&format!("&px={}", price));
-        }
-
-        if let Some(lev) = leverage {
-            path.push_str(&format!("&leverage={}", lev));
-        }
-
+        let path = format!("{}/max-size", API_ACCOUNT_PATH);
+        let query = AccountQuery {
+            inst_id: Some(inst_id),
+            td_mode: Some(td_mode),
+            ccy,
+            px,
+            leverage,
+            ..Default::default()
+        };
         self.client
-            .send_request::<Vec<TradingSwapNumResponseData>>(Method::GET, &path, "")
+            .send_get::<Vec<TradingSwapNumResponseData>, _>(&path, &query)
             .await
     }
 
@@ -129,87 +140,65 @@ impl OkxAccount {
             .await
     }
 
-    /// 获取账户账单
-    pub async fn get_bills(
-        &self,
-        inst_type: Option<&str>,
-        ccy: Option<&str>,
-        margin_mode: Option<&str>,
-        typ: Option<&str>,
-        start_time: Option<&str>,
-        end_time: Option<&str>,
-        limit: Option<u32>,
-    ) -> Result<serde_json::Value, Error> {
-        let mut path = format!("{}/bills", API_ACCOUNT_PATH);
-        let mut query_params = vec![];
-
-        if let Some(it) = inst_type {
-            query_params.push(format!("instType={}", it));
-        }
-
-        if let Some(currency) = ccy {
-            query_params.push(format!("ccy={}", currency));
-        }
-
-        if let Some(mode) = margin_mode {
-            query_params.push(format!("mgnMode={}", mode));
-        }
-
-        if let Some(t) = typ {
-            query_params.push(format!("type={}", t));
-        }
-
-        if let Some(st) = start_time {
-            query_params.push(format!("begin={}", st));
-        }
-
-        if let Some(et) = end_time {
-            query_params.push(format!("end={}", et));
-        }
-
-        if let Some(l) = limit {
-            query_params.push(format!("limit={}", l));
-        }
-
-        if !query_params.is_empty() {
-            path.push_str(&format!("?{}", query_params.join("&")));
-        }
-
+    /// 获取账户账单（近七天）
+    /// 限速：5次/s
+    /// 限速规则：User ID
+    pub async fn get_bills(&self, params: &BillsReqDto) -> Result<Vec<BillRespDto>, Error> {
+        let path = format!("{}/bills", API_ACCOUNT_PATH);
         self.client
-            .send_request::<serde_json::Value>(Method::GET, &path, "")
+            .send_get::<Vec<BillRespDto>, _>(&path, params)
             .await
     }
 
+    /// 自动翻页获取账户账单（近七天），按时间从新到旧逐条返回
+    ///
+    /// `params` 中的 `after` / `limit` 由翻页选项决定；设置了时间范围时同时作为 `begin` / `end` 查询条件。
+    /// 限速：5次/s
+    /// 限速规则：User ID
+    pub fn get_bills_stream(
+        &self,
+        params: BillsReqDto,
+        options: PageOptions,
+    ) -> BoxStream<'static, Result<BillRespDto, Error>> {
+        let client = self.client.clone();
+        let path = format!("{}/bills", API_ACCOUNT_PATH);
+        // 时间范围同时作为查询条件，减少需要翻的页数
+        let params = BillsReqDto {
+            begin: options.since_ms.map(|ts| ts.to_string()).or(params.begin),
+            end: options.until_ms.map(|ts| ts.to_string()).or(params.end),
+            before: None,
+            ..params
+        };
+        paginate(options, move |after, limit| {
+            let client = client.clone();
+            let path = path.clone();
+            let query = BillsReqDto {
+                after,
+                limit,
+                ..params.clone()
+            };
+            async move { client.send_get::<Vec<BillRespDto>, _>(&path, &query).await }
+        })
+    }
+
     /// 获取账户持仓信息
     /// 限速：10次/2s
     // 限速规则：User ID
     pub async fn get_account_positions(
         &self,
-        inst_type: Option<&str>,
+        inst_type: Option<InstrumentType>,
         inst_id: Option<&str>,
         pos_id: Option<&str>,
     ) -> Result<Vec<Position>, Error> {
-        let mut path = format!("{}/positions", API_ACCOUNT_PATH);
-        let mut query_params = vec![];
-
-        if let Some(it) = inst_type {
-            query_params.push(format!("instType={}", it));
-        }
-
-        if let Some(id) = inst_id {
-            query_params.push(format!("instId={}", id));
-        }
-
-        if let Some(pid) = pos_id {
-            query_params.push(format!("posId={}", pid));
-        }
-
-        if !query_params.is_empty() {
-            path.push_str(&format!("?{}", query_params.join("&")));
-        }
-
+        let path = format!("{}/positions", API_ACCOUNT_PATH);
+        let query = AccountQuery {
+            inst_type,
+            inst_id,
+            pos_id,
+            ..Default::default()
+        };
         self.client
-            .send_request::<Vec<Position>>(Method::GET, &path, "")
+            .send_get::<Vec<Position>, _>(&path, &query)
             .await
     }
 }
@@ -1,6 +1,7 @@
-use crate::config::Credentials;
-use crate::{Error, OkxClient};
-use reqwest::Method;
+use crate::api::api_trait::OkxPublicApiTrait;
+use crate::api::API_BIGDATA_PATH;
+use crate::client::OkxPublicClient;
+use crate::enums::Bar;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -10,55 +11,56 @@ pub struct VolumeData {
     pub vol: String, //交易总量（USD）
 }
 
+/// 持仓量及交易量查询参数
+#[derive(Serialize)]
+struct OpenInterestVolumeQuery<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ccy: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    begin: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    period: Option<Bar>,
+}
+
 pub struct OkxContracts {
-    client: OkxClient,
+    client: OkxPublicClient,
 }
 
-impl OkxContracts {
-    /// 创建一个新的OkxAccount实例，使用给定的客户端
-    pub fn new() -> Self {
+impl OkxPublicApiTrait for OkxContracts {
+    /// 创建一个新的OkxContracts实例
+    fn new(client: impl Into<OkxPublicClient>) -> Self {
         Self {
-            // 创建客户端
-            client: OkxClient::new(Credentials::new("api_key", "api_secret", "passphrase", "0"))
-                .unwrap(),
+            client: client.into(),
         }
     }
-
-    /// 从环境变量创建一个新的OkxAccount实例
-    pub fn from_env() -> Result<Self, Error> {
-        let client = OkxClient::from_env()?;
-        Ok(Self { client })
-    }
-
     /// 获取内部客户端引用
-    pub fn client(&self) -> &OkxClient {
+    fn client(&self) -> &OkxPublicClient {
         &self.client
     }
+}
+
+impl OkxContracts {
     //获取未平仓合约的持仓量和交易总量
     pub async fn get_open_interest_volume(
         &self,
         ccy: Option<&str>,
         begin: Option<i64>,
         end: Option<i64>,
-        period: Option<&str>,
+        period: Option<Bar>,
     ) -> anyhow::Result<Vec<VolumeData>, anyhow::Error> {
-        let mut path = "/api/v5/rubik/stat/contracts/open-interest-volume?".to_string();
-        if let Some(ccy) = ccy {
-            path.push_str(&format!("&ccy={}", ccy));
-        }
-
-        if let Some(begin) = begin {
-            path.push_str(&format!("&begin={}", begin));
-        }
-        if let Some(end) = end {
-            path.push_str(&format!("&end={}", end));
-        }
-
-        if let Some(period) = period {
-            path.push_str(&format!("&period={}", period));
-        }
-
-        let res = self.client.send_request(Method::GET, &path, "").await?;
+        let path = format!("{}/stat/contracts/open-interest-volume", API_BIGDATA_PATH);
+        let query = OpenInterestVolumeQuery {
+            ccy,
+            begin,
+            end,
+            period,
+        };
+        let res = self
+            .client
+            .send_get::<Vec<VolumeData>, _>(&path, &query)
+            .await?;
         Ok(res)
     }
 }
@@ -1,8 +1,8 @@
-use crate::api::api_trait::OkxApiTrait;
-use crate::client::OkxClient;
+use crate::api::api_trait::OkxPublicApiTrait;
+use crate::api::API_ANNOUNCEMENTS_PATH;
+use crate::client::OkxPublicClient;
 use crate::enums::language_enums::Language;
 use crate::Error;
-use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 /// 公告详情
@@ -24,29 +24,35 @@ pub struct AnnouncementPage {
     pub total_page: String,
 }
 
+/// 公告查询参数
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AnnouncementsQuery<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ann_type: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<&'a str>,
+}
+
 /// OKX 公告 API
 pub struct OkxAnnouncements {
-    client: OkxClient,
+    client: OkxPublicClient,
 }
 
-impl OkxApiTrait for OkxAnnouncements {
-    fn new(client: OkxClient) -> Self {
-        Self { client }
+impl OkxPublicApiTrait for OkxAnnouncements {
+    /// 创建一个新的OkxAnnouncements实例
+    fn new(client: impl Into<OkxPublicClient>) -> Self {
+        Self {
+            client: client.into(),
+        }
     }
-
-    fn client(&self) -> &OkxClient {
+    /// 获取内部客户端引用
+    fn client(&self) -> &OkxPublicClient {
         &self.client
     }
 }
 
 impl OkxAnnouncements {
-    /// 从环境变量创建实例
-
-    pub fn from_env() -> Result<Self, Error> {
-        let client = OkxClient::from_env()?;
-        Ok(Self { client })
-    }
-
     /// 获取公告
     /// 获取公告信息，以发布时间倒序排序，公告更新不会影响排序。每页默认有 20 条公告
     /// 请求头中 Accept-Language 设置为 en-US 时返回英文公告；设置为 zh-CN 时返回中文公告
@@ -61,27 +67,18 @@ impl OkxAnnouncements {
         page: Option<String>,
         language: Option<Language>,
     ) -> Result<Vec<AnnouncementPage>, Error> {
-        let path = "/api/v5/support/announcements";
-        let mut body = String::new();
-        if let Some(t) = ann_type {
-            body.push_str(&format!("annType={}", t));
-        }
-        if let Some(p) = page {
-            if !body.is_empty() {
-                body.push('&');
-            }
-            body.push_str(&format!("page={}", p));
-        }
+        let query = AnnouncementsQuery {
+            ann_type: ann_type.as_deref(),
+            page: page.as_deref(),
+        };
 
         let mut client = self.client.clone();
         if let Some(l) = language {
             client.set_accept_language(l);
         }
-        println!("body: {}", body);
         let res = client
-            .send_request::<Vec<AnnouncementPage>>(Method::GET, path, &body)
+            .send_get::<Vec<AnnouncementPage>, _>(API_ANNOUNCEMENTS_PATH, &query)
             .await?;
-        println!("res: {:?}", res);
         Ok(res)
     }
 }
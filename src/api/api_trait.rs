@@ -1,22 +1,27 @@
-use crate::client::OkxClient;
+use crate::client::{OkxClient, OkxPublicClient};
+use crate::enums::environment_enums::Environment;
 use crate::error::Error;
 use anyhow::Result;
-use std::env;
 
 pub trait OkxApiTrait {
+    /// 使用OKX客户端创建API实例
+    /// 客户端可以基于默认的reqwest传输层，也可以通过 `OkxClient::builder().transport(..)` 使用 `MockTransport` 等自定义传输层
     fn new(client: OkxClient) -> Self
     where
         Self: Sized;
 
+    /// 从环境变量创建API实例
+    /// 环境由 `OKX_ENVIRONMENT` 决定，值无效时返回错误，未设置时使用模拟盘（兼容已弃用的 `APP_ENV=prod`）：
+    /// 模拟盘读取 `OKX_SIMULATED_*` 凭证，其他环境读取 `OKX_API_KEY` 等凭证
     fn from_env() -> Result<Self, Error>
     where
         Self: Sized,
     {
-        let is_simulated_trading = env::var("APP_ENV").unwrap_or("local".to_string());
-        let client = if is_simulated_trading == "prod" {
-            OkxClient::from_env()?
-        } else {
+        let environment = Environment::from_env_or_legacy()?;
+        let client = if environment.is_demo() {
             OkxClient::from_env_with_simulated_trading()?
+        } else {
+            OkxClient::from_env()?
         };
         Ok(Self::new(client))
     }
@@ -24,3 +29,24 @@ pub trait OkxApiTrait {
     // fn from_env() -> Result<Self, Error> where Self: Sized;
     fn client(&self) -> &OkxClient;
 }
+
+/// 公共接口API，不需要API密钥
+///
+/// 可以使用匿名的 `OkxPublicClient`，也可以传入 `OkxClient`（请求会签名）。
+/// 需要认证的API只实现 `OkxApiTrait`，不能由匿名客户端创建。
+pub trait OkxPublicApiTrait {
+    /// 使用公共接口客户端创建API实例
+    fn new(client: impl Into<OkxPublicClient>) -> Self
+    where
+        Self: Sized;
+
+    /// 创建匿名的API实例，地址和交易环境由全局 `CONFIG`（环境变量）决定，不读取API密钥
+    fn from_env() -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        Ok(Self::new(OkxPublicClient::new()?))
+    }
+
+    fn client(&self) -> &OkxPublicClient;
+}
@@ -1,11 +1,16 @@
 use crate::api::api_trait::OkxApiTrait;
 use crate::api::API_ASSET_PATH;
 use crate::client::OkxClient;
-use crate::dto::asset::asset_dto::TransferOkxReqDto;
-use crate::dto::asset::asset_dto::{AssetBalance, DepositRecord, TransferRecord, WithdrawalRecord};
+use crate::dto::asset::asset_dto::{
+    AssetBalance, CurrencyInfo, DepositAddress, DepositRecord, TransferOkxReqDto, TransferRecord,
+    TransferRespDto, TransferStateReqDto, WithdrawalRecord, WithdrawalReqDto, WithdrawalRespDto,
+};
+use crate::dto::common::PageItem;
 use crate::error::Error;
+use crate::pagination::{paginate, PageOptions};
+use futures::stream::BoxStream;
 use reqwest::Method;
-use serde_json::json;
+use serde::{Deserialize, Serialize};
 
 /// OKX资产API
 /// 提供资产相关的API访问
@@ -28,6 +33,24 @@ impl OkxApiTrait for OkxAsset {
     }
 }
 
+/// 资产查询参数
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct AssetQuery<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ccy: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tx_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+}
+
 impl OkxAsset {
     // /// 获取资产余额
     // pub async fn get_balances(
@@ -49,32 +72,25 @@ impl OkxAsset {
     ) -> Result<Vec<AssetBalance>, Error> {
         // 币种，如 BTC
         // 支持多币种查询（不超过20个），币种之间半角逗号分隔
-        let mut path = format!("{}/balances?", API_ASSET_PATH);
-        if !ccy.is_none() {
-            let ccy_param = ccy.unwrap().join(",");
-            if !ccy_param.is_empty() {
-                path.push_str(&format!("&ccy={}", ccy_param));
-            }
-        }
+        let path = format!("{}/balances", API_ASSET_PATH);
+        let ccy = ccy.map(|list| list.join(",")).filter(|c| !c.is_empty());
+        let query = AssetQuery {
+            ccy: ccy.as_deref(),
+            ..Default::default()
+        };
         self.client
-            .send_request::<Vec<AssetBalance>>(Method::GET, &path, "")
+            .send_get::<Vec<AssetBalance>, _>(&path, &query)
             .await
     }
 
     /// 获取资金划转状态
     pub async fn get_transfer_state(
         &self,
-        trans_id: &str,
-        type_param: Option<&str>,
+        params: &TransferStateReqDto,
     ) -> Result<Vec<TransferRecord>, Error> {
-        let mut path = format!("{}/transfer-state?transId={}", API_ASSET_PATH, trans_id);
-
-        if let Some(t) = type_param {
-            path.push_str(&format!("&type={}", t));
-        }
-
+        let path = format!("{}/transfer-state", API_ASSET_PATH);
         self.client
-            .send_request::<Vec<TransferRecord>>(Method::GET, &path, "")
+            .send_get::<Vec<TransferRecord>, _>(&path, params)
             .await
     }
 
@@ -82,47 +98,23 @@ impl OkxAsset {
     pub async fn transfer(
         &self,
         transfer_req: &TransferOkxReqDto,
-    ) -> Result<serde_json::Value, Error> {
+    ) -> Result<Vec<TransferRespDto>, Error> {
         let path = format!("{}/transfer", API_ASSET_PATH);
-        let body = json!(transfer_req);
-        println!("body: {:?}", body);
-        let body_str = serde_json::to_string(&body).map_err(Error::JsonError)?;
+        let body_str = serde_json::to_string(transfer_req).map_err(Error::JsonError)?;
         self.client
-            .send_request::<serde_json::Value>(Method::POST, &path, &body_str)
+            .send_request::<Vec<TransferRespDto>>(Method::POST, &path, &body_str)
             .await
     }
+
     /// 提币
     pub async fn withdrawal(
         &self,
-        ccy: &str,
-        amt: &str,
-        dest: &str,
-        to_addr: &str,
-        fee: &str,
-        chain: Option<&str>,
-        areal_name: Option<&str>,
-    ) -> Result<serde_json::Value, Error> {
+        params: &WithdrawalReqDto,
+    ) -> Result<Vec<WithdrawalRespDto>, Error> {
         let path = format!("{}/withdrawal", API_ASSET_PATH);
-
-        let mut body = json!({
-            "ccy": ccy,
-            "amt": amt,
-            "dest": dest,
-            "toAddr": to_addr,
-            "fee": fee,
-        });
-
-        if let Some(c) = chain {
-            body["chain"] = json!(c);
-        }
-
-        if let Some(name) = areal_name {
-            body["arealName"] = json!(name);
-        }
-
-        let body_str = serde_json::to_string(&body).map_err(Error::JsonError)?;
+        let body_str = serde_json::to_string(params).map_err(Error::JsonError)?;
         self.client
-            .send_request::<serde_json::Value>(Method::POST, &path, &body_str)
+            .send_request::<Vec<WithdrawalRespDto>>(Method::POST, &path, &body_str)
             .await
     }
 
@@ -136,39 +128,17 @@ impl OkxAsset {
         before: Option<&str>,
         limit: Option<u32>,
     ) -> Result<Vec<WithdrawalRecord>, Error> {
-        let mut path = format!("{}/withdrawal-history", API_ASSET_PATH);
-        let mut query_params = vec![];
-
-        if let Some(currency) = ccy {
-            query_params.push(format!("ccy={}", currency));
-        }
-
-        if let Some(id) = tx_id {
-            query_params.push(format!("txId={}", id));
-        }
-
-        if let Some(s) = state {
-            query_params.push(format!("state={}", s));
-        }
-
-        if let Some(a) = after {
-            query_params.push(format!("after={}", a));
-        }
-
-        if let Some(b) = before {
-            query_params.push(format!("before={}", b));
-        }
-
-        if let Some(l) = limit {
-            query_params.push(format!("limit={}", l));
-        }
-
-        if !query_params.is_empty() {
-            path.push_str(&format!("?{}", query_params.join("&")));
-        }
-
+        let path = format!("{}/withdrawal-history", API_ASSET_PATH);
+        let query = AssetQuery {
+            ccy,
+            tx_id,
+            state,
+            after,
+            before,
+            limit,
+        };
         self.client
-            .send_request::<Vec<WithdrawalRecord>>(Method::GET, &path, "")
+            .send_get::<Vec<WithdrawalRecord>, _>(&path, &query)
             .await
     }
 
@@ -182,55 +152,93 @@ impl OkxAsset {
         before: Option<&str>,
         limit: Option<u32>,
     ) -> Result<Vec<DepositRecord>, Error> {
-        let mut path = format!("{}/deposit-history", API_ASSET_PATH);
-        let mut query_params = vec![];
-
-        if let Some(currency) = ccy {
-            query_params.push(format!("ccy={}", currency));
-        }
-
-        if let Some(id) = tx_id {
-            query_params.push(format!("txId={}", id));
-        }
-
-        if let Some(s) = state {
-            query_params.push(format!("state={}", s));
-        }
-
-        if let Some(a) = after {
-            query_params.push(format!("after={}", a));
-        }
-
-        if let Some(b) = before {
-            query_params.push(format!("before={}", b));
-        }
+        let path = format!("{}/deposit-history", API_ASSET_PATH);
+        let query = AssetQuery {
+            ccy,
+            tx_id,
+            state,
+            after,
+            before,
+            limit,
+        };
+        self.client
+            .send_get::<Vec<DepositRecord>, _>(&path, &query)
+            .await
+    }
 
-        if let Some(l) = limit {
-            query_params.push(format!("limit={}", l));
-        }
+    /// 自动翻页获取提币历史，按时间从新到旧逐条返回
+    pub fn get_withdrawal_history_stream(
+        &self,
+        ccy: Option<&str>,
+        tx_id: Option<&str>,
+        state: Option<&str>,
+        options: PageOptions,
+    ) -> BoxStream<'static, Result<WithdrawalRecord, Error>> {
+        self.history_stream("withdrawal-history", ccy, tx_id, state, options)
+    }
 
-        if !query_params.is_empty() {
-            path.push_str(&format!("?{}", query_params.join("&")));
-        }
+    /// 自动翻页获取充值历史，按时间从新到旧逐条返回
+    pub fn get_deposit_history_stream(
+        &self,
+        ccy: Option<&str>,
+        tx_id: Option<&str>,
+        state: Option<&str>,
+        options: PageOptions,
+    ) -> BoxStream<'static, Result<DepositRecord, Error>> {
+        self.history_stream("deposit-history", ccy, tx_id, state, options)
+    }
 
-        self.client
-            .send_request::<Vec<DepositRecord>>(Method::GET, &path, "")
-            .await
+    fn history_stream<T>(
+        &self,
+        endpoint: &str,
+        ccy: Option<&str>,
+        tx_id: Option<&str>,
+        state: Option<&str>,
+        options: PageOptions,
+    ) -> BoxStream<'static, Result<T, Error>>
+    where
+        T: for<'a> Deserialize<'a> + Serialize + PageItem + Send + 'static,
+    {
+        let client = self.client.clone();
+        let path = format!("{}/{}", API_ASSET_PATH, endpoint);
+        let ccy = ccy.map(str::to_string);
+        let tx_id = tx_id.map(str::to_string);
+        let state = state.map(str::to_string);
+        paginate(options, move |after, limit| {
+            let client = client.clone();
+            let path = path.clone();
+            let (ccy, tx_id, state) = (ccy.clone(), tx_id.clone(), state.clone());
+            async move {
+                let query = AssetQuery {
+                    ccy: ccy.as_deref(),
+                    tx_id: tx_id.as_deref(),
+                    state: state.as_deref(),
+                    after: after.as_deref(),
+                    limit,
+                    ..Default::default()
+                };
+                client.send_get::<Vec<T>, _>(&path, &query).await
+            }
+        })
     }
 
     /// 获取充值地址
-    pub async fn get_deposit_address(&self, ccy: &str) -> Result<serde_json::Value, Error> {
-        let path = format!("{}/deposit-address?ccy={}", API_ASSET_PATH, ccy);
+    pub async fn get_deposit_address(&self, ccy: &str) -> Result<Vec<DepositAddress>, Error> {
+        let path = format!("{}/deposit-address", API_ASSET_PATH);
+        let query = AssetQuery {
+            ccy: Some(ccy),
+            ..Default::default()
+        };
         self.client
-            .send_request::<serde_json::Value>(Method::GET, &path, "")
+            .send_get::<Vec<DepositAddress>, _>(&path, &query)
             .await
     }
 
     /// 获取币种列表
-    pub async fn get_currencies(&self) -> Result<serde_json::Value, Error> {
+    pub async fn get_currencies(&self) -> Result<Vec<CurrencyInfo>, Error> {
         let path = format!("{}/currencies", API_ASSET_PATH);
         self.client
-            .send_request::<serde_json::Value>(Method::GET, &path, "")
+            .send_request::<Vec<CurrencyInfo>>(Method::GET, &path, "")
             .await
     }
 }
@@ -239,6 +247,7 @@ impl OkxAsset {
 mod tests {
     use super::*;
     use crate::enums::account_enums::AccountType;
+    use crate::enums::WithdrawalDest;
     #[tokio::test]
     async fn test_get_balances() {
         let asset = OkxAsset::from_env().expect("无法从环境变量创建资产API");
@@ -256,10 +265,58 @@ mod tests {
             from: AccountType::FOUND,
             to: AccountType::TRADE,
             sub_acct: None,
-            // loan_trans: None,
-            // omit_pos_risk: None,
+            loan_trans: None,
+            omit_pos_risk: None,
+            client_id: None,
         };
         let result = asset.transfer(&transfer_req).await;
         println!("资金划转结果: {:?}", result);
     }
+
+    #[tokio::test]
+    async fn withdrawal_and_transfer_state_use_typed_params() {
+        use crate::transport::tests::mock_client;
+        use crate::transport::MockTransport;
+
+        let transport = MockTransport::new();
+        transport.on_data(
+            Method::POST,
+            "/api/v5/asset/withdrawal",
+            serde_json::json!([{"ccy":"USDT","chain":"USDT-TRC20","amt":"10","wdId":"67485","clientId":""}]),
+        );
+        transport.on_data(
+            Method::GET,
+            "/api/v5/asset/transfer-state?transId=754147",
+            serde_json::json!([{"amt":"1.5","ccy":"USDT","clientId":"","from":"18","instId":"","state":"success","subAcct":"test","to":"6","toInstId":"","transId":"754147","type":"1"}]),
+        );
+        let client = mock_client(&transport);
+        let asset = OkxAsset::new(client);
+
+        let withdrawals = asset
+            .withdrawal(&WithdrawalReqDto {
+                ccy: "USDT".to_string(),
+                amt: "10".to_string(),
+                dest: WithdrawalDest::OnChain,
+                to_addr: "TXaddr".to_string(),
+                chain: Some("USDT-TRC20".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(withdrawals[0].wd_id, "67485");
+        assert_eq!(
+            transport.last_request().unwrap().body,
+            r#"{"ccy":"USDT","amt":"10","dest":"4","toAddr":"TXaddr","chain":"USDT-TRC20"}"#
+        );
+
+        let states = asset
+            .get_transfer_state(&TransferStateReqDto {
+                trans_id: Some("754147".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(states[0].state, "success");
+        assert_eq!(states[0].transfer_type, "1");
+    }
 }
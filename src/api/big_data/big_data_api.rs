@@ -1,33 +1,58 @@
 use reqwest::Method;
+use serde::Serialize;
 
-use crate::api::api_trait::OkxApiTrait;
-use crate::client::{OkxApiResponse, OkxClient};
+use crate::api::api_trait::OkxPublicApiTrait;
+use crate::api::API_BIGDATA_PATH;
+use crate::client::{OkxApiResponse, OkxPublicClient};
 use crate::dto::big_data::*;
+use crate::enums::{Bar, InstrumentType};
 use crate::Error;
 
 pub struct OkxBigData {
-    client: OkxClient,
+    client: OkxPublicClient,
 }
 
-impl OkxApiTrait for OkxBigData {
-    fn new(client: OkxClient) -> Self {
-        OkxBigData { client }
-    }
-    fn from_env() -> Result<Self, Error> {
-        let client = OkxClient::from_env()?;
-        Ok(OkxBigData::new(client))
+impl OkxPublicApiTrait for OkxBigData {
+    /// 创建一个新的OkxBigData实例
+    fn new(client: impl Into<OkxPublicClient>) -> Self {
+        Self {
+            client: client.into(),
+        }
     }
-    fn client(&self) -> &OkxClient {
+    /// 获取内部客户端引用
+    fn client(&self) -> &OkxPublicClient {
         &self.client
     }
 }
 
+/// 交易大数据查询参数
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct BigDataQuery<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ccy: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inst_type: Option<InstrumentType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inst_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    period: Option<Bar>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    begin: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<&'a str>,
+}
+
 impl OkxBigData {
     //获取交易大数据支持币种
     pub async fn get_support_coin(&self) -> Result<OkxApiResponse<SupportCoin>, Error> {
         // 币种，如 BTC
         // 支持多币种查询（不超过20个），币种之间半角逗号分隔
-        let path = "/api/v5/rubik/stat/trading-data/support-coin?".to_string();
+        let path = format!("{}/stat/trading-data/support-coin", API_BIGDATA_PATH);
         self.client.send_request(Method::GET, &path, "").await
     }
 
@@ -42,27 +67,21 @@ impl OkxBigData {
     pub async fn get_taker_volume(
         &self,
         ccy: &str,
-        inst_type: &str,
+        inst_type: InstrumentType,
         begin: Option<&str>,
         end: Option<&str>,
-        period: Option<&str>,
+        period: Option<Bar>,
     ) -> Result<Vec<Vec<String>>, Error> {
-        let mut path = format!(
-            "/api/v5/rubik/stat/taker-volume?ccy={}&instType={}",
-            ccy, inst_type
-        );
-
-        if let Some(begin_time) = begin {
-            path.push_str(&format!("&begin={}", begin_time));
-        }
-        if let Some(end_time) = end {
-            path.push_str(&format!("&end={}", end_time));
-        }
-        if let Some(period_value) = period {
-            path.push_str(&format!("&period={}", period_value));
-        }
-
-        self.client.send_request(Method::GET, &path, "").await
+        let path = format!("{}/stat/taker-volume", API_BIGDATA_PATH);
+        let query = BigDataQuery {
+            ccy: Some(ccy),
+            inst_type: Some(inst_type),
+            begin,
+            end,
+            period,
+            ..Default::default()
+        };
+        self.client.send_get(&path, &query).await
     }
 
     // 获取合约主动买入/卖出情况
@@ -74,32 +93,23 @@ impl OkxBigData {
     pub async fn get_taker_volume_contract(
         &self,
         inst_id: &str,
-        period: Option<&str>,
+        period: Option<Bar>,
         unit: Option<&str>,
         begin: Option<&str>,
         end: Option<&str>,
         limit: Option<&str>,
     ) -> Result<Vec<Vec<String>>, Error> {
-        let mut path = format!(
-            "/api/v5/rubik/stat/taker-volume-contract?instId={}",
-            inst_id
-        );
-        if let Some(period_value) = period {
-            path.push_str(&format!("&period={}", period_value));
-        }
-        if let Some(unit_value) = unit {
-            path.push_str(&format!("&unit={}", unit_value));
-        }
-        if let Some(begin_time) = begin {
-            path.push_str(&format!("&begin={}", begin_time));
-        }
-        if let Some(end_time) = end {
-            path.push_str(&format!("&end={}", end_time));
-        }
-        if let Some(limit_value) = limit {
-            path.push_str(&format!("&limit={}", limit_value));
-        }
-        self.client.send_request(Method::GET, &path, "").await
+        let path = format!("{}/stat/taker-volume-contract", API_BIGDATA_PATH);
+        let query = BigDataQuery {
+            inst_id: Some(inst_id),
+            period,
+            unit,
+            begin,
+            end,
+            limit,
+            ..Default::default()
+        };
+        self.client.send_get(&path, &query).await
     }
 
     //获取精英交易员合约多空持仓人数比
@@ -110,30 +120,24 @@ impl OkxBigData {
     pub async fn get_long_short_account_ratio_contract_top_trader(
         &self,
         inst_id: &str,
-        period: Option<&str>,
+        period: Option<Bar>,
         begin: Option<&str>,
         end: Option<&str>,
         limit: Option<&str>,
     ) -> Result<Vec<Vec<String>>, Error> {
-        let mut path = format!(
-            "/api/v5/rubik/stat/contracts/long-short-account-ratio-contract-top-trader?instId={}",
-            inst_id
+        let path = format!(
+            "{}/stat/contracts/long-short-account-ratio-contract-top-trader",
+            API_BIGDATA_PATH
         );
-
-        if let Some(period_value) = period {
-            path.push_str(&format!("&period={}", period_value));
-        }
-        if let Some(begin_time) = begin {
-            path.push_str(&format!("&begin={}", begin_time));
-        }
-        if let Some(end_time) = end {
-            path.push_str(&format!("&end={}", end_time));
-        }
-        if let Some(limit_value) = limit {
-            path.push_str(&format!("&limit={}", limit_value));
-        }
-
-        self.client.send_request(Method::GET, &path, "").await
+        let query = BigDataQuery {
+            inst_id: Some(inst_id),
+            period,
+            begin,
+            end,
+            limit,
+            ..Default::default()
+        };
+        self.client.send_get(&path, &query).await
     }
 
     //获取精英交易员合约多空持仓仓位比
@@ -145,28 +149,23 @@ impl OkxBigData {
     pub async fn get_long_short_postion_ratio_contract_top_trader(
         &self,
         inst_id: &str,
-        period: Option<&str>,
+        period: Option<Bar>,
         begin: Option<&str>,
         end: Option<&str>,
         limit: Option<&str>,
     ) -> Result<Vec<Vec<String>>, Error> {
-        let mut path = format!(
-            "/api/v5/rubik/stat/contracts/long-short-account-ratio-contract-top-trader?instId={}",
-            inst_id
+        let path = format!(
+            "{}/stat/contracts/long-short-account-ratio-contract-top-trader",
+            API_BIGDATA_PATH
         );
-
-        if let Some(period_value) = period {
-            path.push_str(&format!("&period={}", period_value));
-        }
-        if let Some(begin_time) = begin {
-            path.push_str(&format!("&begin={}", begin_time));
-        }
-        if let Some(end_time) = end {
-            path.push_str(&format!("&end={}", end_time));
-        }
-        if let Some(limit_value) = limit {
-            path.push_str(&format!("&limit={}", limit_value));
-        }
-        self.client.send_request(Method::GET, &path, "").await
+        let query = BigDataQuery {
+            inst_id: Some(inst_id),
+            period,
+            begin,
+            end,
+            limit,
+            ..Default::default()
+        };
+        self.client.send_get(&path, &query).await
     }
 }
@@ -1,55 +1,102 @@
-use crate::api::api_trait::OkxApiTrait;
+use crate::api::api_trait::OkxPublicApiTrait;
 use crate::api::API_MARKET_PATH;
-use crate::client::OkxClient;
+use crate::client::{OkxPublicClient, ResponseMeta};
 use crate::dto::market::market_dto::{
     CandleOkxRespDto, Depth, InstrumentOkxResDto, TickerOkxResDto,
 };
+use crate::enums::{Bar, InstrumentType};
 use crate::error::Error;
+use crate::pagination::{paginate, PageOptions};
+use futures::stream::BoxStream;
 use log::debug;
-use reqwest::Method;
+use serde::Serialize;
 
 /// OKX市场数据API
 /// 提供市场行情相关的API访问
 #[derive(Debug)]
 pub struct OkxMarket {
     /// API客户端
-    client: OkxClient,
+    client: OkxPublicClient,
 }
 
-impl OkxApiTrait for OkxMarket {
+impl OkxPublicApiTrait for OkxMarket {
     /// 创建一个新的OkxMarket实例
-    fn new(client: OkxClient) -> Self {
-        Self { client }
-    }
-    /// 从环境变量创建一个新的OkxMarket实例
-    fn from_env() -> Result<Self, Error> {
-        let client = OkxClient::from_env()?;
-        Ok(Self { client })
+    fn new(client: impl Into<OkxPublicClient>) -> Self {
+        Self {
+            client: client.into(),
+        }
     }
     /// 获取内部客户端引用
-    fn client(&self) -> &OkxClient {
+    fn client(&self) -> &OkxPublicClient {
         &self.client
     }
 }
 
+/// 行情查询参数
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct MarketQuery<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inst_type: Option<InstrumentType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inst_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quot_ccy: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uly: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sz: Option<u32>,
+}
+
+/// K线查询参数
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CandlesQuery<'a> {
+    inst_id: &'a str,
+    bar: Bar,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<&'a str>,
+}
+
 impl OkxMarket {
     /// 获取单个产品行情信息
     pub async fn get_ticker(&self, inst_id: &str) -> Result<Vec<TickerOkxResDto>, Error> {
-        let path = format!("{}/ticker?instId={}", API_MARKET_PATH, inst_id);
-        let tickers = self
-            .client
-            .send_request::<Vec<TickerOkxResDto>>(Method::GET, &path, "")
-            .await?;
-        Ok(tickers)
-        // tickers.into_iter().next()
-        //     .ok_or_else(|| Error::ParseError("获取行情数据失败: 空响应".to_string()))
+        self.get_ticker_with_meta(inst_id)
+            .await
+            .map(|(data, _)| data)
+    }
+
+    /// 获取单个产品行情信息，同时返回响应元数据
+    pub async fn get_ticker_with_meta(
+        &self,
+        inst_id: &str,
+    ) -> Result<(Vec<TickerOkxResDto>, ResponseMeta), Error> {
+        let path = format!("{}/ticker", API_MARKET_PATH);
+        let query = MarketQuery {
+            inst_id: Some(inst_id),
+            ..Default::default()
+        };
+        self.client
+            .send_get_with_meta::<Vec<TickerOkxResDto>, _>(&path, &query)
+            .await
     }
 
     /// 获取多个产品行情信息
-    pub async fn get_tickers(&self, inst_type: &str) -> Result<Vec<TickerOkxResDto>, Error> {
-        let path = format!("{}/tickers?instType={}", API_MARKET_PATH, inst_type);
+    pub async fn get_tickers(
+        &self,
+        inst_type: InstrumentType,
+    ) -> Result<Vec<TickerOkxResDto>, Error> {
+        let path = format!("{}/tickers", API_MARKET_PATH);
+        let query = MarketQuery {
+            inst_type: Some(inst_type),
+            ..Default::default()
+        };
         self.client
-            .send_request::<Vec<TickerOkxResDto>>(Method::GET, &path, "")
+            .send_get::<Vec<TickerOkxResDto>, _>(&path, &query)
             .await
     }
 
@@ -59,23 +106,14 @@ impl OkxMarket {
         quot_ccy: Option<&str>,
         inst_id: Option<&str>,
     ) -> Result<Vec<TickerOkxResDto>, Error> {
-        let mut path = format!("{}/index-tickers", API_MARKET_PATH);
-        let mut query_params = vec![];
-
-        if let Some(ccy) = quot_ccy {
-            query_params.push(format!("quotCcy={}", ccy));
-        }
-
-        if let Some(id) = inst_id {
-            query_params.push(format!("instId={}", id));
-        }
-
-        if !query_params.is_empty() {
-            path.push_str(&format!("?{}", query_params.join("&")));
-        }
-
+        let path = format!("{}/index-tickers", API_MARKET_PATH);
+        let query = MarketQuery {
+            quot_ccy,
+            inst_id,
+            ..Default::default()
+        };
         self.client
-            .send_request::<Vec<TickerOkxResDto>>(Method::GET, &path, "")
+            .send_get::<Vec<TickerOkxResDto>, _>(&path, &query)
             .await
     }
 
@@ -85,30 +123,21 @@ impl OkxMarket {
     pub async fn get_candles(
         &self,
         inst_id: &str,
-        bar: &str,
+        bar: Bar,
         after: Option<&str>,
         before: Option<&str>,
         limit: Option<&str>,
     ) -> Result<Vec<CandleOkxRespDto>, Error> {
-        let mut path = format!("{}/candles?instId={}", API_MARKET_PATH, inst_id);
-
-        path.push_str(&format!("&bar={}", bar));
-        // }
-
-        if let Some(a) = after {
-            path.push_str(&format!("&after={}", a));
-        }
-
-        if let Some(b) = before {
-            path.push_str(&format!("&before={}", b));
-        }
-
-        if let Some(l) = limit {
-            path.push_str(&format!("&limit={}", l));
-        }
-
+        let path = format!("{}/candles", API_MARKET_PATH);
+        let query = CandlesQuery {
+            inst_id,
+            bar,
+            after,
+            before,
+            limit,
+        };
         self.client
-            .send_request::<Vec<CandleOkxRespDto>>(Method::GET, &path, "")
+            .send_get::<Vec<CandleOkxRespDto>, _>(&path, &query)
             .await
     }
 
@@ -118,50 +147,67 @@ impl OkxMarket {
     pub async fn get_history_candles(
         &self,
         inst_id: &str,
-        bar: &str,
+        bar: Bar,
         after: Option<&str>,
         before: Option<&str>,
         limit: Option<&str>,
     ) -> Result<Vec<CandleOkxRespDto>, Error> {
-        let mut path = format!("{}/history-candles?instId={}", API_MARKET_PATH, inst_id);
-
-        path.push_str(&format!("&bar={}", bar));
-
-        if let Some(a) = after {
-            path.push_str(&format!("&after={}", a));
-        }
-
-        if let Some(b) = before {
-            path.push_str(&format!("&before={}", b));
-        }
-
-        if let Some(l) = limit {
-            path.push_str(&format!("&limit={}", l));
-        }
+        let path = format!("{}/history-candles", API_MARKET_PATH);
+        let query = CandlesQuery {
+            inst_id,
+            bar,
+            after,
+            before,
+            limit,
+        };
         debug!("OKX path: {}", path);
         let res: Vec<Vec<String>> = self
             .client
-            .send_request::<Vec<Vec<String>>>(Method::GET, &path, "")
+            .send_get::<Vec<Vec<String>>, _>(&path, &query)
             .await?;
-        let candles = res
-            .into_iter()
-            .map(|v| CandleOkxRespDto::from_vec(v))
-            .collect();
+        let candles = res.into_iter().map(CandleOkxRespDto::from_vec).collect();
         Ok(candles)
     }
 
+    /// 自动翻页获取历史K线，按时间从新到旧逐条返回
+    pub fn get_history_candles_stream(
+        &self,
+        inst_id: &str,
+        bar: Bar,
+        options: PageOptions,
+    ) -> BoxStream<'static, Result<CandleOkxRespDto, Error>> {
+        let client = self.client.clone();
+        let path = format!("{}/history-candles", API_MARKET_PATH);
+        let inst_id = inst_id.to_string();
+        paginate(options, move |after, limit| {
+            let client = client.clone();
+            let path = path.clone();
+            let inst_id = inst_id.clone();
+            async move {
+                let limit = limit.map(|limit| limit.to_string());
+                let query = CandlesQuery {
+                    inst_id: &inst_id,
+                    bar,
+                    after: after.as_deref(),
+                    before: None,
+                    limit: limit.as_deref(),
+                };
+                client
+                    .send_get::<Vec<CandleOkxRespDto>, _>(&path, &query)
+                    .await
+            }
+        })
+    }
+
     /// 获取交易产品深度
     pub async fn get_books(&self, inst_id: &str, sz: Option<u32>) -> Result<Depth, Error> {
-        let mut path = format!("{}/books?instId={}", API_MARKET_PATH, inst_id);
-
-        if let Some(s) = sz {
-            path.push_str(&format!("&sz={}", s));
-        }
-
-        let depths = self
-            .client
-            .send_request::<Vec<Depth>>(Method::GET, &path, "")
-            .await?;
+        let path = format!("{}/books", API_MARKET_PATH);
+        let query = MarketQuery {
+            inst_id: Some(inst_id),
+            sz,
+            ..Default::default()
+        };
+        let depths = self.client.send_get::<Vec<Depth>, _>(&path, &query).await?;
 
         depths
             .into_iter()
@@ -172,28 +218,58 @@ impl OkxMarket {
     /// 获取产品列表
     pub async fn get_instruments(
         &self,
-        inst_type: &str,
+        inst_type: InstrumentType,
         uly: Option<&str>,
         inst_id: Option<&str>,
     ) -> Result<Vec<InstrumentOkxResDto>, Error> {
-        let mut path = format!("{}/instruments?instType={}", API_MARKET_PATH, inst_type);
-
-        if let Some(u) = uly {
-            path.push_str(&format!("&uly={}", u));
-        }
-
-        if let Some(id) = inst_id {
-            path.push_str(&format!("&instId={}", id));
-        }
-
+        let path = format!("{}/instruments", API_MARKET_PATH);
+        let query = MarketQuery {
+            inst_type: Some(inst_type),
+            uly,
+            inst_id,
+            ..Default::default()
+        };
         self.client
-            .send_request::<Vec<InstrumentOkxResDto>>(Method::GET, &path, "")
+            .send_get::<Vec<InstrumentOkxResDto>, _>(&path, &query)
             .await
     }
 }
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::client::OkxClient;
+    use crate::config::Config;
+
+    #[tokio::test]
+    async fn get_candles_url_encodes_query_params() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/api/v5/market/candles")
+            .match_query(mockito::Matcher::Exact(
+                "instId=BTC-USD%2F1&bar=1H&after=1%2B2&limit=10".to_string(),
+            ))
+            .match_header("OK-ACCESS-KEY", mockito::Matcher::Missing)
+            .match_header("OK-ACCESS-SIGN", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_body(r#"{"code":"0","msg":"","data":[]}"#)
+            .create_async()
+            .await;
+
+        let client = OkxClient::builder()
+            .config(Config::new().with_api_url(server.url()))
+            .time_sync_interval(None)
+            .build_public()
+            .expect("client");
+        let market = OkxMarket::new(client);
+
+        let candles = market
+            .get_candles("BTC-USD/1", Bar::Hour1, Some("1+2"), None, Some("10"))
+            .await
+            .unwrap();
+
+        assert!(candles.is_empty());
+        mock.assert_async().await;
+    }
 
     #[tokio::test]
     async fn test_get_ticker() {
@@ -207,7 +283,7 @@ mod tests {
     async fn test_get_candles() {
         let market = OkxMarket::from_env().expect("无法从环境变量创建市场API");
         let candles = market
-            .get_candles("BTC-USDT", "1D", None, None, Some("10"))
+            .get_candles("BTC-USDT", Bar::Day1, None, None, Some("10"))
             .await;
 
         println!("Candles result: {:?}", candles);
@@ -1,34 +1,65 @@
-use crate::api::api_trait::OkxApiTrait;
+use crate::api::api_trait::OkxPublicApiTrait;
 use crate::api::API_PUBLIC_PATH;
-use crate::client::OkxClient;
+use crate::client::OkxPublicClient;
 use crate::dto::market::market_dto::InstrumentOkxResDto;
 use crate::dto::public_data::public_data_dto::{
-    EconomicEventOkxRespDto, RateLimit, SystemStatus, SystemTime, FundingRateOkxRespDto, FundingRateHistoryOkxRespDto,
+    EconomicEventOkxRespDto, FundingRateHistoryOkxRespDto, FundingRateOkxRespDto, RateLimit,
+    SystemStatus, SystemTime,
 };
+use crate::enums::InstrumentType;
 use crate::error::Error;
+use crate::pagination::{paginate, PageOptions};
+use futures::stream::BoxStream;
 use reqwest::Method;
+use serde::Serialize;
 
 /// OKX公共数据API
 /// 提供公共数据相关的API访问
 #[derive(Debug)]
 pub struct OkxPublicData {
     /// API客户端
-    client: OkxClient,
+    client: OkxPublicClient,
 }
 
-impl OkxApiTrait for OkxPublicData {
-    fn new(client: OkxClient) -> Self {
-        OkxPublicData { client }
-    }
-    fn from_env() -> Result<Self, Error> {
-        let client = OkxClient::from_env()?;
-        Ok(OkxPublicData::new(client))
+impl OkxPublicApiTrait for OkxPublicData {
+    /// 创建一个新的OkxPublicData实例
+    fn new(client: impl Into<OkxPublicClient>) -> Self {
+        Self {
+            client: client.into(),
+        }
     }
-    fn client(&self) -> &OkxClient {
+    /// 获取内部客户端引用
+    fn client(&self) -> &OkxPublicClient {
         &self.client
     }
 }
 
+/// 公共数据查询参数
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct PublicDataQuery<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inst_type: Option<InstrumentType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uly: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inst_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inst_family: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    importance: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    api_key: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
 impl OkxPublicData {
     /// 获取系统时间
     pub async fn get_time() -> Result<String, Error> {
@@ -47,11 +78,7 @@ impl OkxPublicData {
             serde_json::from_str(&text).map_err(Error::JsonError)?;
 
         if system_time.code != "0" {
-            return Err(Error::OkxApiError {
-                code: system_time.code,
-                message: system_time.msg,
-                smg: "".to_string(),
-            });
+            return Err(Error::okx_api_error(system_time.code, system_time.msg, ""));
         }
 
         if let Some(time) = system_time.data.first() {
@@ -72,27 +99,21 @@ impl OkxPublicData {
     /// 获取已有交易产品的规格信息
     pub async fn get_instruments(
         &self,
-        inst_type: &str,
+        inst_type: InstrumentType,
         underlying: Option<&str>,
         inst_id: Option<&str>,
         inst_family: Option<&str>,
     ) -> Result<Vec<InstrumentOkxResDto>, Error> {
-        let mut path = format!("{}/instruments?instType={}", API_PUBLIC_PATH, inst_type);
-
-        if let Some(uly) = underlying {
-            path.push_str(&format!("&uly={}", uly));
-        }
-
-        if let Some(id) = inst_id {
-            path.push_str(&format!("&instId={}", id));
-        }
-
-        if let Some(family) = inst_family {
-            path.push_str(&format!("&instFamily={}", family));
-        }
-
+        let path = format!("{}/instruments", API_PUBLIC_PATH);
+        let query = PublicDataQuery {
+            inst_type: Some(inst_type),
+            uly: underlying,
+            inst_id,
+            inst_family,
+            ..Default::default()
+        };
         self.client
-            .send_request::<Vec<InstrumentOkxResDto>>(Method::GET, &path, "")
+            .send_get::<Vec<InstrumentOkxResDto>, _>(&path, &query)
             .await
     }
 
@@ -105,61 +126,44 @@ impl OkxPublicData {
         after: Option<i64>,
         limit: Option<i64>,
     ) -> Result<Vec<EconomicEventOkxRespDto>, Error> {
-        let mut path = format!("{}/economic-calendar", API_PUBLIC_PATH);
-        let mut query_params = vec![];
-
-        if let Some(r) = region {
-            query_params.push(format!("region={}", r));
-        }
-
-        if let Some(i) = importance {
-            query_params.push(format!("importance={}", i));
-        }
-
-        if let Some(b) = before {
-            query_params.push(format!("before={}", b));
-        }
-
-        if let Some(a) = after {
-            query_params.push(format!("after={}", a));
-        }
-
-        if let Some(l) = limit {
-            query_params.push(format!("limit={}", l));
-        }
-
-        if !query_params.is_empty() {
-            path.push_str(&format!("?{}", query_params.join("&")));
-        }
-
+        let path = format!("{}/economic-calendar", API_PUBLIC_PATH);
+        let query = PublicDataQuery {
+            region,
+            importance,
+            before,
+            after,
+            limit,
+            ..Default::default()
+        };
         self.client
-            .send_request::<Vec<EconomicEventOkxRespDto>>(Method::GET, &path, "")
+            .send_get::<Vec<EconomicEventOkxRespDto>, _>(&path, &query)
             .await
     }
 
     ///, 获取API速率限制
     pub async fn get_rate_limit(&self, api_key: Option<&str>) -> Result<Vec<RateLimit>, Error> {
-        let mut path = format!("{}/rate-limit", API_PUBLIC_PATH);
-
-        if let Some(key) = api_key {
-            path.push_str(&format!("?apiKey={}", key));
-        }
-
+        let path = format!("{}/rate-limit", API_PUBLIC_PATH);
+        let query = PublicDataQuery {
+            api_key,
+            ..Default::default()
+        };
         self.client
-            .send_request::<Vec<RateLimit>>(Method::GET, &path, "")
+            .send_get::<Vec<RateLimit>, _>(&path, &query)
             .await
     }
 
     /// 获取资金费率
-    pub async fn get_funding_rate(&self, inst_id: &str) -> Result<Vec<FundingRateOkxRespDto>, Error> {
-        let mut path = format!("{}/funding-rate", API_PUBLIC_PATH);
-        if !inst_id.is_empty() {
-             path.push_str(&format!("?instId={}", inst_id));
-        }
-        
-
+    pub async fn get_funding_rate(
+        &self,
+        inst_id: &str,
+    ) -> Result<Vec<FundingRateOkxRespDto>, Error> {
+        let path = format!("{}/funding-rate", API_PUBLIC_PATH);
+        let query = PublicDataQuery {
+            inst_id: Some(inst_id).filter(|id| !id.is_empty()),
+            ..Default::default()
+        };
         self.client
-            .send_request::<Vec<FundingRateOkxRespDto>>(Method::GET, &path, "")
+            .send_get::<Vec<FundingRateOkxRespDto>, _>(&path, &query)
             .await
     }
 
@@ -171,24 +175,52 @@ impl OkxPublicData {
         after: Option<i64>,
         limit: Option<i64>,
     ) -> Result<Vec<FundingRateHistoryOkxRespDto>, Error> {
-        let mut path = format!("{}/funding-rate-history?instId={}", API_PUBLIC_PATH, inst_id);
-
-        if let Some(b) = before {
-            path.push_str(&format!("&before={}", b));
-        }
-
-        if let Some(a) = after {
-            path.push_str(&format!("&after={}", a));
-        }
-
-        if let Some(l) = limit {
-            path.push_str(&format!("&limit={}", l));
-        }
-
+        let path = format!("{}/funding-rate-history", API_PUBLIC_PATH);
+        let query = PublicDataQuery {
+            inst_id: Some(inst_id),
+            before,
+            after,
+            limit,
+            ..Default::default()
+        };
         self.client
-            .send_request::<Vec<FundingRateHistoryOkxRespDto>>(Method::GET, &path, "")
+            .send_get::<Vec<FundingRateHistoryOkxRespDto>, _>(&path, &query)
             .await
     }
+
+    /// 自动翻页获取历史资金费率，按资金费时间从新到旧逐条返回
+    pub fn get_funding_rate_history_stream(
+        &self,
+        inst_id: &str,
+        options: PageOptions,
+    ) -> BoxStream<'static, Result<FundingRateHistoryOkxRespDto, Error>> {
+        let client = self.client.clone();
+        let path = format!("{}/funding-rate-history", API_PUBLIC_PATH);
+        let inst_id = inst_id.to_string();
+        paginate(options, move |after, limit| {
+            let client = client.clone();
+            let path = path.clone();
+            let inst_id = inst_id.clone();
+            async move {
+                let after = after
+                    .map(|after| {
+                        after.parse::<i64>().map_err(|_| {
+                            Error::ParameterError(format!("无效的分页游标: {}", after))
+                        })
+                    })
+                    .transpose()?;
+                let query = PublicDataQuery {
+                    inst_id: Some(&inst_id),
+                    after,
+                    limit: limit.map(i64::from),
+                    ..Default::default()
+                };
+                client
+                    .send_get::<Vec<FundingRateHistoryOkxRespDto>, _>(&path, &query)
+                    .await
+            }
+        })
+    }
 }
 
 #[cfg(test)]
@@ -204,7 +236,9 @@ mod tests {
     #[tokio::test]
     async fn test_get_instruments() {
         let public_data = OkxPublicData::from_env().expect("无法从环境变量创建公共数据API");
-        let instruments = public_data.get_instruments("SPOT", None, None, None).await;
+        let instruments = public_data
+            .get_instruments(InstrumentType::Spot, None, None, None)
+            .await;
         println!("交易产品列表: {:?}", instruments);
     }
     #[tokio::test]
@@ -1,13 +1,24 @@
 use crate::api::api_trait::OkxApiTrait;
 use crate::api::API_TRADE_PATH;
-use crate::client::OkxClient;
+use crate::client::{OkxClient, ResponseMeta};
+use crate::dto::common::BatchResult;
+use crate::dto::trade::algo_dto::{
+    AlgoOrderDetailRespDto, AlgoOrderListReqDto, AlgoOrderReqDto, AlgoOrderResDto,
+    AmendAlgoOrderReqDto, AmendAlgoOrderResDto, CancelAlgoOrderReqDto,
+};
 use crate::dto::trade::trade_dto::{
-    CancelOrderReqDto, FeeRate, OrderPendingRespDto, OrderReqDto, OrderResDto,
+    AmendOrderReqDto, AmendOrderResDto, CancelAllAfterReqDto, CancelAllAfterResDto,
+    CancelOrderReqDto, FeeRate, FillRespDto, MassCancelReqDto, MassCancelResDto,
+    OrderPendingRespDto, OrderReqDto, OrderResDto,
 };
 use crate::dto::trade_dto::{CloseOrderReqDto, OrdListReqDto, OrderDetailRespDto};
+use crate::enums::{InstrumentType, OrderState, OrderType};
 use crate::error::Error;
+use crate::order_validator::OrderValidator;
+use crate::pagination::{paginate, PageOptions};
+use futures::stream::BoxStream;
 use reqwest::Method;
-use serde_json::json;
+use serde::Serialize;
 
 /// OKX交易API
 /// 提供交易相关的API访问
@@ -15,117 +26,191 @@ use serde_json::json;
 pub struct OkxTrade {
     /// API客户端
     client: OkxClient,
+    /// 下单前的订单校验
+    validator: Option<OrderValidator>,
 }
 
 impl OkxApiTrait for OkxTrade {
     fn new(client: OkxClient) -> Self {
-        OkxTrade { client }
+        OkxTrade {
+            client,
+            validator: None,
+        }
     }
     fn client(&self) -> &OkxClient {
         &self.client
     }
 }
 
+/// 交易查询参数
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct TradeQuery<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inst_type: Option<InstrumentType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inst_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uly: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ord_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cl_ord_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    algo_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    algo_cl_ord_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ord_type: Option<OrderType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<OrderState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+}
+
 impl OkxTrade {
+    /// 设置下单前的订单校验，下单和批量下单在发送前按产品规格和持仓方式检查订单
+    pub fn with_validator(mut self, validator: OrderValidator) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
     /// 下单
     pub async fn place_order(&self, order_params: OrderReqDto) -> Result<Vec<OrderResDto>, Error> {
+        self.place_order_with_meta(order_params)
+            .await
+            .map(|(data, _)| data)
+    }
+
+    /// 下单，同时返回响应元数据（`inTime` / `outTime`、往返耗时等）
+    pub async fn place_order_with_meta(
+        &self,
+        mut order_params: OrderReqDto,
+    ) -> Result<(Vec<OrderResDto>, ResponseMeta), Error> {
+        if let Some(validator) = &self.validator {
+            validator.validate(&mut order_params)?;
+        }
         let path = format!("{}/order", API_TRADE_PATH);
         let body_str = serde_json::to_string(&order_params).map_err(Error::JsonError)?;
         self.client
-            .send_request::<Vec<OrderResDto>>(Method::POST, &path, &body_str)
+            .send_request_with_meta::<Vec<OrderResDto>>(Method::POST, &path, &body_str)
             .await
     }
 
     /// 批量下单
+    /// 部分成功时返回每一笔订单的执行结果
     pub async fn place_multiple_orders(
         &self,
         orders: Vec<OrderReqDto>,
-    ) -> Result<Vec<OrderResDto>, Error> {
+    ) -> Result<BatchResult<OrderResDto>, Error> {
+        self.place_multiple_orders_with_meta(orders)
+            .await
+            .map(|(result, _)| result)
+    }
+
+    /// 批量下单，同时返回响应元数据
+    pub async fn place_multiple_orders_with_meta(
+        &self,
+        mut orders: Vec<OrderReqDto>,
+    ) -> Result<(BatchResult<OrderResDto>, ResponseMeta), Error> {
+        if let Some(validator) = &self.validator {
+            validator.validate_all(&mut orders)?;
+        }
         let path = format!("{}/batch-orders", API_TRADE_PATH);
         let body_str = serde_json::to_string(&orders).map_err(Error::JsonError)?;
         self.client
-            .send_request::<Vec<OrderResDto>>(Method::POST, &path, &body_str)
+            .send_batch_request_with_meta::<OrderResDto>(Method::POST, &path, &body_str)
             .await
     }
 
     /// 撤单
-    pub async fn cancel_order(
+    pub async fn cancel_order(&self, params: CancelOrderReqDto) -> Result<Vec<OrderResDto>, Error> {
+        self.cancel_order_with_meta(params)
+            .await
+            .map(|(data, _)| data)
+    }
+
+    /// 撤单，同时返回响应元数据
+    pub async fn cancel_order_with_meta(
         &self,
-        inst_id: &str,
-        ord_id: Option<&str>,
-        cl_ord_id: Option<&str>,
-    ) -> Result<serde_json::Value, Error> {
+        params: CancelOrderReqDto,
+    ) -> Result<(Vec<OrderResDto>, ResponseMeta), Error> {
         let path = format!("{}/cancel-order", API_TRADE_PATH);
-
-        let mut body = json!({
-            "instId": inst_id,
-        });
-
-        if let Some(order_id) = ord_id {
-            body["ordId"] = json!(order_id);
-        }
-
-        if let Some(client_order_id) = cl_ord_id {
-            body["clOrdId"] = json!(client_order_id);
-        }
-
-        let body_str = serde_json::to_string(&body).map_err(Error::JsonError)?;
+        let body_str = serde_json::to_string(&params).map_err(Error::JsonError)?;
         self.client
-            .send_request::<serde_json::Value>(Method::POST, &path, &body_str)
+            .send_request_with_meta::<Vec<OrderResDto>>(Method::POST, &path, &body_str)
             .await
     }
 
     /// 批量撤单
+    /// 部分成功时返回每一笔订单的执行结果
     pub async fn cancel_multiple_orders(
         &self,
         orders: Vec<CancelOrderReqDto>,
-    ) -> Result<Vec<OrderResDto>, Error> {
+    ) -> Result<BatchResult<OrderResDto>, Error> {
         let path = format!("{}/cancel-batch-orders", API_TRADE_PATH);
         let body_str = serde_json::to_string(&orders).map_err(Error::JsonError)?;
         self.client
-            .send_request::<Vec<OrderResDto>>(Method::POST, &path, &body_str)
+            .send_batch_request::<OrderResDto>(Method::POST, &path, &body_str)
+            .await
+    }
+
+    /// 批量修改订单，每次最多可以修改20笔订单
+    /// 部分成功时返回每一笔订单的执行结果
+    pub async fn amend_multiple_orders(
+        &self,
+        orders: Vec<AmendOrderReqDto>,
+    ) -> Result<BatchResult<AmendOrderResDto>, Error> {
+        let path = format!("{}/amend-batch-orders", API_TRADE_PATH);
+        let body_str = serde_json::to_string(&orders).map_err(Error::JsonError)?;
+        self.client
+            .send_batch_request::<AmendOrderResDto>(Method::POST, &path, &body_str)
+            .await
+    }
+
+    /// 撤销MMP订单
+    /// 撤销同一交易品种下用户所有的MMP挂单，仅适用于组合保证金账户模式下的期权订单
+    /// 限速：5次/2s
+    /// 限速规则：User ID
+    pub async fn mass_cancel(
+        &self,
+        params: MassCancelReqDto,
+    ) -> Result<Vec<MassCancelResDto>, Error> {
+        let path = format!("{}/mass-cancel", API_TRADE_PATH);
+        let body_str = serde_json::to_string(&params).map_err(Error::JsonError)?;
+        self.client
+            .send_request::<Vec<MassCancelResDto>>(Method::POST, &path, &body_str)
             .await
     }
 
     /// 修改订单
     pub async fn amend_order(
         &self,
-        inst_id: &str,
-        ord_id: Option<&str>,
-        cl_ord_id: Option<&str>,
-        req_id: Option<&str>,
-        new_sz: Option<&str>,
-        new_px: Option<&str>,
-    ) -> Result<serde_json::Value, Error> {
+        params: AmendOrderReqDto,
+    ) -> Result<Vec<AmendOrderResDto>, Error> {
         let path = format!("{}/amend-order", API_TRADE_PATH);
+        let body_str = serde_json::to_string(&params).map_err(Error::JsonError)?;
+        self.client
+            .send_request::<Vec<AmendOrderResDto>>(Method::POST, &path, &body_str)
+            .await
+    }
 
-        let mut body = json!({
-            "instId": inst_id,
-        });
-
-        if let Some(order_id) = ord_id {
-            body["ordId"] = json!(order_id);
-        }
-
-        if let Some(client_order_id) = cl_ord_id {
-            body["clOrdId"] = json!(client_order_id);
-        }
-
-        if let Some(request_id) = req_id {
-            body["reqId"] = json!(request_id);
-        }
-
-        if let Some(new_size) = new_sz {
-            body["newSz"] = json!(new_size);
-        }
-
-        if let Some(new_price) = new_px {
-            body["newPx"] = json!(new_price);
-        }
-
-        let body_str = serde_json::to_string(&body).map_err(Error::JsonError)?;
+    /// 倒计时全部撤单
+    /// 在倒计时结束后，取消所有挂单。`time_out` 为0时关闭该功能
+    /// 限速：1次/s
+    /// 限速规则：User ID
+    pub async fn cancel_all_after(
+        &self,
+        params: CancelAllAfterReqDto,
+    ) -> Result<Vec<CancelAllAfterResDto>, Error> {
+        let path = format!("{}/cancel-all-after", API_TRADE_PATH);
+        let body_str = serde_json::to_string(&params).map_err(Error::JsonError)?;
         self.client
-            .send_request::<serde_json::Value>(Method::POST, &path, &body_str)
+            .send_request::<Vec<CancelAllAfterResDto>>(Method::POST, &path, &body_str)
             .await
     }
 
@@ -136,69 +221,43 @@ impl OkxTrade {
         ord_id: Option<&str>,
         cl_ord_id: Option<&str>,
     ) -> Result<Vec<OrderDetailRespDto>, Error> {
-        let mut path = format!("{}/order?instId={}", API_TRADE_PATH, inst_id);
-
-        if let Some(order_id) = ord_id {
-            path.push_str(&format!("&ordId={}", order_id));
-        }
-
-        if let Some(client_order_id) = cl_ord_id {
-            path.push_str(&format!("&clOrdId={}", client_order_id));
-        }
-
+        let path = format!("{}/order", API_TRADE_PATH);
+        let query = TradeQuery {
+            inst_id: Some(inst_id),
+            ord_id,
+            cl_ord_id,
+            ..Default::default()
+        };
         self.client
-            .send_request::<Vec<OrderDetailRespDto>>(Method::GET, &path, "")
+            .send_get::<Vec<OrderDetailRespDto>, _>(&path, &query)
             .await
     }
 
     /// 获取未成交订单列表
+    #[allow(clippy::too_many_arguments)]
     pub async fn get_pending_orders(
         &self,
-        inst_type: Option<&str>,
+        inst_type: Option<InstrumentType>,
         inst_id: Option<&str>,
-        ord_type: Option<&str>,
-        state: Option<&str>,
+        ord_type: Option<OrderType>,
+        state: Option<OrderState>,
         after: Option<&str>,
         before: Option<&str>,
         limit: Option<u32>,
     ) -> Result<Vec<OrderPendingRespDto>, Error> {
-        let mut path = format!("{}/orders-pending", API_TRADE_PATH);
-        let mut query_params = vec![];
-
-        if let Some(it) = inst_type {
-            query_params.push(format!("instType={}", it));
-        }
-
-        if let Some(id) = inst_id {
-            query_params.push(format!("instId={}", id));
-        }
-
-        if let Some(ot) = ord_type {
-            query_params.push(format!("ordType={}", ot));
-        }
-
-        if let Some(s) = state {
-            query_params.push(format!("state={}", s));
-        }
-
-        if let Some(a) = after {
-            query_params.push(format!("after={}", a));
-        }
-
-        if let Some(b) = before {
-            query_params.push(format!("before={}", b));
-        }
-
-        if let Some(l) = limit {
-            query_params.push(format!("limit={}", l));
-        }
-
-        if !query_params.is_empty() {
-            path.push_str(&format!("?{}", query_params.join("&")));
-        }
-
+        let path = format!("{}/orders-pending", API_TRADE_PATH);
+        let query = TradeQuery {
+            inst_type,
+            inst_id,
+            ord_type,
+            state,
+            after,
+            before,
+            limit,
+            ..Default::default()
+        };
         self.client
-            .send_request::<Vec<OrderPendingRespDto>>(Method::GET, &path, "")
+            .send_get::<Vec<OrderPendingRespDto>, _>(&path, &query)
             .await
     }
 
@@ -210,34 +269,9 @@ impl OkxTrade {
         &self,
         params: OrdListReqDto,
     ) -> Result<Vec<OrderDetailRespDto>, Error> {
-        let mut path = format!("{}/orders-history?instType={}", API_TRADE_PATH, params.inst_type);
-
-        if let Some(id) = params.inst_id {
-            path.push_str(&format!("&instId={}", id));
-        }
-
-        if let Some(ot) = params.ord_type {
-            path.push_str(&format!("&ordType={}", ot));
-        }
-
-        if let Some(s) = params.state {
-            path.push_str(&format!("&state={}", s));
-        }
-
-        if let Some(a) = params.after {
-            path.push_str(&format!("&after={}", a));
-        }
-
-        if let Some(b) = params.before {
-            path.push_str(&format!("&before={}", b));
-        }
-
-        if let Some(l) = params.limit {
-            path.push_str(&format!("&limit={}", l));
-        }
-
+        let path = format!("{}/orders-history", API_TRADE_PATH);
         self.client
-            .send_request::<Vec<OrderDetailRespDto>>(Method::GET, &path, "")
+            .send_get::<Vec<OrderDetailRespDto>, _>(&path, &params)
             .await
     }
 
@@ -249,105 +283,221 @@ impl OkxTrade {
         &self,
         params: OrdListReqDto,
     ) -> Result<Vec<OrderDetailRespDto>, Error> {
-        let mut path = format!(
-            "{}/orders-history-archive?instType={}",
-            API_TRADE_PATH, params.inst_type
-        );
-
-        if let Some(id) = params.inst_id {
-            path.push_str(&format!("&instId={}", id));
-        }
-
-        if let Some(ot) = params.ord_type {
-            path.push_str(&format!("&ordType={}", ot));
-        }
-
-        if let Some(s) = params.state {
-            path.push_str(&format!("&state={}", s));
-        }
-
-        if let Some(a) = params.after {
-            path.push_str(&format!("&after={}", a));
-        }
-
-        if let Some(b) = params.before {
-            path.push_str(&format!("&before={}", b));
-        }
-
-        if let Some(l) = params.limit {
-            path.push_str(&format!("&limit={}", l));
-        }
-
+        let path = format!("{}/orders-history-archive", API_TRADE_PATH);
         self.client
-            .send_request::<Vec<OrderDetailRespDto>>(Method::GET, &path, "")
+            .send_get::<Vec<OrderDetailRespDto>, _>(&path, &params)
             .await
     }
 
+    /// 自动翻页获取历史订单记录（近三个月），按订单创建时间从新到旧逐条返回
+    ///
+    /// `params` 中的 `after` 作为起始游标，`limit` 作为每页条数（`options` 中设置时优先）。
+    pub fn get_order_history_archive_stream(
+        &self,
+        params: OrdListReqDto,
+        options: PageOptions,
+    ) -> BoxStream<'static, Result<OrderDetailRespDto, Error>> {
+        let options = PageOptions {
+            after: options.after.or_else(|| params.after.clone()),
+            page_size: options.page_size.or(params.limit),
+            ..options
+        };
+        let client = self.client.clone();
+        let path = format!("{}/orders-history-archive", API_TRADE_PATH);
+        paginate(options, move |after, limit| {
+            let client = client.clone();
+            let path = path.clone();
+            let params = OrdListReqDto {
+                after,
+                before: None,
+                limit,
+                ..params.clone()
+            };
+            async move {
+                client
+                    .send_get::<Vec<OrderDetailRespDto>, _>(&path, &params)
+                    .await
+            }
+        })
+    }
+
     /// 获取成交明细
     pub async fn get_fills(
         &self,
-        inst_type: Option<&str>,
+        inst_type: Option<InstrumentType>,
         inst_id: Option<&str>,
         ord_id: Option<&str>,
         after: Option<&str>,
         before: Option<&str>,
         limit: Option<u32>,
-    ) -> Result<serde_json::Value, Error> {
-        let mut path = format!("{}/fills", API_TRADE_PATH);
-        let mut query_params = vec![];
-
-        if let Some(it) = inst_type {
-            query_params.push(format!("instType={}", it));
-        }
-
-        if let Some(id) = inst_id {
-            query_params.push(format!("instId={}", id));
-        }
-
-        if let Some(oid) = ord_id {
-            query_params.push(format!("ordId={}", oid));
-        }
-
-        if let Some(a) = after {
-            query_params.push(format!("after={}", a));
-        }
-
-        if let Some(b) = before {
-            query_params.push(format!("before={}", b));
-        }
+    ) -> Result<Vec<FillRespDto>, Error> {
+        let path = format!("{}/fills", API_TRADE_PATH);
+        let query = TradeQuery {
+            inst_type,
+            inst_id,
+            ord_id,
+            after,
+            before,
+            limit,
+            ..Default::default()
+        };
+        self.client
+            .send_get::<Vec<FillRespDto>, _>(&path, &query)
+            .await
+    }
 
-        if let Some(l) = limit {
-            query_params.push(format!("limit={}", l));
-        }
+    /// 自动翻页获取成交明细（近三天），按时间从新到旧逐条返回
+    pub fn get_fills_stream(
+        &self,
+        inst_type: Option<InstrumentType>,
+        inst_id: Option<&str>,
+        ord_id: Option<&str>,
+        options: PageOptions,
+    ) -> BoxStream<'static, Result<FillRespDto, Error>> {
+        self.fills_stream("fills", inst_type, inst_id, ord_id, options)
+    }
 
-        if !query_params.is_empty() {
-            path.push_str(&format!("?{}", query_params.join("&")));
-        }
+    /// 自动翻页获取成交明细（近三个月），按时间从新到旧逐条返回
+    /// 限速：10次/2s
+    /// 限速规则：User ID
+    pub fn get_fills_history_stream(
+        &self,
+        inst_type: InstrumentType,
+        inst_id: Option<&str>,
+        ord_id: Option<&str>,
+        options: PageOptions,
+    ) -> BoxStream<'static, Result<FillRespDto, Error>> {
+        self.fills_stream("fills-history", Some(inst_type), inst_id, ord_id, options)
+    }
 
-        self.client
-            .send_request::<serde_json::Value>(Method::GET, &path, "")
-            .await
+    fn fills_stream(
+        &self,
+        endpoint: &str,
+        inst_type: Option<InstrumentType>,
+        inst_id: Option<&str>,
+        ord_id: Option<&str>,
+        options: PageOptions,
+    ) -> BoxStream<'static, Result<FillRespDto, Error>> {
+        let client = self.client.clone();
+        let path = format!("{}/{}", API_TRADE_PATH, endpoint);
+        let inst_id = inst_id.map(str::to_string);
+        let ord_id = ord_id.map(str::to_string);
+        paginate(options, move |after, limit| {
+            let client = client.clone();
+            let path = path.clone();
+            let (inst_id, ord_id) = (inst_id.clone(), ord_id.clone());
+            async move {
+                let query = TradeQuery {
+                    inst_type,
+                    inst_id: inst_id.as_deref(),
+                    ord_id: ord_id.as_deref(),
+                    after: after.as_deref(),
+                    limit,
+                    ..Default::default()
+                };
+                client.send_get::<Vec<FillRespDto>, _>(&path, &query).await
+            }
+        })
     }
 
     /// 获取交易产品费率
     pub async fn get_fee_rates(
         &self,
-        inst_type: &str,
+        inst_type: InstrumentType,
         inst_id: Option<&str>,
         uly: Option<&str>,
     ) -> Result<Vec<FeeRate>, Error> {
-        let mut path = format!("{}/trade-fee?instType={}", API_TRADE_PATH, inst_type);
+        let path = format!("{}/trade-fee", API_TRADE_PATH);
+        let query = TradeQuery {
+            inst_type: Some(inst_type),
+            inst_id,
+            uly,
+            ..Default::default()
+        };
+        self.client.send_get::<Vec<FeeRate>, _>(&path, &query).await
+    }
 
-        if let Some(id) = inst_id {
-            path.push_str(&format!("&instId={}", id));
-        }
+    /// 策略委托下单
+    /// 支持单向止盈止损、双向止盈止损、计划委托、移动止盈止损、冰山委托和时间加权委托
+    /// 限速：20次/2s
+    /// 限速规则：User ID
+    pub async fn place_algo_order(
+        &self,
+        params: AlgoOrderReqDto,
+    ) -> Result<Vec<AlgoOrderResDto>, Error> {
+        let path = format!("{}/order-algo", API_TRADE_PATH);
+        let body_str = serde_json::to_string(&params).map_err(Error::JsonError)?;
+        self.client
+            .send_request::<Vec<AlgoOrderResDto>>(Method::POST, &path, &body_str)
+            .await
+    }
 
-        if let Some(underlying) = uly {
-            path.push_str(&format!("&uly={}", underlying));
-        }
+    /// 撤销策略委托订单，每次最多可以撤销10个策略委托单
+    /// 部分成功时返回每一笔订单的执行结果
+    pub async fn cancel_algo_orders(
+        &self,
+        orders: Vec<CancelAlgoOrderReqDto>,
+    ) -> Result<BatchResult<AlgoOrderResDto>, Error> {
+        let path = format!("{}/cancel-algos", API_TRADE_PATH);
+        let body_str = serde_json::to_string(&orders).map_err(Error::JsonError)?;
+        self.client
+            .send_batch_request::<AlgoOrderResDto>(Method::POST, &path, &body_str)
+            .await
+    }
+
+    /// 修改策略委托订单
+    /// 仅支持单向止盈止损和计划委托（交割、永续）
+    pub async fn amend_algo_order(
+        &self,
+        params: AmendAlgoOrderReqDto,
+    ) -> Result<Vec<AmendAlgoOrderResDto>, Error> {
+        let path = format!("{}/amend-algos", API_TRADE_PATH);
+        let body_str = serde_json::to_string(&params).map_err(Error::JsonError)?;
+        self.client
+            .send_request::<Vec<AmendAlgoOrderResDto>>(Method::POST, &path, &body_str)
+            .await
+    }
+
+    /// 获取策略委托单信息，`algo_id` 和 `algo_cl_ord_id` 必须传一个
+    pub async fn get_algo_order_details(
+        &self,
+        algo_id: Option<&str>,
+        algo_cl_ord_id: Option<&str>,
+    ) -> Result<Vec<AlgoOrderDetailRespDto>, Error> {
+        let path = format!("{}/order-algo", API_TRADE_PATH);
+        let query = TradeQuery {
+            algo_id,
+            algo_cl_ord_id,
+            ..Default::default()
+        };
+        self.client
+            .send_get::<Vec<AlgoOrderDetailRespDto>, _>(&path, &query)
+            .await
+    }
+
+    /// 获取未完成策略委托单列表
+    /// 限速：20次/2s
+    /// 限速规则：User ID
+    pub async fn get_algo_orders_pending(
+        &self,
+        params: AlgoOrderListReqDto,
+    ) -> Result<Vec<AlgoOrderDetailRespDto>, Error> {
+        let path = format!("{}/orders-algo-pending", API_TRADE_PATH);
+        self.client
+            .send_get::<Vec<AlgoOrderDetailRespDto>, _>(&path, &params)
+            .await
+    }
 
+    /// 获取最近3个月当前账户下所有策略委托单列表，`state` 和 `algo_id` 必须传一个
+    /// 限速：20次/2s
+    /// 限速规则：User ID
+    pub async fn get_algo_order_history(
+        &self,
+        params: AlgoOrderListReqDto,
+    ) -> Result<Vec<AlgoOrderDetailRespDto>, Error> {
+        let path = format!("{}/orders-algo-history", API_TRADE_PATH);
         self.client
-            .send_request::<Vec<FeeRate>>(Method::GET, &path, "")
+            .send_get::<Vec<AlgoOrderDetailRespDto>, _>(&path, &params)
             .await
     }
 
@@ -369,15 +519,20 @@ impl OkxTrade {
 mod tests {
     use super::*;
     use crate::config::Credentials;
+    use crate::enums::environment_enums::Environment;
+    use crate::enums::{Side, TdMode};
+    use crate::error::ApiErrorCode;
+    use crate::transport::tests::mock_client;
+    use crate::transport::MockTransport;
 
     fn sample_order() -> OrderReqDto {
         OrderReqDto {
             inst_id: "BTC-USDT".to_string(),
-            side: "buy".to_string(),
-            ord_type: "limit".to_string(),
-            sz: "0.001".to_string(),
-            td_mode: "cash".to_string(),
-            px: Some("20000".to_string()),
+            side: Side::Buy,
+            ord_type: OrderType::Limit,
+            sz: "0.001".parse().unwrap(),
+            td_mode: TdMode::Cash,
+            px: Some("20000".parse().unwrap()),
             pos_side: None,
             cl_ord_id: None,
             tag: None,
@@ -417,8 +572,13 @@ mod tests {
             .create_async()
             .await;
 
-        let mut client =
-            OkxClient::new(Credentials::new("key", "secret", "passphrase", "0")).expect("client");
+        let mut client = OkxClient::new(Credentials::new(
+            "key",
+            "secret",
+            "passphrase",
+            Environment::Production,
+        ))
+        .expect("client");
         client.set_base_url(server.url());
         let trade = OkxTrade::new(client);
 
@@ -427,7 +587,8 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(result[0].ord_id, "1");
+        assert!(result.is_all_success());
+        assert_eq!(result.succeeded[0].ord_id, "1");
         mock.assert_async().await;
     }
 
@@ -449,8 +610,13 @@ mod tests {
             .create_async()
             .await;
 
-        let mut client =
-            OkxClient::new(Credentials::new("key", "secret", "passphrase", "0")).expect("client");
+        let mut client = OkxClient::new(Credentials::new(
+            "key",
+            "secret",
+            "passphrase",
+            Environment::Production,
+        ))
+        .expect("client");
         client.set_base_url(server.url());
         let trade = OkxTrade::new(client);
 
@@ -463,7 +629,49 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(result[0].ord_id, "1");
+        assert_eq!(result.succeeded[0].ord_id, "1");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn place_multiple_orders_splits_partial_success() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/api/v5/trade/batch-orders")
+            .with_status(200)
+            .with_body(
+                r#"{"code":"2","msg":"Bulk operation partially succeeded","data":[{"ordId":"1","clOrdId":"a","tag":"","sCode":"0","sMsg":"","ts":"1"},{"ordId":"","clOrdId":"b","tag":"","sCode":"51008","sMsg":"Order failed. Insufficient USDT balance","ts":"1"}]}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let mut client = OkxClient::new(Credentials::new(
+            "key",
+            "secret",
+            "passphrase",
+            Environment::Production,
+        ))
+        .expect("client");
+        client.set_base_url(server.url());
+        let trade = OkxTrade::new(client);
+
+        let result = trade
+            .place_multiple_orders(vec![sample_order(), sample_order()])
+            .await
+            .unwrap();
+
+        assert!(result.is_partial_success());
+        assert_eq!(result.succeeded[0].ord_id, "1");
+        assert_eq!(result.failed[0].cl_ord_id.as_deref(), Some("b"));
+        assert_eq!(
+            result.errors()[0].code,
+            ApiErrorCode::OrderFailedAccountBalanceNotEnough
+        );
+
+        let err = result.into_result().unwrap_err();
+        assert_eq!(err.api_code(), Some(ApiErrorCode::PartialSuccess));
+        assert!(!err.is_recoverable());
         mock.assert_async().await;
     }
 
@@ -475,11 +683,11 @@ mod tests {
         let result = trade
             .place_order(OrderReqDto {
                 inst_id: "BTC-USDT".to_string(),
-                side: "buy".to_string(),
-                ord_type: "limit".to_string(),
-                sz: "0.001".to_string(),
-                td_mode: "cash".to_string(),
-                px: Some("20000".to_string()),
+                side: Side::Buy,
+                ord_type: OrderType::Limit,
+                sz: "0.001".parse().unwrap(),
+                td_mode: TdMode::Cash,
+                px: Some("20000".parse().unwrap()),
                 pos_side: None,
                 cl_ord_id: None,
                 tag: None,
@@ -499,4 +707,267 @@ mod tests {
 
         println!("Place order result: {:?}", result);
     }
+
+    #[tokio::test]
+    async fn fills_history_stream_follows_bill_id_cursor() {
+        use futures::TryStreamExt;
+
+        let fill = |bill_id: &str, ts: &str| serde_json::json!({"instType":"SWAP","instId":"BTC-USDT-SWAP","billId":bill_id,"ts":ts});
+        let transport = MockTransport::new();
+        transport.on_data(
+            Method::GET,
+            "/api/v5/trade/fills-history?instType=SWAP&limit=2",
+            serde_json::json!([fill("5", "500"), fill("4", "400")]),
+        );
+        transport.on_data(
+            Method::GET,
+            "/api/v5/trade/fills-history?instType=SWAP&after=4&limit=2",
+            serde_json::json!([fill("3", "300"), fill("2", "200")]),
+        );
+        let client = mock_client(&transport);
+        let trade = OkxTrade::new(client);
+
+        let fills: Vec<FillRespDto> = trade
+            .get_fills_history_stream(
+                InstrumentType::Swap,
+                None,
+                None,
+                PageOptions::new().with_page_size(2).with_since(250),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+
+        let bill_ids: Vec<_> = fills.iter().map(|f| f.bill_id.as_str()).collect();
+        assert_eq!(bill_ids, vec!["5", "4", "3"]);
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn get_fills_returns_typed_fills() {
+        let transport = MockTransport::new();
+        transport.on_data(
+            Method::GET,
+            "/api/v5/trade/fills?instType=SWAP&limit=1",
+            serde_json::json!([{"instType":"SWAP","instId":"BTC-USDT-SWAP","billId":"6","ts":"600"}]),
+        );
+        let trade = OkxTrade::new(mock_client(&transport));
+
+        let fills = trade
+            .get_fills(Some(InstrumentType::Swap), None, None, None, None, Some(1))
+            .await
+            .unwrap();
+
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].bill_id, "6");
+        assert_eq!(fills[0].inst_id, "BTC-USDT-SWAP");
+    }
+
+    #[tokio::test]
+    async fn algo_order_endpoints_use_typed_requests() {
+        use crate::dto::trade::algo_dto::{AlgoOrderKind, TriggerAlgoParams};
+        use crate::enums::{AlgoOrderState, AlgoOrderType};
+
+        let transport = MockTransport::new();
+        transport.on_data(
+            Method::POST,
+            "/api/v5/trade/order-algo",
+            serde_json::json!([{"algoId":"a1","clOrdId":"","algoClOrdId":"s1","sCode":"0","sMsg":"","tag":""}]),
+        );
+        transport.on_data(
+            Method::POST,
+            "/api/v5/trade/cancel-algos",
+            serde_json::json!([{"algoId":"a1","sCode":"0","sMsg":""}]),
+        );
+        transport.on_data(
+            Method::GET,
+            "/api/v5/trade/orders-algo-history?ordType=trigger&state=canceled&instId=BTC-USDT",
+            serde_json::json!([{"algoId":"a1","instId":"BTC-USDT","ordType":"trigger","state":"canceled","triggerPx":"30000","ordIdList":[]}]),
+        );
+        let client = mock_client(&transport);
+        let trade = OkxTrade::new(client);
+
+        let mut order = AlgoOrderReqDto::new(
+            "BTC-USDT",
+            TdMode::Cash,
+            Side::Buy,
+            Some("0.01".parse().unwrap()),
+            AlgoOrderKind::Trigger(TriggerAlgoParams {
+                trigger_px: "30000".parse().unwrap(),
+                order_px: "-1".parse().unwrap(),
+                trigger_px_type: None,
+            }),
+        );
+        order.algo_cl_ord_id = Some("s1".to_string());
+        let placed = trade.place_algo_order(order).await.unwrap();
+        assert_eq!(placed[0].algo_id, "a1");
+        assert_eq!(
+            transport.last_request().unwrap().body,
+            r#"{"instId":"BTC-USDT","tdMode":"cash","side":"buy","sz":"0.01","algoClOrdId":"s1","ordType":"trigger","triggerPx":"30000","orderPx":"-1"}"#
+        );
+
+        let canceled = trade
+            .cancel_algo_orders(vec![CancelAlgoOrderReqDto {
+                inst_id: "BTC-USDT".to_string(),
+                algo_id: Some("a1".to_string()),
+                ..Default::default()
+            }])
+            .await
+            .unwrap();
+        assert!(canceled.is_all_success());
+
+        let history = trade
+            .get_algo_order_history(AlgoOrderListReqDto {
+                state: Some(AlgoOrderState::Canceled),
+                inst_id: Some("BTC-USDT".to_string()),
+                ..AlgoOrderListReqDto::new(AlgoOrderType::Trigger)
+            })
+            .await
+            .unwrap();
+        assert_eq!(history[0].algo_id, "a1");
+        assert_eq!(history[0].state, "canceled");
+    }
+
+    #[tokio::test]
+    async fn amend_multiple_orders_and_mass_cancel() {
+        let transport = MockTransport::new();
+        transport.on(
+            Method::POST,
+            "/api/v5/trade/amend-batch-orders",
+            crate::transport::HttpResponse::new(
+                200,
+                r#"{"code":"2","msg":"","data":[{"ordId":"1","clOrdId":"","reqId":"r1","ts":"1","sCode":"0","sMsg":""},{"ordId":"2","clOrdId":"","reqId":"r2","ts":"1","sCode":"51503","sMsg":"Order modification failed as the order does not exist."}]}"#,
+            ),
+        );
+        transport.on_data(
+            Method::POST,
+            "/api/v5/trade/mass-cancel",
+            serde_json::json!([{"result":true}]),
+        );
+        let client = mock_client(&transport);
+        let trade = OkxTrade::new(client);
+
+        let amend = |ord_id: &str, req_id: &str| AmendOrderReqDto {
+            inst_id: "BTC-USDT-SWAP".to_string(),
+            ord_id: Some(ord_id.to_string()),
+            req_id: Some(req_id.to_string()),
+            new_px: Some("21000".parse().unwrap()),
+            cxl_on_fail: Some(true),
+            new_tp_trigger_px: Some("25000".parse().unwrap()),
+            ..Default::default()
+        };
+        let result = trade
+            .amend_multiple_orders(vec![amend("1", "r1"), amend("2", "r2")])
+            .await
+            .unwrap();
+        assert!(result.is_partial_success());
+        assert_eq!(result.succeeded[0].req_id.as_deref(), Some("r1"));
+        assert_eq!(result.failed[0].ord_id, "2");
+        let body: serde_json::Value =
+            serde_json::from_str(&transport.last_request().unwrap().body).unwrap();
+        assert_eq!(body[1]["cxlOnFail"], true);
+        assert_eq!(body[1]["newTpTriggerPx"], "25000");
+
+        let canceled = trade
+            .mass_cancel(MassCancelReqDto::option("BTC-USD"))
+            .await
+            .unwrap();
+        assert!(canceled[0].result);
+        assert_eq!(
+            transport.last_request().unwrap().body,
+            r#"{"instType":"OPTION","instFamily":"BTC-USD"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn cancel_and_amend_return_typed_results() {
+        let transport = MockTransport::new();
+        transport.on_data(
+            Method::POST,
+            "/api/v5/trade/cancel-order",
+            serde_json::json!([{"ordId":"1","clOrdId":"c1","ts":"1","sCode":"0","sMsg":""}]),
+        );
+        transport.on_data(
+            Method::POST,
+            "/api/v5/trade/amend-order",
+            serde_json::json!([{"ordId":"1","clOrdId":"","reqId":"r1","ts":"2","sCode":"0","sMsg":""}]),
+        );
+        let client = mock_client(&transport);
+        let trade = OkxTrade::new(client);
+
+        let canceled = trade
+            .cancel_order(CancelOrderReqDto {
+                inst_id: "BTC-USDT".to_string(),
+                ord_id: Some("1".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(canceled[0].cl_ord_id.as_deref(), Some("c1"));
+        assert_eq!(
+            transport.last_request().unwrap().body,
+            r#"{"instId":"BTC-USDT","ordId":"1"}"#
+        );
+
+        let amended = trade
+            .amend_order(AmendOrderReqDto {
+                inst_id: "BTC-USDT".to_string(),
+                ord_id: Some("1".to_string()),
+                req_id: Some("r1".to_string()),
+                new_px: Some("21000".parse().unwrap()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(amended[0].req_id.as_deref(), Some("r1"));
+        assert_eq!(
+            transport.last_request().unwrap().body,
+            r#"{"instId":"BTC-USDT","ordId":"1","reqId":"r1","newPx":"21000"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn validator_checks_orders_before_sending() {
+        use crate::dto::market::market_dto::InstrumentOkxResDto;
+        use crate::order_validator::ValidationMode;
+
+        let transport = MockTransport::new();
+        transport.on_data(
+            Method::POST,
+            "/api/v5/trade/order",
+            serde_json::json!([{"ordId":"1","clOrdId":"","tag":"","ts":"1","sCode":"0","sMsg":""}]),
+        );
+        let client = mock_client(&transport);
+        let instrument: InstrumentOkxResDto = serde_json::from_value(serde_json::json!({
+            "instType": "SPOT", "instId": "BTC-USDT", "tickSz": "0.1", "lotSz": "0.0001",
+            "minSz": "0.0001", "maxLmtSz": "10000", "maxMktSz": "1000000", "ctVal": "",
+            "state": "live"
+        }))
+        .unwrap();
+        let validator = OrderValidator::new();
+        validator.insert_instruments([instrument]).unwrap();
+
+        let trade = OkxTrade::new(client.clone()).with_validator(validator.clone());
+        let mut order = sample_order();
+        order.px = Some("20000.05".parse().unwrap());
+        assert!(matches!(
+            trade.place_order(order).await,
+            Err(Error::ParameterError(_))
+        ));
+        let mut batch = vec![sample_order(), sample_order()];
+        batch[1].sz = "0.00001".parse().unwrap();
+        assert!(trade.place_multiple_orders(batch).await.is_err());
+        assert!(transport.last_request().is_none());
+
+        let trade =
+            OkxTrade::new(client).with_validator(validator.with_mode(ValidationMode::Round));
+        let mut order = sample_order();
+        order.px = Some("20000.05".parse().unwrap());
+        order.sz = "0.00123".parse().unwrap();
+        trade.place_order(order).await.unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&transport.last_request().unwrap().body).unwrap();
+        assert_eq!(body["px"], "20000");
+        assert_eq!(body["sz"], "0.0012");
+    }
 }
@@ -8,9 +8,9 @@ pub struct OkxWebsocketApi {
 }
 
 impl OkxWebsocketApi {
-    pub fn new_public() -> Self {
-        let client = OkxWebsocketClient::new_public();
-        OkxWebsocketApi { client }
+    pub fn new_public() -> Result<Self, Error> {
+        let client = OkxWebsocketClient::new_public()?;
+        Ok(OkxWebsocketApi { client })
     }
 
     pub fn new_private(credentials: Credentials) -> Self {
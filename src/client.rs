@@ -1,14 +1,26 @@
-use crate::config::{Credentials, CONFIG};
+use crate::api::API_PUBLIC_PATH;
+use crate::clock::{ServerClock, DEFAULT_TIME_SYNC_INTERVAL};
+use crate::config::{global_config, Config, Credentials};
+use crate::credential_provider::{CredentialProvider, CredentialStore};
 
+use crate::dto::common::{BatchItem, BatchResult};
+use crate::dto::public_data::public_data_dto::SystemTime;
+use crate::enums::environment_enums::Environment;
 use crate::enums::language_enums::Language;
-use crate::error::Error;
+use crate::error::{ApiErrorCode, Error};
+use crate::middleware::{Middleware, ResponseContext};
+use crate::rate_limiter::RateLimiter;
+use crate::retry::{RetryAttempt, RetryPolicy};
+use crate::secret::redact_body;
+use crate::transport::{HttpRequest, HttpTransport, ReqwestTransport};
 use crate::utils;
-use log::{debug, error};
-use reqwest::{Client, Method, StatusCode};
+use log::{debug, error, warn};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Client, Method, Proxy, StatusCode};
 use serde::{Deserialize, Serialize};
-use serde_json::{json, Deserializer};
 use serde_path_to_error;
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 /// 通用的OKX API响应结构
 #[derive(Serialize, Deserialize, Debug)]
 pub struct OkxApiResponse<T: Serialize> {
@@ -17,47 +29,126 @@ pub struct OkxApiResponse<T: Serialize> {
     pub data: T,
 }
 
-/// OKX API错误响应
-#[derive(Serialize, Deserialize, Debug)]
-struct OkxApiErrorResponse {
-    msg: String,
+/// 未解析data的OKX API响应，用于先判断code再反序列化data
+#[derive(Deserialize)]
+struct RawOkxApiResponse {
     code: String,
+    #[serde(default)]
+    msg: String,
+    #[serde(default)]
+    data: serde_json::Value,
+    #[serde(default, rename = "inTime")]
+    in_time: Option<String>,
+    #[serde(default, rename = "outTime")]
+    out_time: Option<String>,
+}
+
+/// 响应元数据，用于统计网关延迟和观察限速额度
+#[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    /// HTTP状态码
+    pub status: u16,
+    /// 请求到达网关的时间（微秒时间戳），对应响应中的 `inTime`
+    pub in_time: Option<i64>,
+    /// 网关返回响应的时间（微秒时间戳），对应响应中的 `outTime`
+    pub out_time: Option<i64>,
+    /// 本地往返耗时（最后一次尝试，不含限速和重试等待）
+    pub round_trip: Duration,
+    /// 响应头
+    pub headers: HeaderMap,
+}
+
+impl ResponseMeta {
+    /// 网关处理耗时（`outTime - inTime`）
+    pub fn gateway_latency(&self) -> Option<Duration> {
+        let (in_time, out_time) = (self.in_time?, self.out_time?);
+        u64::try_from(out_time - in_time)
+            .ok()
+            .map(Duration::from_micros)
+    }
+
+    /// 获取响应头的值
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    /// 与限速相关的响应头（`x-ratelimit-*`、`retry-after` 等）
+    pub fn rate_limit_headers(&self) -> Vec<(&str, &str)> {
+        self.headers
+            .iter()
+            .filter(|(name, _)| {
+                let name = name.as_str();
+                name.contains("ratelimit") || name.contains("rate-limit") || name == "retry-after"
+            })
+            .filter_map(|(name, value)| Some((name.as_str(), value.to_str().ok()?)))
+            .collect()
+    }
+
+    /// 当前窗口的限速额度，对应 `x-ratelimit-limit`
+    pub fn rate_limit_limit(&self) -> Option<u32> {
+        self.header("x-ratelimit-limit")?.trim().parse().ok()
+    }
+
+    /// 当前窗口剩余的请求次数，对应 `x-ratelimit-remaining`
+    pub fn rate_limit_remaining(&self) -> Option<u32> {
+        self.header("x-ratelimit-remaining")?.trim().parse().ok()
+    }
+
+    /// 限速窗口重置时间，对应 `x-ratelimit-reset`
+    pub fn rate_limit_reset(&self) -> Option<&str> {
+        self.header("x-ratelimit-reset")
+    }
+
+    /// 被限速时建议的等待时间，对应 `retry-after`（秒）
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.header("retry-after")?
+            .trim()
+            .parse()
+            .ok()
+            .map(Duration::from_secs)
+    }
 }
 
 /// OKX HTTP API客户端
 #[derive(Debug, Clone)]
 pub struct OkxClient {
-    /// HTTP客户端
-    client: Client,
-    /// API凭证
-    credentials: Credentials,
-    /// 是否使用模拟交易
-    is_simulated_trading: String,
+    /// HTTP传输层
+    transport: Arc<dyn HttpTransport>,
+    /// API凭证，克隆后共享，轮换后下一次请求即生效；匿名的公共客户端为 `None`，不发送签名请求头
+    credentials: Option<CredentialStore>,
+    /// 交易环境，只接受同一环境的凭证
+    environment: Environment,
     /// API基础URL
     base_url: String,
     /// 请求有效期（毫秒）
     request_expiration_ms: i64,
     /// 请求头中 Accept-Language
     accept_language: Option<Language>,
+    /// 客户端限速器，克隆后共享额度
+    rate_limiter: RateLimiter,
+    /// 请求重试策略
+    retry_policy: RetryPolicy,
+    /// 请求中间件，按添加顺序执行
+    middlewares: Vec<Arc<dyn Middleware>>,
+    /// 服务器时钟，用于补偿本地时钟偏差
+    server_clock: ServerClock,
+    /// 服务器时间同步间隔，`None` 表示不自动同步
+    time_sync_interval: Option<Duration>,
 }
 
 impl OkxClient {
-    /// 创建一个新的OKX客户端
+    /// 创建一个新的OKX客户端，使用全局 `CONFIG`，交易环境与凭证一致
     pub fn new(credentials: Credentials) -> Result<Self, Error> {
-        // 避免 macOS system-configuration 在沙箱环境下探测系统代理导致 panic
-        let client = Client::builder()
-            .timeout(Duration::from_millis(CONFIG.api_timeout_ms))
+        OkxClientBuilder::new()
+            .config(global_config()?.with_environment(credentials.environment))
+            .credentials(credentials)
+            .system_proxy(true)
             .build()
-            .map_err(Error::HttpError)?;
+    }
 
-        Ok(Self {
-            client,
-            is_simulated_trading: credentials.is_simulated_trading.clone(),
-            credentials,
-            base_url: CONFIG.api_url.clone(),
-            request_expiration_ms: CONFIG.request_expiration_ms,
-            accept_language: None,
-        })
+    /// 创建客户端构建器
+    pub fn builder() -> OkxClientBuilder {
+        OkxClientBuilder::new()
     }
 
     /// 从环境变量创建OKX客户端
@@ -66,16 +157,16 @@ impl OkxClient {
         Self::new(credentials)
     }
 
-    /// 从环境变量创建OKX客户端，并设置模拟交易
+    /// 从 `OKX_SIMULATED_*` 环境变量创建模拟盘客户端
     pub fn from_env_with_simulated_trading() -> Result<Self, Error> {
         let credentials = Credentials::from_env_with_simulated_trading()?;
         let client = Self::new(credentials)?;
         Ok(client)
     }
 
-    /// 设置是否使用模拟交易
-    pub fn set_simulated_trading(&mut self, is_simulated: String) {
-        self.is_simulated_trading = is_simulated;
+    /// 交易环境
+    pub fn environment(&self) -> Environment {
+        self.environment
     }
 
     /// 设置API基础URL
@@ -93,101 +184,1032 @@ impl OkxClient {
         self.accept_language = Some(accept_language);
     }
 
-    /// 发送API请求并返回反序列化的响应
+    /// 设置客户端限速器
+    pub fn set_rate_limiter(&mut self, rate_limiter: RateLimiter) {
+        self.rate_limiter = rate_limiter;
+    }
+
+    /// 获取客户端限速器
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// 设置请求重试策略
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// 获取请求重试策略
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// 在中间件链末尾添加一个中间件
+    pub fn add_middleware(&mut self, middleware: impl Middleware + 'static) {
+        self.middlewares.push(Arc::new(middleware));
+    }
+
+    /// 当前API凭证
+    pub fn credentials(&self) -> Credentials {
+        self.credential_store().current()
+    }
+
+    /// 可轮换的凭证，可用于 `refresh` / `spawn_refresh`
+    pub fn credential_store(&self) -> &CredentialStore {
+        // 只有 OkxPublicClient 内部的匿名客户端没有凭证，它不会暴露为 OkxClient
+        self.credentials.as_ref().expect("OkxClient总是带有API凭证")
+    }
+
+    /// 轮换API凭证，共享同一凭证的客户端（包括克隆）下一次请求即使用新凭证；新凭证必须属于同一环境
+    pub fn set_credentials(&self, credentials: Credentials) {
+        self.credential_store().set(credentials);
+    }
+
+    /// 设置服务器时钟，默认使用进程内共享的 `SERVER_CLOCK`
+    pub fn set_server_clock(&mut self, server_clock: ServerClock) {
+        self.server_clock = server_clock;
+    }
+
+    /// 获取服务器时钟
+    pub fn server_clock(&self) -> &ServerClock {
+        &self.server_clock
+    }
+
+    /// 设置服务器时间同步间隔，`None` 表示不自动同步
+    pub fn set_time_sync_interval(&mut self, interval: Option<Duration>) {
+        self.time_sync_interval = interval;
+    }
+
+    /// 请求 `/api/v5/public/time` 同步服务器时间，返回新的偏差（毫秒）
+    pub async fn sync_server_time(&self) -> Result<i64, Error> {
+        let url = format!("{}{}/time", self.base_url, API_PUBLIC_PATH);
+        let local_before = chrono::Utc::now().timestamp_millis();
+        let response = self
+            .transport
+            .send(HttpRequest {
+                method: Method::GET,
+                url,
+                path: format!("{}/time", API_PUBLIC_PATH),
+                headers: HeaderMap::new(),
+                body: String::new(),
+            })
+            .await?;
+        let response_body = response.body;
+        let local_after = chrono::Utc::now().timestamp_millis();
+
+        let result: OkxApiResponse<Vec<SystemTime>> =
+            serde_json::from_str(&response_body).map_err(Error::JsonError)?;
+        if result.code != "0" {
+            return Err(Error::okx_api_error(result.code, result.msg, ""));
+        }
+        let server_ms = result
+            .data
+            .first()
+            .ok_or_else(|| Error::ParseError("获取系统时间失败: 空响应".to_string()))
+            .and_then(|time| utils::parse_timestamp_ms(&time.ts))?;
+
+        let offset_ms = self
+            .server_clock
+            .record_sample(local_before, server_ms, local_after);
+        debug!("服务器时间偏差: {} 毫秒", offset_ms);
+        Ok(offset_ms)
+    }
+
+    /// 偏差过期时同步服务器时间，同步失败只记录日志
+    async fn sync_server_time_if_stale(&self) {
+        let Some(interval) = self.time_sync_interval else {
+            return;
+        };
+        if !self.server_clock.is_stale(interval) || !self.server_clock.try_begin_sync() {
+            return;
+        }
+        let result = self.sync_server_time().await;
+        if let Err(e) = &result {
+            warn!("同步服务器时间失败: {}", e);
+        }
+        self.server_clock.end_sync(result.is_ok());
+    }
+
+    /// 发送GET请求，查询参数由 `params` 序列化并进行URL编码后参与签名
+    pub async fn send_get<T, Q>(&self, path: &str, params: &Q) -> Result<T, Error>
+    where
+        T: for<'a> Deserialize<'a> + Serialize,
+        Q: Serialize + ?Sized,
+    {
+        let request_path = utils::build_request_path(path, params)?;
+        self.send_request::<T>(Method::GET, &request_path, "").await
+    }
+
+    /// 发送GET请求，同时返回响应元数据
+    pub async fn send_get_with_meta<T, Q>(
+        &self,
+        path: &str,
+        params: &Q,
+    ) -> Result<(T, ResponseMeta), Error>
+    where
+        T: for<'a> Deserialize<'a> + Serialize,
+        Q: Serialize + ?Sized,
+    {
+        let request_path = utils::build_request_path(path, params)?;
+        self.send_request_with_meta::<T>(Method::GET, &request_path, "")
+            .await
+    }
+
+    /// 从查询参数或请求体中提取限速所需的instId，以及本次请求消耗的额度（批量请求按条数计算）
+    fn rate_limit_subject(path: &str, body: &str) -> (Option<String>, u32) {
+        if let Some((_, query)) = path.split_once('?') {
+            let inst_id = url::form_urlencoded::parse(query.as_bytes())
+                .find(|(k, _)| k == "instId")
+                .map(|(_, v)| v.into_owned());
+            return (inst_id, 1);
+        }
+        match serde_json::from_str::<serde_json::Value>(body) {
+            Ok(serde_json::Value::Array(items)) => (None, items.len() as u32),
+            Ok(value) => (
+                value
+                    .get("instId")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string),
+                1,
+            ),
+            Err(_) => (None, 1),
+        }
+    }
+
+    /// 发送API请求并返回反序列化的响应，失败时按重试策略重试
     pub async fn send_request<T: for<'a> Deserialize<'a> + Serialize>(
         &self,
         method: Method,
         path: &str,
         body: &str,
     ) -> Result<T, Error> {
-        let method_str = method.to_string(); // 克隆方法字符串用于错误报告
-        let timestamp = utils::generate_timestamp();
-        let signature = utils::generate_signature(
-            &self.credentials.api_secret,
-            &timestamp,
-            &method,
-            path,
-            body,
-        )?;
-        let exp_time = utils::generate_expiration_timestamp(self.request_expiration_ms);
-
-        let url = format!("{}{}", self.base_url, path);
-
-        let mut request_builder = self
-            .client
-            .request(method, &url)
-            .header("OK-ACCESS-KEY", &self.credentials.api_key)
-            .header("OK-ACCESS-SIGN", signature)
-            .header("OK-ACCESS-TIMESTAMP", timestamp)
-            .header("OK-ACCESS-PASSPHRASE", &self.credentials.passphrase)
-            .header("Content-Type", "application/json")
-            .header("expTime", exp_time.to_string());
-        if self.is_simulated_trading == "1" {
-            request_builder = request_builder.header("x-simulated-trading", "1");
+        self.send_request_with_meta::<T>(method, path, body)
+            .await
+            .map(|(data, _)| data)
+    }
+
+    /// 发送API请求，同时返回响应元数据（HTTP状态码、`inTime` / `outTime`、本地往返耗时、响应头）
+    pub async fn send_request_with_meta<T: for<'a> Deserialize<'a> + Serialize>(
+        &self,
+        method: Method,
+        path: &str,
+        body: &str,
+    ) -> Result<(T, ResponseMeta), Error> {
+        self.send_with_retry::<T>(method, path, body, false)
+            .await
+            .map(|(result, meta)| (result.data, meta))
+    }
+
+    /// 发送批量操作请求，返回每一项的执行结果
+    ///
+    /// 响应code为1（全部失败）或2（部分成功）时不会转换为错误，而是按 `sCode` 拆分为成功项和失败项
+    pub async fn send_batch_request<T>(
+        &self,
+        method: Method,
+        path: &str,
+        body: &str,
+    ) -> Result<BatchResult<T>, Error>
+    where
+        T: for<'a> Deserialize<'a> + Serialize + BatchItem,
+    {
+        self.send_batch_request_with_meta::<T>(method, path, body)
+            .await
+            .map(|(result, _)| result)
+    }
+
+    /// 发送批量操作请求，同时返回响应元数据
+    pub async fn send_batch_request_with_meta<T>(
+        &self,
+        method: Method,
+        path: &str,
+        body: &str,
+    ) -> Result<(BatchResult<T>, ResponseMeta), Error>
+    where
+        T: for<'a> Deserialize<'a> + Serialize + BatchItem,
+    {
+        let (result, meta) = self
+            .send_with_retry::<Vec<T>>(method, path, body, true)
+            .await?;
+        Ok((BatchResult::new(result.code, result.msg, result.data), meta))
+    }
+
+    /// 按重试策略发送请求
+    async fn send_with_retry<T: for<'a> Deserialize<'a> + Serialize>(
+        &self,
+        method: Method,
+        path: &str,
+        body: &str,
+        allow_partial: bool,
+    ) -> Result<(OkxApiResponse<T>, ResponseMeta), Error> {
+        let mut attempt = 1;
+        loop {
+            let error = match self
+                .send_once::<T>(method.clone(), path, body, allow_partial)
+                .await
+            {
+                Ok(result) => return Ok(result),
+                Err(e) => e,
+            };
+            if is_timestamp_error(&error) {
+                // 时间戳相关错误，下次请求前重新同步服务器时间
+                self.server_clock.invalidate();
+            }
+            let Some(delay) = self
+                .retry_policy
+                .next_delay(&method, path, body, attempt, &error)
+            else {
+                return Err(error);
+            };
+            self.retry_policy.notify(&RetryAttempt {
+                method: &method,
+                path,
+                attempt,
+                max_attempts: self.retry_policy.max_attempts,
+                delay,
+                error: &error,
+            });
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// 发送一次API请求
+    ///
+    /// `allow_partial` 为真时，批量操作的全部失败/部分成功响应也会正常返回
+    async fn send_once<T: for<'a> Deserialize<'a> + Serialize>(
+        &self,
+        method: Method,
+        path: &str,
+        body: &str,
+        allow_partial: bool,
+    ) -> Result<(OkxApiResponse<T>, ResponseMeta), Error> {
+        let credentials = self.credentials.as_ref().map(CredentialStore::current);
+        if let Some(credentials) = &credentials {
+            if credentials.environment != self.environment {
+                return Err(Error::ConfigError(format!(
+                    "API凭证属于{}环境，不能发送到{}环境",
+                    credentials.environment, self.environment
+                )));
+            }
+        }
+        let (inst_id, cost) = Self::rate_limit_subject(path, body);
+        let user_id = credentials.as_ref().map_or("", |c| c.api_key.as_str());
+        self.rate_limiter
+            .acquire(path, user_id, inst_id.as_deref(), cost)
+            .await?;
+
+        self.sync_server_time_if_stale().await;
+
+        let exp_time = self.server_clock.now_ms() + self.request_expiration_ms;
+        let mut request = HttpRequest {
+            method,
+            url: String::new(),
+            path: path.to_string(),
+            headers: HeaderMap::new(),
+            body: body.to_string(),
+        };
+        insert_header(&mut request.headers, "Content-Type", "application/json")?;
+        insert_header(&mut request.headers, "expTime", &exp_time.to_string())?;
+        if self.environment.is_demo() {
+            insert_header(&mut request.headers, "x-simulated-trading", "1")?;
         }
         if let Some(accept_language) = &self.accept_language {
-            request_builder =
-                request_builder.header("Accept-Language", accept_language.to_string());
+            insert_header(
+                &mut request.headers,
+                "Accept-Language",
+                accept_language.to_string(),
+            )?;
         }
+        for middleware in &self.middlewares {
+            middleware.on_request(&mut request)?;
+        }
+
+        let hooked_request = (!self.middlewares.is_empty()).then(|| request.clone());
+        if let Some(credentials) = &credentials {
+            // 签名使用中间件处理之后的请求
+            let timestamp = self.server_clock.timestamp();
+            let signature = utils::generate_signature(
+                credentials.api_secret.expose(),
+                &timestamp,
+                &request.method,
+                &request.path,
+                &request.body,
+            )?;
+            insert_header(&mut request.headers, "OK-ACCESS-KEY", &credentials.api_key)?;
+            insert_header(&mut request.headers, "OK-ACCESS-SIGN", &signature)?;
+            insert_header(&mut request.headers, "OK-ACCESS-TIMESTAMP", &timestamp)?;
+            insert_header(
+                &mut request.headers,
+                "OK-ACCESS-PASSPHRASE",
+                credentials.passphrase.expose(),
+            )?;
+        }
+        request.url = format!("{}{}", self.base_url, request.path);
+
+        let method_str = request.method.to_string(); // 克隆方法字符串用于错误报告
+        let url = request.url.clone();
         debug!("OKX API请求: {}", url);
-        debug!("OKX API请求: {}", body);
-        let request_builder = request_builder.body(body.to_string());
-        let response = request_builder.send().await.map_err(Error::HttpError)?;
-        let status_code = response.status();
-        let response_body = response.text().await.map_err(Error::HttpError)?;
-        debug!("okx result: {:?}", response_body);
-        match status_code {
-            StatusCode::OK => {
-                // 使用 serde_path_to_error 来获取详细的字段路径信息
-                let deserializer = &mut Deserializer::from_str(&response_body);
-                let result: OkxApiResponse<T> = serde_path_to_error::deserialize(deserializer)
-                    .map_err(|e| {
-                        error!("JSON解析错误详情: {}", e);
-                        error!("请求URL: {}, 请求方法: {}", url, method_str);
-                        Error::JsonError(e.into_inner())
-                    })?;
-                if result.code == "0" {
-                    return Ok(result.data);
+        debug!("OKX API请求: {}", redact_body(&request.body));
+        let started = Instant::now();
+        let response = match self.transport.send(request).await {
+            Ok(response) => response,
+            Err(e) => {
+                if let Some(request) = &hooked_request {
+                    for middleware in self.middlewares.iter().rev() {
+                        middleware.on_error(request, &e, started.elapsed());
+                    }
+                }
+                return Err(e);
+            }
+        };
+        let elapsed = started.elapsed();
+        let status_code = StatusCode::from_u16(response.status)
+            .map_err(|e| Error::ApiRequestError(format!("无效的HTTP状态码: {}", e)))?;
+        let response_body = response.body;
+        debug!("okx result: {:?}", redact_body(&response_body));
+        let raw_result = serde_json::from_str::<RawOkxApiResponse>(&response_body);
+        if let Some(request) = &hooked_request {
+            let context = ResponseContext {
+                status: response.status,
+                headers: &response.headers,
+                body: &response_body,
+                code: raw_result.as_ref().ok().map(|r| r.code.as_str()),
+                elapsed,
+            };
+            for middleware in self.middlewares.iter().rev() {
+                middleware.on_response(request, &context);
+            }
+        }
+        if status_code != StatusCode::OK {
+            error!("OKX API错误响应: {}", redact_body(&response_body));
+            // 非200响应通常也会在响应体中带上OKX错误码
+            if let Ok(result) = raw_result {
+                if !result.code.is_empty() && result.code != "0" {
+                    return Err(Error::from_okx_response(
+                        result.code,
+                        result.msg,
+                        &result.data,
+                    ));
                 }
-                // result={"code":"1","data":[{"clOrdId":"","ordId":"","sCode":"51000","sMsg":"Parameter ordId error","ts":"1752558485701"}],"inTime":"1752558485701589","msg":"All operations failed","outTime":"1752558485701884"}
-                // 尝试从data数组的第一个元素中提取sMsg
-                let smg = if let Ok(data_array) =
-                    serde_json::from_str::<Vec<serde_json::Value>>(&json!(result.data).to_string())
-                {
-                    data_array
-                        .get(0)
-                        .and_then(|item| item.get("sMsg"))
-                        .and_then(|s| s.as_str())
-                        .unwrap_or("未知错误")
-                        .to_string()
-                } else {
-                    error!("解析错误信息失败: {}", response_body);
-                    "解析错误信息失败".to_string()
-                };
-
-                error!("OKX API错误响应: {}", response_body);
-                return Err(Error::OkxApiError {
-                    code: result.code,
-                    message: result.msg,
-                    smg,
-                });
             }
-            StatusCode::NOT_FOUND => {
-                error!("OKX API错误响应: {}", response_body);
-                Err(Error::OkxApiError {
-                    code: "404".to_string(),
-                    message: format!("API not found: {}", url),
-                    smg: "".to_string(),
-                })
+            let (code, message) = if status_code == StatusCode::NOT_FOUND {
+                ("404".to_string(), format!("API not found: {}", url))
+            } else {
+                (status_code.to_string(), response_body)
+            };
+            return Err(Error::OkxApiError {
+                code,
+                message,
+                smg: "".to_string(),
+                api_code: ApiErrorCode::from_http_status(status_code.as_u16()),
+                item_errors: Vec::new(),
+            });
+        }
+
+        let result = raw_result.map_err(|e| {
+            error!("JSON解析错误详情: {}", e);
+            error!("请求URL: {}, 请求方法: {}", url, method_str);
+            Error::JsonError(e)
+        })?;
+        let is_batch_result = allow_partial
+            && (result.code == "1" || result.code == "2")
+            && result.data.as_array().is_some_and(|items| {
+                !items.is_empty() && items.iter().all(|item| item.get("sCode").is_some())
+            });
+        if result.code != "0" && !is_batch_result {
+            // result={"code":"1","data":[{"clOrdId":"","ordId":"","sCode":"51000","sMsg":"Parameter ordId error","ts":"1752558485701"}],"inTime":"1752558485701589","msg":"All operations failed","outTime":"1752558485701884"}
+            error!("OKX API错误响应: {}", redact_body(&response_body));
+            return Err(Error::from_okx_response(
+                result.code,
+                result.msg,
+                &result.data,
+            ));
+        }
+        // 使用 serde_path_to_error 来获取详细的字段路径信息
+        let data = serde_path_to_error::deserialize(result.data).map_err(|e| {
+            error!("JSON解析错误详情: {}", e);
+            error!("请求URL: {}, 请求方法: {}", url, method_str);
+            Error::JsonError(e.into_inner())
+        })?;
+        let meta = ResponseMeta {
+            status: status_code.as_u16(),
+            in_time: result.in_time.and_then(|t| t.parse().ok()),
+            out_time: result.out_time.and_then(|t| t.parse().ok()),
+            round_trip: elapsed,
+            headers: response.headers,
+        };
+        Ok((
+            OkxApiResponse {
+                code: result.code,
+                msg: result.msg,
+                data,
+            },
+            meta,
+        ))
+    }
+}
+
+/// 公共接口客户端
+///
+/// 只能用于不需要认证的公共接口（行情、公共数据、交易大数据、公告等），不能用来创建账户、交易、资金等
+/// 需要认证的API实例。通过 `OkxPublicClient::new()` 或 `OkxClientBuilder::build_public` 创建的客户端不需要API密钥，
+/// 也不会发送签名请求头；也可以由 `OkxClient` 转换而来，此时请求仍然签名。
+#[derive(Debug, Clone)]
+pub struct OkxPublicClient {
+    inner: OkxClient,
+}
+
+impl OkxPublicClient {
+    /// 创建匿名的公共接口客户端，使用全局 `CONFIG`
+    pub fn new() -> Result<Self, Error> {
+        OkxClientBuilder::new()
+            .config(global_config()?)
+            .system_proxy(true)
+            .build_public()
+    }
+
+    /// 创建指定交易环境的匿名公共接口客户端
+    pub fn for_environment(environment: Environment) -> Result<Self, Error> {
+        OkxClientBuilder::new()
+            .config(global_config()?.with_environment(environment))
+            .system_proxy(true)
+            .build_public()
+    }
+
+    /// 是否为匿名客户端（不发送签名请求头）
+    pub fn is_anonymous(&self) -> bool {
+        self.inner.credentials.is_none()
+    }
+
+    /// 交易环境
+    pub fn environment(&self) -> Environment {
+        self.inner.environment
+    }
+
+    /// 请求 `/api/v5/public/time` 同步服务器时间，返回新的偏差（毫秒）
+    pub async fn sync_server_time(&self) -> Result<i64, Error> {
+        self.inner.sync_server_time().await
+    }
+
+    /// 设置API基础URL
+    pub fn set_base_url(&mut self, base_url: impl Into<String>) {
+        self.inner.set_base_url(base_url);
+    }
+
+    /// 设置请求头中 Accept-Language
+    pub fn set_accept_language(&mut self, accept_language: Language) {
+        self.inner.set_accept_language(accept_language);
+    }
+
+    /// 设置客户端限速器
+    pub fn set_rate_limiter(&mut self, rate_limiter: RateLimiter) {
+        self.inner.set_rate_limiter(rate_limiter);
+    }
+
+    /// 获取客户端限速器
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        self.inner.rate_limiter()
+    }
+
+    /// 设置请求重试策略
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.inner.set_retry_policy(retry_policy);
+    }
+
+    /// 在中间件链末尾添加一个中间件
+    pub fn add_middleware(&mut self, middleware: impl Middleware + 'static) {
+        self.inner.add_middleware(middleware);
+    }
+
+    /// 发送GET请求
+    pub async fn send_get<T, Q>(&self, path: &str, params: &Q) -> Result<T, Error>
+    where
+        T: for<'a> Deserialize<'a> + Serialize,
+        Q: Serialize + ?Sized,
+    {
+        self.inner.send_get(path, params).await
+    }
+
+    /// 发送GET请求，同时返回响应元数据
+    pub async fn send_get_with_meta<T, Q>(
+        &self,
+        path: &str,
+        params: &Q,
+    ) -> Result<(T, ResponseMeta), Error>
+    where
+        T: for<'a> Deserialize<'a> + Serialize,
+        Q: Serialize + ?Sized,
+    {
+        self.inner.send_get_with_meta(path, params).await
+    }
+
+    /// 发送API请求并返回反序列化的响应
+    pub async fn send_request<T: for<'a> Deserialize<'a> + Serialize>(
+        &self,
+        method: Method,
+        path: &str,
+        body: &str,
+    ) -> Result<T, Error> {
+        self.inner.send_request(method, path, body).await
+    }
+}
+
+impl From<OkxClient> for OkxPublicClient {
+    fn from(client: OkxClient) -> Self {
+        Self { inner: client }
+    }
+}
+
+/// OKX客户端构建器
+///
+/// 所有配置都需要显式传入，不会读取环境变量；需要时可以传入 `CONFIG.clone()` 或使用 `Credentials::from_env()`。
+#[derive(Debug, Default)]
+pub struct OkxClientBuilder {
+    config: Option<Config>,
+    environment: Option<Environment>,
+    credentials: Option<CredentialStore>,
+    credential_provider: Option<Arc<dyn CredentialProvider>>,
+    http_client: Option<Client>,
+    transport: Option<Arc<dyn HttpTransport>>,
+    proxy: Option<String>,
+    system_proxy: bool,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    default_headers: Vec<(String, String)>,
+    user_agent: Option<String>,
+    accept_language: Option<Language>,
+    rate_limiter: Option<RateLimiter>,
+    retry_policy: Option<RetryPolicy>,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    server_clock: Option<ServerClock>,
+    time_sync_interval: Option<Option<Duration>>,
+}
+
+impl OkxClientBuilder {
+    /// 创建构建器，默认使用 `Config::default()`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置SDK配置（API地址、超时、请求有效期等）
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// 设置交易环境，默认与凭证一致；与凭证所属环境不一致时构建失败
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.environment = Some(environment);
+        self
+    }
+
+    /// 设置API凭证
+    pub fn credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = Some(CredentialStore::fixed(credentials));
+        self
+    }
+
+    /// 使用可轮换的凭证，可与其他客户端共享
+    pub fn credential_store(mut self, store: CredentialStore) -> Self {
+        self.credentials = Some(store);
+        self
+    }
+
+    /// 从凭证来源读取API凭证，构建时读取一次，之后可通过 `CredentialStore::refresh` 轮换
+    pub fn credential_provider(mut self, provider: impl CredentialProvider + 'static) -> Self {
+        self.credential_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// 使用预先构建的 `reqwest::Client`，此时不能再设置代理、超时、默认请求头和User-Agent
+    pub fn http_client(mut self, http_client: Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// 使用自定义传输层（如测试用的 `MockTransport`），此时不能再设置HTTP相关选项
+    pub fn transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// 设置代理，支持 `http://`、`https://`、`socks5://`、`socks5h://`
+    pub fn proxy(mut self, proxy_url: impl Into<String>) -> Self {
+        self.proxy = Some(proxy_url.into());
+        self
+    }
+
+    /// 是否使用系统代理（`HTTP_PROXY` / `HTTPS_PROXY` 等环境变量），默认不使用
+    pub fn system_proxy(mut self, enabled: bool) -> Self {
+        self.system_proxy = enabled;
+        self
+    }
+
+    /// 设置连接超时
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// 设置请求超时，默认使用 `Config::api_timeout_ms`
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// 添加默认请求头
+    pub fn default_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.default_headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// 设置User-Agent
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// 设置请求头中 Accept-Language
+    pub fn accept_language(mut self, accept_language: Language) -> Self {
+        self.accept_language = Some(accept_language);
+        self
+    }
+
+    /// 设置客户端限速器
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// 设置请求重试策略
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// 在中间件链末尾添加一个中间件
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// 设置服务器时钟，默认使用进程内共享的 `SERVER_CLOCK`
+    pub fn server_clock(mut self, server_clock: ServerClock) -> Self {
+        self.server_clock = Some(server_clock);
+        self
+    }
+
+    /// 设置服务器时间同步间隔，`None` 表示不自动同步
+    pub fn time_sync_interval(mut self, interval: Option<Duration>) -> Self {
+        self.time_sync_interval = Some(interval);
+        self
+    }
+
+    /// 构建OKX客户端，必须设置API凭证或凭证来源
+    pub fn build(self) -> Result<OkxClient, Error> {
+        if self.credentials.is_none() && self.credential_provider.is_none() {
+            return Err(Error::ConfigError(
+                "缺少API凭证，只访问公共接口时可以使用 build_public".to_string(),
+            ));
+        }
+        self.build_client()
+    }
+
+    /// 构建公共接口客户端
+    ///
+    /// 未设置API凭证时为匿名客户端，不发送任何签名请求头，交易环境由 `environment` 或配置决定；
+    /// 设置了凭证时公共接口也会签名，按账户计算限速。
+    pub fn build_public(self) -> Result<OkxPublicClient, Error> {
+        self.build_client().map(|inner| OkxPublicClient { inner })
+    }
+
+    fn build_client(self) -> Result<OkxClient, Error> {
+        let credentials = match (self.credentials, self.credential_provider) {
+            (Some(_), Some(_)) => {
+                return Err(Error::ConfigError(
+                    "不能同时设置API凭证和凭证来源".to_string(),
+                ));
+            }
+            (Some(store), None) => Some(store),
+            (None, Some(provider)) => Some(CredentialStore::from_provider(provider)?),
+            (None, None) => None,
+        };
+        let credential_environment = credentials.as_ref().map(|c| c.current().environment);
+        let mut config = self.config.unwrap_or_else(|| {
+            Config::for_environment(
+                credential_environment
+                    .or(self.environment)
+                    .unwrap_or_default(),
+            )
+        });
+        if let Some(env) = self.environment {
+            config = config.with_environment(env);
+        }
+        let environment = config.environment;
+        if let Some(credential_environment) = credential_environment {
+            if credential_environment != environment {
+                return Err(Error::ConfigError(format!(
+                    "API凭证属于{}环境，但客户端配置为{}环境",
+                    credential_environment, environment
+                )));
+            }
+        }
+
+        let has_http_options = self.proxy.is_some()
+            || self.connect_timeout.is_some()
+            || self.timeout.is_some()
+            || !self.default_headers.is_empty()
+            || self.user_agent.is_some();
+        let transport: Arc<dyn HttpTransport> = match (self.transport, self.http_client) {
+            (Some(_), Some(_)) => {
+                return Err(Error::ConfigError(
+                    "不能同时设置自定义传输层和reqwest::Client".to_string(),
+                ));
+            }
+            (Some(_), None) | (None, Some(_)) if has_http_options => {
+                return Err(Error::ConfigError(
+                    "使用自定义传输层或reqwest::Client时不能再设置代理、超时、默认请求头或User-Agent"
+                        .to_string(),
+                ));
             }
-            _ => {
-                error!("OKX API错误响应: {}", response_body);
-                Err(Error::OkxApiError {
-                    code: status_code.to_string(),
-                    message: response_body,
-                    smg: "".to_string(),
-                })
+            (Some(transport), None) => transport,
+            (None, Some(client)) => Arc::new(ReqwestTransport::new(client)),
+            (None, None) => {
+                let mut builder = Client::builder().timeout(
+                    self.timeout
+                        .unwrap_or(Duration::from_millis(config.api_timeout_ms)),
+                );
+                if !self.system_proxy {
+                    builder = builder.no_proxy();
+                }
+                if let Some(proxy_url) = &self.proxy {
+                    let proxy = Proxy::all(proxy_url).map_err(|e| {
+                        Error::ConfigError(format!("无效的代理地址 {}: {}", proxy_url, e))
+                    })?;
+                    builder = builder.proxy(proxy);
+                }
+                if let Some(connect_timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(connect_timeout);
+                }
+                if let Some(user_agent) = &self.user_agent {
+                    builder = builder.user_agent(user_agent);
+                }
+                if !self.default_headers.is_empty() {
+                    let mut headers = HeaderMap::new();
+                    for (name, value) in &self.default_headers {
+                        let header_name = HeaderName::from_bytes(name.as_bytes()).map_err(|e| {
+                            Error::ConfigError(format!("无效的请求头名称 {}: {}", name, e))
+                        })?;
+                        let header_value = HeaderValue::from_str(value).map_err(|e| {
+                            Error::ConfigError(format!("无效的请求头 {}: {}", name, e))
+                        })?;
+                        headers.insert(header_name, header_value);
+                    }
+                    builder = builder.default_headers(headers);
+                }
+                Arc::new(ReqwestTransport::new(
+                    builder.build().map_err(Error::HttpError)?,
+                ))
             }
+        };
+
+        Ok(OkxClient {
+            transport,
+            environment,
+            credentials,
+            base_url: config.api_url,
+            request_expiration_ms: config.request_expiration_ms,
+            accept_language: self.accept_language,
+            rate_limiter: self.rate_limiter.unwrap_or_default(),
+            retry_policy: self.retry_policy.unwrap_or_default(),
+            middlewares: self.middlewares,
+            server_clock: self.server_clock.unwrap_or_else(ServerClock::global),
+            time_sync_interval: self
+                .time_sync_interval
+                .unwrap_or(Some(DEFAULT_TIME_SYNC_INTERVAL)),
+        })
+    }
+}
+
+/// 插入请求头
+fn insert_header(headers: &mut HeaderMap, name: &'static str, value: &str) -> Result<(), Error> {
+    let value = HeaderValue::from_str(value)
+        .map_err(|e| Error::ParameterError(format!("无效的请求头 {}: {}", name, e)))?;
+    headers.insert(name, value);
+    Ok(())
+}
+
+/// 是否为本地时钟偏差导致的错误（时间戳过期、无效时间戳、expTime相关）
+fn is_timestamp_error(error: &Error) -> bool {
+    matches!(
+        error.api_code(),
+        Some(
+            ApiErrorCode::RequestTimestampExpired
+                | ApiErrorCode::InvalidOkAccessTimestamp
+                | ApiErrorCode::ExpTimeTooEarly
+                | ApiErrorCode::OrderExpired
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::tests::mock_client;
+    use crate::transport::{HttpResponse, MockTransport};
+
+    fn credentials() -> Credentials {
+        Credentials::new("key", "secret", "passphrase", Environment::Production)
+    }
+
+    #[tokio::test]
+    async fn builder_applies_config_headers_and_user_agent() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/api/v5/account/config")
+            .match_header("user-agent", "okx-rs-test")
+            .match_header("x-desk", "alpha")
+            .match_header("OK-ACCESS-KEY", "key")
+            .with_status(200)
+            .with_body(r#"{"code":"0","msg":"","data":[]}"#)
+            .create_async()
+            .await;
+
+        let client = OkxClient::builder()
+            .config(Config::new().with_api_url(server.url()))
+            .credentials(credentials())
+            .user_agent("okx-rs-test")
+            .default_header("x-desk", "alpha")
+            .connect_timeout(Duration::from_secs(1))
+            .build()
+            .unwrap();
+
+        let data = client
+            .send_get::<Vec<serde_json::Value>, _>("/api/v5/account/config", &())
+            .await
+            .unwrap();
+
+        assert!(data.is_empty());
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn builder_rejects_invalid_settings() {
+        let err = OkxClient::builder().build().unwrap_err();
+        assert!(matches!(err, Error::ConfigError(_)));
+
+        let err = OkxClient::builder()
+            .credentials(credentials())
+            .http_client(Client::new())
+            .proxy("socks5://127.0.0.1:1080")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, Error::ConfigError(_)));
+
+        let err = OkxClient::builder()
+            .credentials(credentials())
+            .default_header("bad header", "v")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, Error::ConfigError(_)));
+
+        assert!(OkxClient::builder()
+            .credentials(credentials())
+            .proxy("socks5h://127.0.0.1:1080")
+            .build()
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn send_request_with_meta_returns_gateway_times_and_headers() {
+        let transport = MockTransport::new();
+        transport.on(
+            Method::GET,
+            "/api/v5/account/config",
+            HttpResponse::new(
+                200,
+                r#"{"code":"0","msg":"","data":[],"inTime":"1752558485701589","outTime":"1752558485701884"}"#,
+            )
+            .with_header("x-ratelimit-limit", "20")
+            .with_header("x-ratelimit-remaining", "19")
+            .with_header("content-type", "application/json"),
+        );
+        let client = mock_client(&transport);
+
+        let (data, meta) = client
+            .send_get_with_meta::<Vec<serde_json::Value>, _>("/api/v5/account/config", &())
+            .await
+            .unwrap();
+
+        assert!(data.is_empty());
+        assert_eq!(meta.status, 200);
+        assert_eq!(meta.in_time, Some(1752558485701589));
+        assert_eq!(meta.out_time, Some(1752558485701884));
+        assert_eq!(meta.gateway_latency(), Some(Duration::from_micros(295)));
+        assert_eq!(meta.rate_limit_limit(), Some(20));
+        assert_eq!(meta.rate_limit_remaining(), Some(19));
+        assert_eq!(meta.rate_limit_headers().len(), 2);
+        assert_eq!(meta.retry_after(), None);
+    }
+
+    #[tokio::test]
+    async fn rotated_credentials_are_used_by_clones() {
+        let transport = MockTransport::new();
+        transport.on_data(Method::GET, "/api/v5/account/config", serde_json::json!([]));
+        let client = OkxClient::builder()
+            .credential_provider(credentials())
+            .transport(transport.clone())
+            .time_sync_interval(None)
+            .build()
+            .unwrap();
+        let cloned = client.clone();
+
+        client.set_credentials(Credentials::new(
+            "new-key",
+            "new-secret",
+            "new-pass",
+            Environment::Production,
+        ));
+        cloned
+            .send_get::<Vec<serde_json::Value>, _>("/api/v5/account/config", &())
+            .await
+            .unwrap();
+
+        let request = transport.last_request().unwrap();
+        assert_eq!(request.header("OK-ACCESS-KEY"), Some("new-key"));
+        assert_eq!(request.header("OK-ACCESS-PASSPHRASE"), Some("new-pass"));
+        assert!(request.verify_signature("new-secret"));
+    }
+
+    #[tokio::test]
+    async fn credentials_are_bound_to_their_environment() {
+        let demo = Credentials::new("key", "secret", "passphrase", Environment::Demo);
+        let err = OkxClient::builder()
+            .config(Config::for_environment(Environment::Production))
+            .credentials(demo.clone())
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, Error::ConfigError(_)));
+
+        let transport = MockTransport::new();
+        transport.on_data(Method::GET, "/api/v5/account/config", serde_json::json!([]));
+        let client = OkxClient::builder()
+            .credentials(demo)
+            .transport(transport.clone())
+            .time_sync_interval(None)
+            .build()
+            .unwrap();
+        assert_eq!(client.environment(), Environment::Demo);
+        client
+            .send_get::<Vec<serde_json::Value>, _>("/api/v5/account/config", &())
+            .await
+            .unwrap();
+        let request = transport.last_request().unwrap();
+        assert_eq!(request.header("x-simulated-trading"), Some("1"));
+
+        // 轮换为实盘凭证后拒绝发送
+        client.set_credentials(credentials());
+        let err = client
+            .send_get::<Vec<serde_json::Value>, _>("/api/v5/account/config", &())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::ConfigError(_)));
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn anonymous_public_client_sends_no_auth_headers() {
+        let transport = MockTransport::new();
+        transport.on_data(Method::GET, "/api/v5/market/ticker", serde_json::json!([]));
+        let client = OkxClient::builder()
+            .environment(Environment::Demo)
+            .transport(transport.clone())
+            .time_sync_interval(None)
+            .build_public()
+            .unwrap();
+        assert!(client.is_anonymous());
+        assert_eq!(client.environment(), Environment::Demo);
+
+        client
+            .send_get::<Vec<serde_json::Value>, _>(
+                "/api/v5/market/ticker",
+                &[("instId", "BTC-USDT")],
+            )
+            .await
+            .unwrap();
+
+        let request = transport.last_request().unwrap();
+        for header in [
+            "OK-ACCESS-KEY",
+            "OK-ACCESS-SIGN",
+            "OK-ACCESS-TIMESTAMP",
+            "OK-ACCESS-PASSPHRASE",
+        ] {
+            assert_eq!(request.header(header), None, "{}", header);
         }
+        assert_eq!(request.header("x-simulated-trading"), Some("1"));
+
+        let signed: OkxPublicClient = mock_client(&transport).into();
+        assert!(!signed.is_anonymous());
     }
 }
@@ -0,0 +1,220 @@
+use once_cell::sync::Lazy;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 默认的服务器时间同步间隔
+pub const DEFAULT_TIME_SYNC_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 进程内共享的服务器时钟，REST签名和WebSocket登录默认都使用它
+pub static SERVER_CLOCK: Lazy<ServerClock> = Lazy::new(ServerClock::new);
+
+#[derive(Debug, Default)]
+struct ClockState {
+    /// 服务器时间 - 本地时间（毫秒）
+    offset_ms: AtomicI64,
+    /// 上次同步的本地时间（毫秒），0表示从未同步
+    last_sync_ms: AtomicI64,
+    /// 是否有同步正在进行
+    syncing: AtomicBool,
+}
+
+/// 服务器时钟
+///
+/// 记录本地时钟与OKX服务器时钟的偏差，生成时间戳时自动补偿。克隆后共享同一份偏差。
+#[derive(Debug, Clone, Default)]
+pub struct ServerClock {
+    state: Arc<ClockState>,
+}
+
+impl ServerClock {
+    /// 创建一个新的服务器时钟，初始偏差为0
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 获取进程内共享的服务器时钟
+    pub fn global() -> Self {
+        SERVER_CLOCK.clone()
+    }
+
+    /// 当前偏差（毫秒），正数表示本地时钟落后于服务器
+    pub fn offset_ms(&self) -> i64 {
+        self.state.offset_ms.load(Ordering::Relaxed)
+    }
+
+    /// 手动设置偏差（毫秒）
+    pub fn set_offset_ms(&self, offset_ms: i64) {
+        self.state.offset_ms.store(offset_ms, Ordering::Relaxed);
+        self.state
+            .last_sync_ms
+            .store(local_now_ms(), Ordering::Relaxed);
+    }
+
+    /// 根据一次对时结果更新偏差，返回新的偏差
+    ///
+    /// 以请求发出和收到响应的中点作为服务器时间对应的本地时间
+    pub fn record_sample(&self, local_before_ms: i64, server_ms: i64, local_after_ms: i64) -> i64 {
+        let local_mid = local_before_ms + (local_after_ms - local_before_ms) / 2;
+        let offset_ms = server_ms - local_mid;
+        self.set_offset_ms(offset_ms);
+        offset_ms
+    }
+
+    /// 上次同步的本地时间（毫秒），从未同步时返回 `None`
+    pub fn last_sync_ms(&self) -> Option<i64> {
+        match self.state.last_sync_ms.load(Ordering::Relaxed) {
+            0 => None,
+            ms => Some(ms),
+        }
+    }
+
+    /// 距离上次同步是否已超过 `interval`
+    pub fn is_stale(&self, interval: Duration) -> bool {
+        match self.last_sync_ms() {
+            None => true,
+            Some(last) => local_now_ms() - last >= interval.as_millis() as i64,
+        }
+    }
+
+    /// 使当前偏差失效，下次请求前会重新同步
+    pub fn invalidate(&self) {
+        self.state.last_sync_ms.store(0, Ordering::Relaxed);
+    }
+
+    /// 尝试获取同步权，同一时间只有一个请求执行同步
+    pub(crate) fn try_begin_sync(&self) -> bool {
+        self.state
+            .syncing
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Relaxed)
+            .is_ok()
+    }
+
+    /// 结束同步；同步失败时也记录时间，避免每个请求都重复对时
+    pub(crate) fn end_sync(&self, succeeded: bool) {
+        if !succeeded {
+            self.state
+                .last_sync_ms
+                .store(local_now_ms(), Ordering::Relaxed);
+        }
+        self.state.syncing.store(false, Ordering::Release);
+    }
+
+    /// 补偿后的当前时间（毫秒）
+    pub fn now_ms(&self) -> i64 {
+        local_now_ms() + self.offset_ms()
+    }
+
+    /// 补偿后的ISO 8601格式时间戳，用于REST签名
+    pub fn timestamp(&self) -> String {
+        chrono::DateTime::from_timestamp_millis(self.now_ms())
+            .unwrap_or_else(chrono::Utc::now)
+            .format("%Y-%m-%dT%H:%M:%S.%3fZ")
+            .to_string()
+    }
+
+    /// 补偿后的秒级时间戳，用于WebSocket登录
+    pub fn timestamp_secs(&self) -> String {
+        (self.now_ms() / 1000).to_string()
+    }
+}
+
+fn local_now_ms() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::OkxClient;
+    use crate::config::Credentials;
+    use crate::enums::environment_enums::Environment;
+    use crate::retry::RetryPolicy;
+
+    #[test]
+    fn record_sample_uses_round_trip_midpoint() {
+        let clock = ServerClock::new();
+        assert!(clock.is_stale(DEFAULT_TIME_SYNC_INTERVAL));
+
+        let offset = clock.record_sample(1_000, 6_050, 1_100);
+        assert_eq!(offset, 5_000);
+        assert_eq!(clock.offset_ms(), 5_000);
+        assert!(!clock.is_stale(DEFAULT_TIME_SYNC_INTERVAL));
+
+        clock.invalidate();
+        assert!(clock.is_stale(DEFAULT_TIME_SYNC_INTERVAL));
+    }
+
+    #[test]
+    fn timestamps_apply_offset() {
+        let clock = ServerClock::new();
+        clock.set_offset_ms(-3_600_000);
+
+        let local_secs = chrono::Utc::now().timestamp();
+        let secs: i64 = clock.timestamp_secs().parse().unwrap();
+        assert!((local_secs - 3_600 - secs).abs() <= 1);
+
+        let ts = chrono::DateTime::parse_from_rfc3339(&clock.timestamp()).unwrap();
+        assert!((local_secs - 3_600 - ts.timestamp()).abs() <= 1);
+    }
+
+    #[test]
+    fn clones_share_offset() {
+        let clock = ServerClock::new();
+        let cloned = clock.clone();
+        clock.set_offset_ms(42);
+        assert_eq!(cloned.offset_ms(), 42);
+    }
+
+    #[tokio::test]
+    async fn client_resyncs_after_timestamp_error() {
+        let mut server = mockito::Server::new_async().await;
+        let server_ms = chrono::Utc::now().timestamp_millis() + 10_000;
+        let time_mock = server
+            .mock("GET", "/api/v5/public/time")
+            .with_status(200)
+            .with_body(format!(
+                r#"{{"code":"0","msg":"","data":[{{"ts":"{}"}}]}}"#,
+                server_ms
+            ))
+            .expect(2)
+            .create_async()
+            .await;
+        let expired_mock = server
+            .mock("GET", "/api/v5/account/config")
+            .with_status(401)
+            .with_body(r#"{"code":"50102","msg":"Timestamp request expired","data":[]}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let clock = ServerClock::new();
+        let mut client = OkxClient::new(Credentials::new(
+            "key",
+            "secret",
+            "passphrase",
+            Environment::Production,
+        ))
+        .expect("client");
+        client.set_base_url(server.url());
+        client.set_server_clock(clock.clone());
+        client.set_retry_policy(
+            RetryPolicy::default()
+                .with_max_attempts(2)
+                .with_max_delay(Duration::from_millis(1)),
+        );
+
+        let err = client
+            .send_get::<serde_json::Value, _>("/api/v5/account/config", &())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.api_code(),
+            Some(crate::error::ApiErrorCode::RequestTimestampExpired)
+        );
+        assert!((clock.offset_ms() - 10_000).abs() < 1_000);
+        time_mock.assert_async().await;
+        expired_mock.assert_async().await;
+    }
+}
@@ -1,7 +1,15 @@
+use crate::enums::environment_enums::Environment;
 use crate::error::Error;
+use crate::secret::SecretString;
+use crate::websocket::auto_reconnect_client::ReconnectConfig;
+use crate::websocket::manager::ManagerConfig;
 use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::collections::HashMap;
 use std::env;
+use std::path::Path;
 use std::sync::Once;
+use url::Url;
 
 /// OKX API的默认基础URL
 pub const DEFAULT_API_URL: &str = "https://www.okx.com";
@@ -18,53 +26,28 @@ pub const DEFAULT_API_TIMEOUT_MS: u64 = 5000;
 /// 默认请求有效时间（毫秒）
 pub const DEFAULT_REQUEST_EXPIRATION_MS: i64 = 1000;
 
+/// 配置文件环境变量覆盖的前缀，例如 `OKX_API_TIMEOUT_MS`、`OKX_RECONNECT__INTERVAL`
+pub const CONFIG_ENV_PREFIX: &str = "OKX";
+
+/// 配置文件环境变量覆盖中嵌套字段的分隔符
+pub const CONFIG_ENV_SEPARATOR: &str = "__";
+
 /// 环境初始化状态
 static INIT_ENV: Once = Once::new();
 
 /// 全局配置
-pub static CONFIG: Lazy<Config> = Lazy::new(|| {
-    // Ensure .env is loaded before reading env vars
-    init_env();
-
-    let mut config = Config::default();
-
-    if let Ok(api_url) = env::var("OKX_API_URL") {
-        config.api_url = api_url;
-    }
-    if let Ok(ws_url) = env::var("OKX_WEBSOCKET_URL") {
-        config.websocket_url = ws_url;
-    }
-    if let Ok(ws_url) = env::var("WS_PUBLIC_URL") {
-        config.websocket_url = ws_url;
-    }
-    if let Ok(private_ws_url) = env::var("OKX_PRIVATE_WEBSOCKET_URL") {
-        config.private_websocket_url = private_ws_url;
-    }
-    if let Ok(private_ws_url) = env::var("WS_PRIVATE_URL") {
-        config.private_websocket_url = private_ws_url;
-    }
-    if let Ok(business_ws_url) = env::var("OKX_BUSINESS_WEBSOCKET_URL") {
-        config.business_websocket_url = business_ws_url;
-    }
-    if let Ok(business_ws_url) = env::var("WS_BUSINESS_URL") {
-        config.business_websocket_url = business_ws_url;
-    }
-    if let Ok(timeout) = env::var("OKX_API_TIMEOUT_MS").map(|v| v.parse::<u64>()) {
-        if let Ok(timeout) = timeout {
-            config.api_timeout_ms = timeout;
-        }
-    }
-    if let Ok(expiration) = env::var("OKX_REQUEST_EXPIRATION_MS").map(|v| v.parse::<i64>()) {
-        if let Ok(expiration) = expiration {
-            config.request_expiration_ms = expiration;
-        }
-    }
-    if let Ok(value) = env::var("OKX_SIMULATED_TRADING") {
-        config.is_simulated_trading = value;
-    }
+///
+/// `OKX_ENVIRONMENT` 或 `OKX_SIMULATED_TRADING` 的值无效时首次访问会panic，
+/// 需要处理错误时使用 `Config::from_env`。
+pub static CONFIG: Lazy<Config> =
+    Lazy::new(|| Config::from_env().unwrap_or_else(|e| panic!("读取全局配置失败: {}", e)));
 
-    config
-});
+/// 读取全局配置，交易环境无效时返回错误而不是panic
+pub(crate) fn global_config() -> Result<Config, Error> {
+    init_env();
+    Environment::from_env_prefix("OKX_")?;
+    Ok(CONFIG.clone())
+}
 
 /// OKX SDK配置
 #[derive(Debug, Clone)]
@@ -81,8 +64,14 @@ pub struct Config {
     pub api_timeout_ms: u64,
     /// 请求有效时间（毫秒）
     pub request_expiration_ms: i64,
-    /// 是否为模拟交易
-    pub is_simulated_trading: String,
+    /// 交易环境
+    pub environment: Environment,
+    /// WebSocket自动重连配置
+    pub reconnect: ReconnectConfig,
+    /// WebSocket连接管理器配置
+    pub manager: ManagerConfig,
+    /// 命名的凭证配置
+    pub credential_profiles: HashMap<String, Credentials>,
 }
 
 impl Default for Config {
@@ -94,7 +83,10 @@ impl Default for Config {
             business_websocket_url: DEFAULT_BUSINESS_WEBSOCKET_URL.to_string(),
             api_timeout_ms: DEFAULT_API_TIMEOUT_MS,
             request_expiration_ms: DEFAULT_REQUEST_EXPIRATION_MS,
-            is_simulated_trading: "1".into(),
+            environment: Environment::Production,
+            reconnect: ReconnectConfig::default(),
+            manager: ManagerConfig::default(),
+            credential_profiles: HashMap::new(),
         }
     }
 }
@@ -105,6 +97,94 @@ impl Config {
         Self::default()
     }
 
+    /// 从环境变量读取配置
+    ///
+    /// 交易环境由 `OKX_ENVIRONMENT` 或 `OKX_SIMULATED_TRADING` 决定，值无效时返回错误，都未设置时使用实盘；
+    /// `OKX_API_URL`、`OKX_WEBSOCKET_URL` 等变量覆盖对应的地址。
+    pub fn from_env() -> Result<Self, Error> {
+        // Ensure .env is loaded before reading env vars
+        init_env();
+
+        let mut config = Environment::from_env_prefix("OKX_")?
+            .map(Config::for_environment)
+            .unwrap_or_default();
+
+        if let Ok(api_url) = env::var("OKX_API_URL") {
+            config.api_url = api_url;
+        }
+        if let Ok(ws_url) = env::var("OKX_WEBSOCKET_URL") {
+            config.websocket_url = ws_url;
+        }
+        if let Ok(ws_url) = env::var("WS_PUBLIC_URL") {
+            config.websocket_url = ws_url;
+        }
+        if let Ok(private_ws_url) = env::var("OKX_PRIVATE_WEBSOCKET_URL") {
+            config.private_websocket_url = private_ws_url;
+        }
+        if let Ok(private_ws_url) = env::var("WS_PRIVATE_URL") {
+            config.private_websocket_url = private_ws_url;
+        }
+        if let Ok(business_ws_url) = env::var("OKX_BUSINESS_WEBSOCKET_URL") {
+            config.business_websocket_url = business_ws_url;
+        }
+        if let Ok(business_ws_url) = env::var("WS_BUSINESS_URL") {
+            config.business_websocket_url = business_ws_url;
+        }
+        if let Ok(Ok(timeout)) = env::var("OKX_API_TIMEOUT_MS").map(|v| v.parse::<u64>()) {
+            config.api_timeout_ms = timeout;
+        }
+        if let Ok(Ok(expiration)) =
+            env::var("OKX_REQUEST_EXPIRATION_MS").map(|v| v.parse::<i64>())
+        {
+            config.request_expiration_ms = expiration;
+        }
+        Ok(config)
+    }
+
+    /// 创建指定环境的配置，API和WebSocket地址使用该环境的默认值
+    pub fn for_environment(environment: Environment) -> Self {
+        Self {
+            api_url: environment.rest_url().to_string(),
+            websocket_url: environment.public_websocket_url().to_string(),
+            private_websocket_url: environment.private_websocket_url().to_string(),
+            business_websocket_url: environment.business_websocket_url().to_string(),
+            environment,
+            ..Self::default()
+        }
+    }
+
+    /// 切换交易环境，未自定义的API和WebSocket地址随环境切换
+    pub fn with_environment(mut self, environment: Environment) -> Self {
+        let previous = self.environment;
+        let switch = |url: &mut String, old: &str, new: &str| {
+            if url == old {
+                *url = new.to_string();
+            }
+        };
+        switch(
+            &mut self.api_url,
+            previous.rest_url(),
+            environment.rest_url(),
+        );
+        switch(
+            &mut self.websocket_url,
+            previous.public_websocket_url(),
+            environment.public_websocket_url(),
+        );
+        switch(
+            &mut self.private_websocket_url,
+            previous.private_websocket_url(),
+            environment.private_websocket_url(),
+        );
+        switch(
+            &mut self.business_websocket_url,
+            previous.business_websocket_url(),
+            environment.business_websocket_url(),
+        );
+        self.environment = environment;
+        self
+    }
+
     /// 设置API基础URL
     pub fn with_api_url(mut self, api_url: impl Into<String>) -> Self {
         self.api_url = api_url.into();
@@ -124,7 +204,10 @@ impl Config {
     }
 
     /// 设置业务WebSocket URL
-    pub fn with_business_websocket_url(mut self, business_websocket_url: impl Into<String>) -> Self {
+    pub fn with_business_websocket_url(
+        mut self,
+        business_websocket_url: impl Into<String>,
+    ) -> Self {
         self.business_websocket_url = business_websocket_url.into();
         self
     }
@@ -141,23 +224,248 @@ impl Config {
         self
     }
 
-    /// 设置是否为模拟交易
-    pub fn with_simulated_trading(mut self, is_simulated: String) -> Self {
-        self.is_simulated_trading = is_simulated;
+    /// 设置WebSocket自动重连配置
+    pub fn with_reconnect(mut self, reconnect: ReconnectConfig) -> Self {
+        self.reconnect = reconnect;
         self
     }
+
+    /// 设置WebSocket连接管理器配置
+    pub fn with_manager(mut self, manager: ManagerConfig) -> Self {
+        self.manager = manager;
+        self
+    }
+
+    /// 添加命名的凭证配置，同名配置会被替换
+    pub fn with_credential_profile(
+        mut self,
+        name: impl Into<String>,
+        credentials: Credentials,
+    ) -> Self {
+        self.credential_profiles.insert(name.into(), credentials);
+        self
+    }
+
+    /// 获取命名的凭证配置
+    pub fn credentials(&self, profile: &str) -> Result<Credentials, Error> {
+        self.credential_profiles
+            .get(profile)
+            .cloned()
+            .ok_or_else(|| Error::ConfigError(format!("未找到凭证配置: {}", profile)))
+    }
+
+    /// 从配置文件加载，格式由扩展名决定（TOML / YAML / JSON），再应用 `OKX_` 前缀的环境变量覆盖
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_sources(&[path])
+    }
+
+    /// 按顺序加载多个配置文件，后面的文件覆盖前面的同名字段，最后应用环境变量覆盖
+    ///
+    /// 环境变量使用 `OKX_` 前缀，嵌套字段用 `__` 分隔，例如 `OKX_ENVIRONMENT=demo`、
+    /// `OKX_RECONNECT__MAX_ATTEMPTS=10`、`OKX_CREDENTIALS__MAIN__API_KEY=...`。
+    /// 未设置的字段使用所选环境的默认值，加载后会校验整个配置。
+    pub fn from_sources<P: AsRef<Path>>(paths: &[P]) -> Result<Self, Error> {
+        init_env();
+        Self::load(
+            paths,
+            config::Environment::with_prefix(CONFIG_ENV_PREFIX)
+                .prefix_separator("_")
+                .separator(CONFIG_ENV_SEPARATOR),
+        )
+    }
+
+    fn load<P: AsRef<Path>>(paths: &[P], overrides: config::Environment) -> Result<Self, Error> {
+        let mut builder = config::Config::builder();
+        for path in paths {
+            let path = path.as_ref();
+            if !path.is_file() {
+                return Err(Error::ConfigError(format!(
+                    "配置文件不存在: {}",
+                    path.display()
+                )));
+            }
+            builder = builder.add_source(config::File::from(path));
+        }
+        let file: ConfigFile = builder
+            .add_source(overrides)
+            .build()
+            .and_then(|source| source.try_deserialize())
+            .map_err(|e| Error::ConfigError(format!("解析配置失败: {}", e)))?;
+        let config = file.into_config()?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// 校验配置，返回第一个不合法的字段
+    pub fn validate(&self) -> Result<(), Error> {
+        validate_url("api_url", &self.api_url, &["http", "https"])?;
+        for (field, url) in [
+            ("websocket_url", &self.websocket_url),
+            ("private_websocket_url", &self.private_websocket_url),
+            ("business_websocket_url", &self.business_websocket_url),
+        ] {
+            validate_url(field, url, &["ws", "wss"])?;
+        }
+        if self.api_timeout_ms == 0 {
+            return Err(invalid("api_timeout_ms", "必须大于0"));
+        }
+        if self.request_expiration_ms <= 0 {
+            return Err(invalid("request_expiration_ms", "必须大于0"));
+        }
+
+        let reconnect = &self.reconnect;
+        if reconnect.interval == 0 {
+            return Err(invalid("reconnect.interval", "必须大于0"));
+        }
+        if !reconnect.backoff_factor.is_finite() || reconnect.backoff_factor < 1.0 {
+            return Err(invalid("reconnect.backoff_factor", "必须不小于1"));
+        }
+        if reconnect.max_backoff < reconnect.interval {
+            return Err(invalid(
+                "reconnect.max_backoff",
+                "不能小于 reconnect.interval",
+            ));
+        }
+        if reconnect.heartbeat_interval == 0 {
+            return Err(invalid("reconnect.heartbeat_interval", "必须大于0"));
+        }
+        if reconnect.message_timeout <= reconnect.heartbeat_interval {
+            return Err(invalid(
+                "reconnect.message_timeout",
+                "必须大于 reconnect.heartbeat_interval",
+            ));
+        }
+
+        let manager = &self.manager;
+        if manager.reconnect_interval == 0 {
+            return Err(invalid("manager.reconnect_interval", "必须大于0"));
+        }
+        if manager.heartbeat_interval == 0 {
+            return Err(invalid("manager.heartbeat_interval", "必须大于0"));
+        }
+        if manager.message_timeout <= manager.heartbeat_interval {
+            return Err(invalid(
+                "manager.message_timeout",
+                "必须大于 manager.heartbeat_interval",
+            ));
+        }
+
+        for (name, credentials) in &self.credential_profiles {
+            for (field, value) in [
+                ("api_key", credentials.api_key.as_str()),
+                ("api_secret", credentials.api_secret.expose()),
+                ("passphrase", credentials.passphrase.expose()),
+            ] {
+                if value.trim().is_empty() {
+                    return Err(invalid(
+                        &format!("credentials.{}.{}", name, field),
+                        "不能为空",
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 配置文件内容，未设置的字段使用所选环境的默认值
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ConfigFile {
+    environment: Option<Environment>,
+    /// 兼容 `OKX_SIMULATED_TRADING`，仅在未设置 `environment` 时使用
+    simulated_trading: Option<String>,
+    api_url: Option<String>,
+    websocket_url: Option<String>,
+    private_websocket_url: Option<String>,
+    business_websocket_url: Option<String>,
+    api_timeout_ms: Option<u64>,
+    request_expiration_ms: Option<i64>,
+    reconnect: ReconnectConfig,
+    manager: ManagerConfig,
+    credentials: HashMap<String, CredentialProfile>,
+}
+
+/// 配置文件中的凭证，未设置 `environment` 时使用配置的交易环境
+#[derive(Debug, Deserialize)]
+struct CredentialProfile {
+    api_key: String,
+    api_secret: String,
+    passphrase: String,
+    environment: Option<Environment>,
+}
+
+impl ConfigFile {
+    fn into_config(self) -> Result<Config, Error> {
+        let environment = match (self.environment, &self.simulated_trading) {
+            (Some(environment), _) => environment,
+            (None, Some(flag)) => Environment::from_simulated_flag(flag)?,
+            (None, None) => Environment::default(),
+        };
+        let mut config = Config::for_environment(environment);
+        if let Some(api_url) = self.api_url {
+            config.api_url = api_url;
+        }
+        if let Some(websocket_url) = self.websocket_url {
+            config.websocket_url = websocket_url;
+        }
+        if let Some(private_websocket_url) = self.private_websocket_url {
+            config.private_websocket_url = private_websocket_url;
+        }
+        if let Some(business_websocket_url) = self.business_websocket_url {
+            config.business_websocket_url = business_websocket_url;
+        }
+        if let Some(timeout) = self.api_timeout_ms {
+            config.api_timeout_ms = timeout;
+        }
+        if let Some(expiration) = self.request_expiration_ms {
+            config.request_expiration_ms = expiration;
+        }
+        config.reconnect = self.reconnect;
+        config.manager = self.manager;
+        config.credential_profiles = self
+            .credentials
+            .into_iter()
+            .map(|(name, profile)| {
+                let credentials = Credentials::new(
+                    profile.api_key,
+                    profile.api_secret,
+                    profile.passphrase,
+                    profile.environment.unwrap_or(environment),
+                );
+                (name, credentials)
+            })
+            .collect();
+        Ok(config)
+    }
+}
+
+fn invalid(field: &str, reason: &str) -> Error {
+    Error::ConfigError(format!("配置项 {} 无效: {}", field, reason))
+}
+
+fn validate_url(field: &str, value: &str, schemes: &[&str]) -> Result<(), Error> {
+    let url = Url::parse(value).map_err(|e| invalid(field, &format!("{} ({})", e, value)))?;
+    if !schemes.contains(&url.scheme()) {
+        return Err(invalid(
+            field,
+            &format!("协议必须是 {} ({})", schemes.join(" / "), value),
+        ));
+    }
+    Ok(())
 }
 
 /// API凭证
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Credentials {
     /// API密钥
     pub api_key: String,
     /// API密钥
-    pub api_secret: String,
+    pub api_secret: SecretString,
     /// API密码
-    pub passphrase: String,
-    pub is_simulated_trading: String,
+    pub passphrase: SecretString,
+    /// API Key所属的交易环境
+    pub environment: Environment,
 }
 
 impl Credentials {
@@ -166,17 +474,17 @@ impl Credentials {
         api_key: impl Into<String>,
         api_secret: impl Into<String>,
         passphrase: impl Into<String>,
-        is_simulated_trading: impl Into<String>,
+        environment: Environment,
     ) -> Self {
         Self {
             api_key: api_key.into(),
-            api_secret: api_secret.into(),
-            passphrase: passphrase.into(),
-            is_simulated_trading: is_simulated_trading.into(),
+            api_secret: SecretString::new(api_secret),
+            passphrase: SecretString::new(passphrase),
+            environment,
         }
     }
 
-    /// 从环境变量读取凭证
+    /// 从 `OKX_SIMULATED_*` 环境变量读取模拟盘凭证
     pub fn from_env_with_simulated_trading() -> Result<Self, Error> {
         // 确保环境变量已加载
         init_env();
@@ -191,15 +499,14 @@ impl Credentials {
             Error::ConfigError("缺少环境变量: OKX_SIMULATED_PASSPHRASE".to_string())
         })?;
 
-        let is_simulated_trading = "1".to_string();
         Ok(Self::new(
             api_key,
             api_secret,
             passphrase,
-            is_simulated_trading,
+            Environment::Demo,
         ))
     }
-    /// 从环境变量读取凭证
+    /// 从环境变量读取凭证，环境由 `OKX_ENVIRONMENT` 或 `OKX_SIMULATED_TRADING` 决定
     pub fn from_env() -> Result<Self, Error> {
         // 确保环境变量已加载
         init_env();
@@ -212,14 +519,8 @@ impl Credentials {
         let passphrase = env::var("OKX_PASSPHRASE")
             .map_err(|_| Error::ConfigError("缺少环境变量: OKX_PASSPHRASE".to_string()))?;
 
-        let is_simulated_trading = env::var("OKX_SIMULATED_TRADING")
-            .map_err(|_| Error::ConfigError("缺少环境变量: OKX_SIMULATED_TRADING".to_string()))?;
-        Ok(Self::new(
-            api_key,
-            api_secret,
-            passphrase,
-            is_simulated_trading,
-        ))
+        let environment = Environment::from_env()?;
+        Ok(Self::new(api_key, api_secret, passphrase, environment))
     }
 }
 
@@ -230,3 +531,130 @@ pub fn init_env() {
         dotenv::dotenv().ok();
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn write_temp(name: &str, content: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("okx-config-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    fn overrides(vars: &[(&str, &str)]) -> config::Environment {
+        let source = vars
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        config::Environment::with_prefix(CONFIG_ENV_PREFIX)
+            .prefix_separator("_")
+            .separator(CONFIG_ENV_SEPARATOR)
+            .source(Some(source))
+    }
+
+    #[test]
+    fn loads_layered_files_with_env_overrides() {
+        let base = write_temp(
+            "base.toml",
+            r#"
+environment = "demo"
+api_timeout_ms = 3000
+
+[reconnect]
+max_attempts = 5
+
+[manager]
+auto_reconnect = false
+
+[credentials.main]
+api_key = "demo-key"
+api_secret = "demo-secret"
+passphrase = "demo-pass"
+
+[credentials.live]
+api_key = "live-key"
+api_secret = "live-secret"
+passphrase = "live-pass"
+environment = "production"
+"#,
+        );
+        let overlay = write_temp(
+            "overlay.yaml",
+            "request_expiration_ms: 2000\nreconnect:\n  interval: 2\n",
+        );
+
+        let config = Config::load(
+            &[&base, &overlay],
+            overrides(&[
+                ("OKX_API_TIMEOUT_MS", "1500"),
+                ("OKX_CREDENTIALS__MAIN__PASSPHRASE", "0123"),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(config.environment, Environment::Demo);
+        assert_eq!(
+            config.private_websocket_url,
+            Environment::Demo.private_websocket_url()
+        );
+        assert_eq!(config.api_timeout_ms, 1500);
+        assert_eq!(config.request_expiration_ms, 2000);
+        assert_eq!(config.reconnect.interval, 2);
+        assert_eq!(config.reconnect.max_attempts, 5);
+        assert_eq!(config.reconnect.heartbeat_interval, 3);
+        assert!(!config.manager.auto_reconnect);
+
+        let main = config.credentials("main").unwrap();
+        assert_eq!(main.environment, Environment::Demo);
+        assert_eq!(main.passphrase.expose(), "0123");
+        assert_eq!(
+            config.credentials("live").unwrap().environment,
+            Environment::Production
+        );
+        assert!(config.credentials("missing").is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_configuration() {
+        let cases = [
+            ("api_url = \"www.okx.com\"", "api_url"),
+            ("websocket_url = \"https://ws.okx.com\"", "websocket_url"),
+            ("api_timeout_ms = 0", "api_timeout_ms"),
+            (
+                "[reconnect]\nbackoff_factor = 0.5",
+                "reconnect.backoff_factor",
+            ),
+            ("[manager]\nmessage_timeout = 10", "manager.message_timeout"),
+            (
+                "[credentials.main]\napi_key = \"\"\napi_secret = \"s\"\npassphrase = \"p\"",
+                "credentials.main.api_key",
+            ),
+        ];
+        for (index, (content, field)) in cases.into_iter().enumerate() {
+            let path = write_temp(&format!("invalid-{}.toml", index), content);
+            match Config::load(&[&path], overrides(&[])) {
+                Err(Error::ConfigError(message)) => {
+                    assert!(message.contains(field), "{}: {}", field, message)
+                }
+                other => panic!("{} 应当校验失败: {:?}", field, other.map(|_| ())),
+            }
+        }
+
+        let missing = env::temp_dir().join("okx-config-missing.toml");
+        assert!(matches!(
+            Config::from_file(missing),
+            Err(Error::ConfigError(_))
+        ));
+        assert!(matches!(
+            Config::load(
+                &[&write_temp("bad-env.toml", "")],
+                overrides(&[("OKX_ENVIRONMENT", "staging")])
+            ),
+            Err(Error::ConfigError(_))
+        ));
+    }
+}
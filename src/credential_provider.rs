@@ -0,0 +1,490 @@
+use crate::config::{init_env, Credentials};
+use crate::enums::environment_enums::Environment;
+use crate::error::Error;
+use crate::secret::SecretString;
+use aes_gcm::aead::Aead;
+use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
+use base64::{engine::general_purpose, Engine as _};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::env;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// 环境变量凭证的默认前缀
+pub const DEFAULT_ENV_PREFIX: &str = "OKX_";
+
+/// 加密密钥库默认的PBKDF2迭代次数
+pub const DEFAULT_KEYSTORE_ITERATIONS: u32 = 100_000;
+
+/// 加密密钥库的文件格式版本
+const KEYSTORE_VERSION: u32 = 1;
+
+/// 加密密钥库使用的密钥派生算法
+const KEYSTORE_KDF: &str = "pbkdf2-hmac-sha256";
+
+/// 凭证来源
+///
+/// 每次调用 `load` 都会重新读取来源，配合 `CredentialStore::refresh` 实现密钥轮换。
+pub trait CredentialProvider: fmt::Debug + Send + Sync {
+    /// 读取当前凭证
+    fn load(&self) -> Result<Credentials, Error>;
+}
+
+impl CredentialProvider for Credentials {
+    fn load(&self) -> Result<Credentials, Error> {
+        Ok(self.clone())
+    }
+}
+
+/// 凭证文件内容，同时用于TOML/JSON文件和加密密钥库的明文
+#[derive(Serialize, Deserialize)]
+struct CredentialFile {
+    api_key: String,
+    api_secret: String,
+    passphrase: String,
+    #[serde(default)]
+    environment: Environment,
+}
+
+impl From<CredentialFile> for Credentials {
+    fn from(file: CredentialFile) -> Self {
+        Credentials::new(
+            file.api_key,
+            file.api_secret,
+            file.passphrase,
+            file.environment,
+        )
+    }
+}
+
+impl From<&Credentials> for CredentialFile {
+    fn from(credentials: &Credentials) -> Self {
+        Self {
+            api_key: credentials.api_key.clone(),
+            api_secret: credentials.api_secret.expose().to_string(),
+            passphrase: credentials.passphrase.expose().to_string(),
+            environment: credentials.environment,
+        }
+    }
+}
+
+/// 从环境变量读取凭证
+///
+/// 读取 `{prefix}API_KEY`、`{prefix}API_SECRET`、`{prefix}PASSPHRASE`，
+/// 环境由 `{prefix}ENVIRONMENT` 或 `{prefix}SIMULATED_TRADING` 决定，都未设置时使用 `with_environment` 指定的环境（默认实盘）。
+#[derive(Debug, Clone)]
+pub struct EnvCredentialProvider {
+    prefix: String,
+    environment: Environment,
+}
+
+impl Default for EnvCredentialProvider {
+    fn default() -> Self {
+        Self::new(DEFAULT_ENV_PREFIX)
+    }
+}
+
+impl EnvCredentialProvider {
+    /// 使用指定前缀创建，如 `"OKX_"`、`"OKX_SIMULATED_"`
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            environment: Environment::Production,
+        }
+    }
+
+    /// 设置环境变量中未指定环境时使用的交易环境
+    pub fn with_environment(mut self, environment: Environment) -> Self {
+        self.environment = environment;
+        self
+    }
+
+    fn var(&self, name: &str) -> Result<String, Error> {
+        let key = format!("{}{}", self.prefix, name);
+        env::var(&key).map_err(|_| Error::ConfigError(format!("缺少环境变量: {}", key)))
+    }
+}
+
+impl CredentialProvider for EnvCredentialProvider {
+    fn load(&self) -> Result<Credentials, Error> {
+        init_env();
+        Ok(Credentials::new(
+            self.var("API_KEY")?,
+            self.var("API_SECRET")?,
+            self.var("PASSPHRASE")?,
+            Environment::from_env_prefix(&self.prefix)?.unwrap_or(self.environment),
+        ))
+    }
+}
+
+/// 从TOML或JSON文件读取凭证，格式由扩展名决定
+///
+/// 文件中包含 `api_key`、`api_secret`、`passphrase` 以及可选的 `environment`（默认 `production`）；
+/// 设置 `profile` 时从同名的表中读取。
+#[derive(Debug, Clone)]
+pub struct FileCredentialProvider {
+    path: PathBuf,
+    profile: Option<String>,
+}
+
+impl FileCredentialProvider {
+    /// 创建文件凭证来源
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            profile: None,
+        }
+    }
+
+    /// 从文件中指定的表读取凭证
+    pub fn with_profile(mut self, profile: impl Into<String>) -> Self {
+        self.profile = Some(profile.into());
+        self
+    }
+}
+
+impl CredentialProvider for FileCredentialProvider {
+    fn load(&self) -> Result<Credentials, Error> {
+        let source = config::Config::builder()
+            .add_source(config::File::from(self.path.as_path()))
+            .build()
+            .map_err(|e| {
+                Error::ConfigError(format!("读取凭证文件失败 {}: {}", self.path.display(), e))
+            })?;
+        let file: CredentialFile = match &self.profile {
+            Some(profile) => source.get(profile),
+            None => source.try_deserialize(),
+        }
+        .map_err(|e| {
+            Error::ConfigError(format!("解析凭证文件失败 {}: {}", self.path.display(), e))
+        })?;
+        Ok(file.into())
+    }
+}
+
+/// 加密密钥库文件内容
+#[derive(Serialize, Deserialize)]
+struct KeystoreFile {
+    version: u32,
+    kdf: String,
+    iterations: u32,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// 使用口令加密的本地密钥库
+///
+/// 使用PBKDF2-HMAC-SHA256从口令派生密钥，AES-256-GCM加密凭证，文件为JSON格式。
+/// 使用 `save` 生成或更新密钥库。
+#[derive(Debug, Clone)]
+pub struct KeystoreCredentialProvider {
+    path: PathBuf,
+    password: SecretString,
+    iterations: u32,
+}
+
+impl KeystoreCredentialProvider {
+    /// 创建密钥库凭证来源
+    pub fn new(path: impl Into<PathBuf>, password: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            password: SecretString::new(password),
+            iterations: DEFAULT_KEYSTORE_ITERATIONS,
+        }
+    }
+
+    /// 设置 `save` 时使用的PBKDF2迭代次数，读取时以文件中记录的次数为准
+    pub fn with_iterations(mut self, iterations: u32) -> Self {
+        self.iterations = iterations.max(1);
+        self
+    }
+
+    /// 加密凭证并写入密钥库文件，已存在时覆盖
+    pub fn save(&self, credentials: &Credentials) -> Result<(), Error> {
+        let mut salt = [0u8; 16];
+        let mut nonce = [0u8; 12];
+        rand::rng().fill(&mut salt);
+        rand::rng().fill(&mut nonce);
+
+        let plaintext =
+            serde_json::to_vec(&CredentialFile::from(credentials)).map_err(Error::JsonError)?;
+        let cipher = Self::cipher(self.password.expose(), &salt, self.iterations)?;
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_slice())
+            .map_err(|_| Error::ConfigError("加密凭证失败".to_string()))?;
+
+        let keystore = KeystoreFile {
+            version: KEYSTORE_VERSION,
+            kdf: KEYSTORE_KDF.to_string(),
+            iterations: self.iterations,
+            salt: general_purpose::STANDARD.encode(salt),
+            nonce: general_purpose::STANDARD.encode(nonce),
+            ciphertext: general_purpose::STANDARD.encode(ciphertext),
+        };
+        let content = serde_json::to_string_pretty(&keystore).map_err(Error::JsonError)?;
+        std::fs::write(&self.path, content).map_err(|e| {
+            Error::ConfigError(format!("写入密钥库失败 {}: {}", self.path.display(), e))
+        })
+    }
+
+    fn cipher(password: &str, salt: &[u8], iterations: u32) -> Result<Aes256Gcm, Error> {
+        let key = pbkdf2::pbkdf2_hmac_array::<Sha256, 32>(password.as_bytes(), salt, iterations);
+        Aes256Gcm::new_from_slice(&key)
+            .map_err(|e| Error::ConfigError(format!("无效的密钥: {}", e)))
+    }
+
+    fn read(path: &Path) -> Result<KeystoreFile, Error> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::ConfigError(format!("读取密钥库失败 {}: {}", path.display(), e)))?;
+        serde_json::from_str(&content)
+            .map_err(|e| Error::ConfigError(format!("解析密钥库失败 {}: {}", path.display(), e)))
+    }
+}
+
+impl CredentialProvider for KeystoreCredentialProvider {
+    fn load(&self) -> Result<Credentials, Error> {
+        let keystore = Self::read(&self.path)?;
+        if keystore.version != KEYSTORE_VERSION || keystore.kdf != KEYSTORE_KDF {
+            return Err(Error::ConfigError(format!(
+                "不支持的密钥库格式: version={}, kdf={}",
+                keystore.version, keystore.kdf
+            )));
+        }
+        let decode = |field: &str, value: &str| {
+            general_purpose::STANDARD
+                .decode(value)
+                .map_err(|e| Error::ConfigError(format!("密钥库字段 {} 无效: {}", field, e)))
+        };
+        let salt = decode("salt", &keystore.salt)?;
+        let nonce = decode("nonce", &keystore.nonce)?;
+        let ciphertext = decode("ciphertext", &keystore.ciphertext)?;
+        if nonce.len() != 12 {
+            return Err(Error::ConfigError("密钥库字段 nonce 长度无效".to_string()));
+        }
+
+        let cipher = Self::cipher(self.password.expose(), &salt, keystore.iterations)?;
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| Error::ConfigError("解密密钥库失败，口令错误或文件已损坏".to_string()))?;
+        let file: CredentialFile = serde_json::from_slice(&plaintext).map_err(Error::JsonError)?;
+        Ok(file.into())
+    }
+}
+
+/// 可轮换的凭证
+///
+/// 持有当前凭证和可选的凭证来源，克隆后共享同一份凭证。`OkxClient` 每次请求、
+/// `AutoReconnectWebsocketClient` 每次连接时读取当前凭证，调用 `set` / `refresh` 后无需重建客户端。
+#[derive(Debug, Clone)]
+pub struct CredentialStore {
+    current: Arc<RwLock<Credentials>>,
+    provider: Option<Arc<dyn CredentialProvider>>,
+}
+
+impl From<Credentials> for CredentialStore {
+    fn from(credentials: Credentials) -> Self {
+        Self::fixed(credentials)
+    }
+}
+
+impl CredentialStore {
+    /// 从凭证来源读取初始凭证
+    pub fn new(provider: impl CredentialProvider + 'static) -> Result<Self, Error> {
+        Self::from_provider(Arc::new(provider))
+    }
+
+    /// 从共享的凭证来源读取初始凭证
+    pub fn from_provider(provider: Arc<dyn CredentialProvider>) -> Result<Self, Error> {
+        let credentials = provider.load()?;
+        Ok(Self {
+            current: Arc::new(RwLock::new(credentials)),
+            provider: Some(provider),
+        })
+    }
+
+    /// 使用固定凭证，只能通过 `set` 轮换
+    pub fn fixed(credentials: Credentials) -> Self {
+        Self {
+            current: Arc::new(RwLock::new(credentials)),
+            provider: None,
+        }
+    }
+
+    /// 当前凭证
+    pub fn current(&self) -> Credentials {
+        self.current
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// 手动替换凭证
+    pub fn set(&self, credentials: Credentials) {
+        *self.current.write().unwrap_or_else(|e| e.into_inner()) = credentials;
+    }
+
+    /// 从凭证来源重新读取凭证，返回凭证是否发生变化；没有凭证来源时返回 `false`
+    pub fn refresh(&self) -> Result<bool, Error> {
+        let Some(provider) = &self.provider else {
+            return Ok(false);
+        };
+        let credentials = provider.load()?;
+        let mut current = self.current.write().unwrap_or_else(|e| e.into_inner());
+        if *current == credentials {
+            return Ok(false);
+        }
+        *current = credentials;
+        Ok(true)
+    }
+
+    /// 启动后台任务，每隔 `interval` 从凭证来源刷新一次凭证；读取失败时保留旧凭证
+    ///
+    /// 凭证来源的读取是同步的（读文件、派生密钥），在阻塞线程池中执行，不占用异步工作线程。
+    pub fn spawn_refresh(&self, interval: Duration) -> JoinHandle<()> {
+        let store = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                let refreshing = store.clone();
+                match tokio::task::spawn_blocking(move || refreshing.refresh()).await {
+                    Ok(Ok(true)) => log::info!("API凭证已更新"),
+                    Ok(Ok(false)) => {}
+                    Ok(Err(e)) => log::warn!("刷新API凭证失败: {}", e),
+                    Err(e) => log::warn!("刷新API凭证任务异常: {}", e),
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!(
+            "okx-credentials-{}-{}-{}",
+            std::process::id(),
+            rand::rng().random::<u32>(),
+            name
+        ))
+    }
+
+    #[test]
+    fn env_provider_uses_prefix() {
+        env::set_var("OKX_TEST_PROVIDER_API_KEY", "key");
+        env::set_var("OKX_TEST_PROVIDER_API_SECRET", "secret");
+        env::set_var("OKX_TEST_PROVIDER_PASSPHRASE", "pass");
+
+        let credentials = EnvCredentialProvider::new("OKX_TEST_PROVIDER_")
+            .with_environment(Environment::Demo)
+            .load()
+            .unwrap();
+        assert_eq!(
+            credentials,
+            Credentials::new("key", "secret", "pass", Environment::Demo)
+        );
+
+        let err = EnvCredentialProvider::new("OKX_TEST_MISSING_")
+            .load()
+            .unwrap_err();
+        assert!(matches!(err, Error::ConfigError(_)));
+    }
+
+    #[test]
+    fn file_provider_reads_toml_profiles_and_json() {
+        let toml_path = temp_path("creds.toml");
+        std::fs::write(
+            &toml_path,
+            "[main]\napi_key = \"k1\"\napi_secret = \"s1\"\npassphrase = \"p1\"\n\n\
+             [demo]\napi_key = \"k2\"\napi_secret = \"s2\"\npassphrase = \"p2\"\nenvironment = \"demo\"\n",
+        )
+        .unwrap();
+        let json_path = temp_path("creds.json");
+        std::fs::write(
+            &json_path,
+            r#"{"api_key":"k3","api_secret":"s3","passphrase":"p3"}"#,
+        )
+        .unwrap();
+
+        let main = FileCredentialProvider::new(&toml_path)
+            .with_profile("main")
+            .load()
+            .unwrap();
+        let demo = FileCredentialProvider::new(&toml_path)
+            .with_profile("demo")
+            .load()
+            .unwrap();
+        let json = FileCredentialProvider::new(&json_path).load().unwrap();
+
+        assert_eq!(
+            main,
+            Credentials::new("k1", "s1", "p1", Environment::Production)
+        );
+        assert_eq!(demo, Credentials::new("k2", "s2", "p2", Environment::Demo));
+        assert_eq!(
+            json,
+            Credentials::new("k3", "s3", "p3", Environment::Production)
+        );
+        std::fs::remove_file(toml_path).ok();
+        std::fs::remove_file(json_path).ok();
+    }
+
+    #[test]
+    fn keystore_round_trips_and_rejects_wrong_password() {
+        let path = temp_path("keystore.json");
+        let credentials = Credentials::new("key", "secret", "pass", Environment::Production);
+        KeystoreCredentialProvider::new(&path, "hunter2")
+            .with_iterations(1_000)
+            .save(&credentials)
+            .unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(!content.contains("secret"));
+        assert_eq!(
+            KeystoreCredentialProvider::new(&path, "hunter2")
+                .load()
+                .unwrap(),
+            credentials
+        );
+        assert!(KeystoreCredentialProvider::new(&path, "wrong")
+            .load()
+            .is_err());
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn store_refresh_picks_up_rotated_keys() {
+        let path = temp_path("rotate.json");
+        std::fs::write(
+            &path,
+            r#"{"api_key":"old","api_secret":"s","passphrase":"p"}"#,
+        )
+        .unwrap();
+        let store = CredentialStore::new(FileCredentialProvider::new(&path)).unwrap();
+        let shared = store.clone();
+        assert_eq!(store.current().api_key, "old");
+        assert!(!store.refresh().unwrap());
+
+        std::fs::write(
+            &path,
+            r#"{"api_key":"new","api_secret":"s","passphrase":"p"}"#,
+        )
+        .unwrap();
+        assert!(store.refresh().unwrap());
+        assert_eq!(shared.current().api_key, "new");
+
+        // 来源读取失败时保留旧凭证
+        std::fs::remove_file(&path).unwrap();
+        assert!(store.refresh().is_err());
+        assert_eq!(store.current().api_key, "new");
+    }
+}
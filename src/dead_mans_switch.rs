@@ -0,0 +1,284 @@
+use crate::api::api_trait::OkxApiTrait;
+use crate::api::trade::OkxTrade;
+use crate::client::OkxClient;
+use crate::dto::trade::trade_dto::{CancelAllAfterReqDto, CancelAllAfterResDto};
+use crate::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+/// OKX允许的最短倒计时
+pub const MIN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// OKX允许的最长倒计时
+pub const MAX_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// 续期失败回调
+pub type ArmFailureCallback = Arc<dyn Fn(&Error) + Send + Sync>;
+
+/// 基于倒计时全部撤单（`/api/v5/trade/cancel-all-after`）的失联保护
+///
+/// 后台任务每隔 `refresh_interval` 重新设置一次倒计时。进程退出或网络中断导致续期停止时，
+/// OKX会在倒计时结束后撤销所有挂单；正常退出时通过 `DeadMansSwitchHandle::shutdown` 关闭倒计时。
+#[derive(Clone)]
+pub struct DeadMansSwitch {
+    client: OkxClient,
+    timeout: Duration,
+    refresh_interval: Duration,
+    tag: Option<String>,
+    on_failure: Option<ArmFailureCallback>,
+}
+
+impl fmt::Debug for DeadMansSwitch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeadMansSwitch")
+            .field("timeout", &self.timeout)
+            .field("refresh_interval", &self.refresh_interval)
+            .field("tag", &self.tag)
+            .field("on_failure", &self.on_failure.is_some())
+            .finish()
+    }
+}
+
+impl DeadMansSwitch {
+    /// 创建失联保护，倒计时为 `timeout`，默认每隔三分之一的倒计时续期一次
+    pub fn new(client: OkxClient, timeout: Duration) -> Self {
+        Self {
+            client,
+            timeout,
+            refresh_interval: timeout / 3,
+            tag: None,
+            on_failure: None,
+        }
+    }
+
+    /// 设置续期间隔，必须小于倒计时
+    pub fn with_refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
+        self
+    }
+
+    /// 设置CAA订单标签，倒计时结束时仅撤销带有该标签的订单
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
+    /// 设置续期失败时的回调，可用于及时平仓或告警
+    pub fn with_on_failure<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&Error) + Send + Sync + 'static,
+    {
+        self.on_failure = Some(Arc::new(callback));
+        self
+    }
+
+    /// 倒计时
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// 续期间隔
+    pub fn refresh_interval(&self) -> Duration {
+        self.refresh_interval
+    }
+
+    /// 检查倒计时和续期间隔是否有效
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.timeout < MIN_TIMEOUT || self.timeout > MAX_TIMEOUT {
+            return Err(Error::ParameterError(format!(
+                "倒计时必须在{}秒到{}秒之间: {:?}",
+                MIN_TIMEOUT.as_secs(),
+                MAX_TIMEOUT.as_secs(),
+                self.timeout
+            )));
+        }
+        if self.refresh_interval.is_zero() || self.refresh_interval >= self.timeout {
+            return Err(Error::ParameterError(format!(
+                "续期间隔必须大于0且小于倒计时: {:?}",
+                self.refresh_interval
+            )));
+        }
+        Ok(())
+    }
+
+    /// 设置一次倒计时
+    pub async fn arm(&self) -> Result<CancelAllAfterResDto, Error> {
+        self.send(self.timeout.as_secs()).await
+    }
+
+    /// 关闭倒计时
+    pub async fn disarm(&self) -> Result<(), Error> {
+        self.send(0).await.map(|_| ())
+    }
+
+    async fn send(&self, timeout_secs: u64) -> Result<CancelAllAfterResDto, Error> {
+        let trade = OkxTrade::new(self.client.clone());
+        let params = CancelAllAfterReqDto {
+            tag: self.tag.clone(),
+            ..CancelAllAfterReqDto::new(timeout_secs)
+        };
+        trade
+            .cancel_all_after(params)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::ParseError("倒计时全部撤单响应为空".to_string()))
+    }
+
+    /// 启动后台续期任务，立即设置第一次倒计时
+    pub fn spawn(self) -> Result<DeadMansSwitchHandle, Error> {
+        self.validate()?;
+        let (shutdown_tx, mut shutdown_rx) = oneshot::channel::<()>();
+        let task = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(self.refresh_interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    signal = &mut shutdown_rx => {
+                        // 句柄被丢弃时不关闭倒计时，交由OKX在倒计时结束后撤单
+                        if signal.is_err() {
+                            return Ok(());
+                        }
+                        break;
+                    }
+                    _ = ticker.tick() => {
+                        if let Err(e) = self.arm().await {
+                            log::warn!("倒计时全部撤单续期失败: {}", e);
+                            if let Some(callback) = &self.on_failure {
+                                callback(&e);
+                            }
+                        }
+                    }
+                }
+            }
+            self.disarm().await
+        });
+        Ok(DeadMansSwitchHandle {
+            shutdown: Some(shutdown_tx),
+            task,
+        })
+    }
+}
+
+/// 失联保护后台任务的句柄
+///
+/// 丢弃句柄会停止续期但不关闭倒计时，挂单将在倒计时结束后被撤销。
+#[derive(Debug)]
+pub struct DeadMansSwitchHandle {
+    shutdown: Option<oneshot::Sender<()>>,
+    task: JoinHandle<Result<(), Error>>,
+}
+
+impl DeadMansSwitchHandle {
+    /// 停止续期并关闭倒计时，返回关闭倒计时的结果
+    pub async fn shutdown(mut self) -> Result<(), Error> {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        match (&mut self.task).await {
+            Ok(result) => result,
+            Err(e) => Err(Error::Unknown(format!("失联保护任务异常退出: {}", e))),
+        }
+    }
+
+    /// 后台任务是否已结束
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+impl Drop for DeadMansSwitchHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rate_limiter::RateLimiter;
+    use crate::transport::tests::mock_builder;
+    use crate::transport::MockTransport;
+    use reqwest::Method;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const PATH: &str = "/api/v5/trade/cancel-all-after";
+
+    fn client(transport: &MockTransport) -> OkxClient {
+        mock_builder(transport)
+            .rate_limiter(RateLimiter::new())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn validates_timeout_and_interval() {
+        let client = client(&MockTransport::new());
+        assert!(DeadMansSwitch::new(client.clone(), Duration::from_secs(60))
+            .validate()
+            .is_ok());
+        assert!(DeadMansSwitch::new(client.clone(), Duration::from_secs(5))
+            .validate()
+            .is_err());
+        assert!(DeadMansSwitch::new(client, Duration::from_secs(30))
+            .with_refresh_interval(Duration::from_secs(30))
+            .validate()
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn rearms_until_shutdown_then_disarms() {
+        let transport = MockTransport::new();
+        transport.on_data(
+            Method::POST,
+            PATH,
+            serde_json::json!([{"triggerTime":"1587971460000","tag":"","ts":"1587971400000"}]),
+        );
+
+        let handle = DeadMansSwitch::new(client(&transport), Duration::from_secs(10))
+            .with_refresh_interval(Duration::from_millis(20))
+            .with_tag("bot")
+            .spawn()
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(70)).await;
+        handle.shutdown().await.unwrap();
+
+        let bodies: Vec<_> = transport
+            .requests_to(PATH)
+            .into_iter()
+            .map(|r| r.body)
+            .collect();
+        assert!(bodies.len() >= 3);
+        assert_eq!(bodies[0], r#"{"timeOut":"10","tag":"bot"}"#);
+        assert_eq!(bodies.last().unwrap(), r#"{"timeOut":"0","tag":"bot"}"#);
+    }
+
+    #[tokio::test]
+    async fn reports_arm_failures() {
+        let transport = MockTransport::new();
+        transport.on_error(
+            Method::POST,
+            PATH,
+            "50001",
+            "Service temporarily unavailable",
+        );
+        let failures = Arc::new(AtomicUsize::new(0));
+        let counter = failures.clone();
+
+        let handle = DeadMansSwitch::new(client(&transport), Duration::from_secs(10))
+            .with_refresh_interval(Duration::from_millis(20))
+            .with_on_failure(move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            })
+            .spawn()
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert!(failures.load(Ordering::SeqCst) >= 1);
+        assert!(handle.shutdown().await.is_err());
+    }
+}
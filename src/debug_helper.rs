@@ -40,7 +40,7 @@ impl DebugHelper {
         let lines: Vec<&str> = response.lines().collect();
         if line > 0 && line <= lines.len() {
             let error_line = lines[line - 1];
-            let start = if column > 20 { column - 20 } else { 0 };
+            let start = column.saturating_sub(20);
             let end = if column + 20 < error_line.len() {
                 column + 20
             } else {
@@ -65,12 +65,12 @@ impl DebugHelper {
     /// 诊断OKX API错误
     pub fn diagnose_okx_error(error: &Error) {
         match error {
-            Error::JsonError(json_err) => {
+            Error::JsonError(_) => {
                 println!("🔍 JSON解析错误诊断:");
                 println!("   这通常表示OKX API的响应格式与期望不符");
                 println!("   建议: 检查API文档是否有更新，或联系OKX技术支持");
             }
-            Error::OkxApiError { code, message, smg } => {
+            Error::OkxApiError { code, message, .. } => {
                 println!("🔍 OKX API错误诊断:");
                 println!("   错误代码: {}", code);
                 println!("   错误信息: {}", message);
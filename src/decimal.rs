@@ -0,0 +1,345 @@
+//! 价格、数量等数值字段的类型
+//!
+//! OKX接口中的数值均以字符串传输，默认情况下这些字段保持为 `String`。
+//! 启用 `decimal` feature 后，响应中的数值字段解析为精确的 `Option<Decimal>`（空字符串为 `None`），
+//! 请求中的数值参数使用 `Decimal`，并按OKX的规范字符串形式序列化（去掉末尾多余的0，不使用科学计数法）。
+//!
+//! DTO字段通过 `#[serde(with = "num")]` 等属性使用本模块，两种模式下的JSON格式保持一致。
+
+#[cfg(feature = "decimal")]
+pub use rust_decimal::Decimal;
+
+/// 响应中的数值字段
+#[cfg(not(feature = "decimal"))]
+pub type Num = String;
+/// 响应中的数值字段，空字符串为 `None`
+#[cfg(feature = "decimal")]
+pub type Num = Option<Decimal>;
+
+/// 响应中可能缺省的数值字段
+#[cfg(not(feature = "decimal"))]
+pub type OptNum = Option<String>;
+/// 响应中可能缺省的数值字段，缺省或空字符串为 `None`
+#[cfg(feature = "decimal")]
+pub type OptNum = Option<Decimal>;
+
+/// 请求中的数值参数
+#[cfg(not(feature = "decimal"))]
+pub type NumParam = String;
+/// 请求中的数值参数
+#[cfg(feature = "decimal")]
+pub type NumParam = Decimal;
+
+/// 把OKX返回的字符串转换为数值字段，启用 `decimal` 时空字符串或无法解析的值为 `None`
+#[cfg(not(feature = "decimal"))]
+pub fn to_num(value: &str) -> Num {
+    value.to_string()
+}
+
+/// 把OKX返回的字符串转换为数值字段，启用 `decimal` 时空字符串或无法解析的值为 `None`
+#[cfg(feature = "decimal")]
+pub fn to_num(value: &str) -> Num {
+    exact::parse(value).ok().flatten()
+}
+
+/// 响应中数值字段的字符串形式，空值为 `None`
+#[cfg(not(feature = "decimal"))]
+pub fn num_to_string(value: &Num) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// 响应中数值字段的字符串形式，空值为 `None`
+#[cfg(feature = "decimal")]
+pub fn num_to_string(value: &Num) -> Option<String> {
+    value.as_ref().map(canonical)
+}
+
+/// 数值参数的规范字符串形式，即请求中发送给OKX的值
+#[cfg(not(feature = "decimal"))]
+pub fn canonical(value: &NumParam) -> String {
+    value.clone()
+}
+
+/// 数值参数的规范字符串形式，即请求中发送给OKX的值
+#[cfg(feature = "decimal")]
+pub fn canonical(value: &NumParam) -> String {
+    value.normalize().to_string()
+}
+
+#[cfg(not(feature = "decimal"))]
+pub use plain::{num, num_levels, num_param, opt_num, opt_num_param};
+
+#[cfg(feature = "decimal")]
+pub use exact::{num, num_levels, num_param, opt_num, opt_num_param};
+
+/// 默认模式：数值字段保持为字符串，按原样序列化和反序列化
+#[cfg(not(feature = "decimal"))]
+mod plain {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<T: Serialize + ?Sized, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        T::deserialize(deserializer)
+    }
+
+    /// 响应中的数值字段（[`Num`](super::Num)）
+    pub mod num {
+        pub use super::{deserialize, serialize};
+    }
+
+    /// 响应中可能缺省的数值字段（[`OptNum`](super::OptNum)），需要同时标注 `#[serde(default)]`
+    pub mod opt_num {
+        pub use super::{deserialize, serialize};
+    }
+
+    /// 深度档位等由数值组成的二维数组（`Vec<Vec<Num>>`）
+    pub mod num_levels {
+        pub use super::{deserialize, serialize};
+    }
+
+    /// 请求中的数值参数（[`NumParam`](super::NumParam)）
+    pub mod num_param {
+        pub use super::{deserialize, serialize};
+    }
+
+    /// 请求中可选的数值参数（`Option<NumParam>`），需要同时标注 `#[serde(default)]`
+    pub mod opt_num_param {
+        pub use super::{deserialize, serialize};
+    }
+}
+
+/// `decimal` 模式：数值字段解析为 `Decimal`，按规范字符串形式序列化
+#[cfg(feature = "decimal")]
+mod exact {
+    use super::{canonical, Decimal, Num, NumParam};
+    use serde::de::{self, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::fmt;
+    use std::str::FromStr;
+
+    /// 解析OKX的数值字符串，空字符串为 `None`
+    pub(super) fn parse(value: &str) -> Result<Option<Decimal>, String> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Ok(None);
+        }
+        Decimal::from_str(value)
+            .or_else(|_| Decimal::from_scientific(value))
+            .map(Some)
+            .map_err(|e| format!("无效的数值 {}: {}", value, e))
+    }
+
+    /// 按规范字符串形式序列化的数值，`None` 输出为空字符串
+    struct Canonical<'a>(&'a Num);
+
+    impl Serialize for Canonical<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self.0 {
+                Some(value) => serializer.serialize_str(&canonical(value)),
+                None => serializer.serialize_str(""),
+            }
+        }
+    }
+
+    /// 从字符串、数字或 `null` 解析的数值
+    struct Lenient(Num);
+
+    impl<'de> Deserialize<'de> for Lenient {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(LenientVisitor).map(Lenient)
+        }
+    }
+
+    struct LenientVisitor;
+
+    impl<'de> Visitor<'de> for LenientVisitor {
+        type Value = Num;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("数值字符串或数字")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            parse(value).map_err(E::custom)
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            Ok(Some(Decimal::from(value)))
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            Ok(Some(Decimal::from(value)))
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+            Decimal::try_from(value).map(Some).map_err(E::custom)
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            deserializer.deserialize_any(self)
+        }
+    }
+
+    /// 响应中的数值字段（[`Num`](super::Num)），`None` 序列化为空字符串
+    pub mod num {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(value: &Num, serializer: S) -> Result<S::Ok, S::Error> {
+            Canonical(value).serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Num, D::Error> {
+            Lenient::deserialize(deserializer).map(|value| value.0)
+        }
+    }
+
+    /// 响应中可能缺省的数值字段（[`OptNum`](super::OptNum)），需要同时标注 `#[serde(default)]`
+    pub mod opt_num {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(value: &Num, serializer: S) -> Result<S::Ok, S::Error> {
+            match value {
+                Some(_) => Canonical(value).serialize(serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub use super::num::deserialize;
+    }
+
+    /// 深度档位等由数值组成的二维数组（`Vec<Vec<Num>>`）
+    pub mod num_levels {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(
+            value: &[Vec<Num>],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(
+                value
+                    .iter()
+                    .map(|level| level.iter().map(Canonical).collect::<Vec<_>>()),
+            )
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<Vec<Num>>, D::Error> {
+            let levels = Vec::<Vec<Lenient>>::deserialize(deserializer)?;
+            Ok(levels
+                .into_iter()
+                .map(|level| level.into_iter().map(|value| value.0).collect())
+                .collect())
+        }
+    }
+
+    /// 请求中的数值参数（[`NumParam`](super::NumParam)），按规范字符串形式序列化
+    pub mod num_param {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(
+            value: &NumParam,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&canonical(value))
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<NumParam, D::Error> {
+            Lenient::deserialize(deserializer)?
+                .0
+                .ok_or_else(|| de::Error::custom("数值参数不能为空"))
+        }
+    }
+
+    /// 请求中可选的数值参数（`Option<NumParam>`），需要同时标注 `#[serde(default)]`
+    pub mod opt_num_param {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(
+            value: &Option<NumParam>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match value {
+                Some(value) => super::num_param::serialize(value, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub use super::num::deserialize;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::market::market_dto::Depth;
+    use crate::dto::trade::trade_dto::{AmendOrderReqDto, OrderPendingRespDto};
+
+    #[test]
+    fn numeric_fields_keep_okx_wire_format() {
+        let order: OrderPendingRespDto = serde_json::from_value(serde_json::json!({
+            "instType": "SWAP", "instId": "BTC-USDT-SWAP", "lever": "10", "px": "", "sz": "0.0100",
+            "ordId": "1", "fillPx": "65000.5", "ordType": "market", "side": "buy",
+            "state": "live", "cTime": "1"
+        }))
+        .unwrap();
+        let depth: Depth = serde_json::from_str(
+            r#"{"instId":"BTC-USDT","asks":[["65001.2","1.5","0","3"]],"bids":[],"ts":"1"}"#,
+        )
+        .unwrap();
+        let amend = AmendOrderReqDto {
+            inst_id: "BTC-USDT".to_string(),
+            new_sz: Some("2.500".parse().unwrap()),
+            ..Default::default()
+        };
+
+        assert_eq!(order.sz, to_num("0.0100"));
+        assert_eq!(order.filled_price, Some("65000.5".parse().unwrap()));
+        assert_eq!(order.update_time, None);
+        assert_eq!(depth.asks[0][0], to_num("65001.2"));
+        let round_trip = serde_json::to_value(&depth).unwrap();
+        assert_eq!(round_trip["asks"][0][1], "1.5");
+
+        #[cfg(not(feature = "decimal"))]
+        {
+            assert_eq!(order.px, "");
+            assert_eq!(
+                serde_json::to_string(&amend).unwrap(),
+                r#"{"instId":"BTC-USDT","newSz":"2.500"}"#
+            );
+        }
+        #[cfg(feature = "decimal")]
+        {
+            assert_eq!(order.px, None);
+            assert_eq!(order.sz, Some(Decimal::new(1, 2)));
+            assert_eq!(
+                serde_json::to_string(&amend).unwrap(),
+                r#"{"instId":"BTC-USDT","newSz":"2.5"}"#
+            );
+            assert_eq!(to_num("1e-8"), Some(Decimal::new(1, 8)));
+            assert_eq!(canonical(&Decimal::new(-1000, 3)), "-1");
+        }
+    }
+}
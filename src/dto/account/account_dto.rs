@@ -1,4 +1,6 @@
-use crate::dto::common::MarginMode;
+use crate::decimal::{num, opt_num, Num, OptNum};
+use crate::dto::common::PageItem;
+use crate::enums::{BillType, ContractType, InstrumentType, MarginMode, PositionSide, TdMode};
 use serde::{Deserialize, Serialize};
 
 /// 平仓策略委托订单结构体
@@ -23,7 +25,7 @@ pub struct CloseOrderAlgo {
 #[serde(rename_all = "camelCase")]
 pub struct TradingNumRequestParams {
     pub inst_id: String,              // 产品ID，如 BTC-USDT
-    pub td_mode: String,              // 交易模式: cross, isolated, cash, spot_isolated
+    pub td_mode: TdMode,              // 交易模式: cross, isolated, cash, spot_isolated
     pub ccy: Option<String>,          // 保证金币种，仅适用于单币种保证金模式下的全仓杠杆订单
     pub reduce_only: Option<bool>,    // 是否为只减仓模式，仅适用于币币杠杆
     pub px: Option<String>,           // 对应平仓价格下的可用数量，默认为市价，仅适用于杠杆只减仓
@@ -42,7 +44,7 @@ pub struct TradingNumResponseData {
 #[serde(rename_all = "camelCase")]
 pub struct TradingSwapNumRequestParams {
     pub inst_id: String,              // 产品ID，如 BTC-USDT
-    pub td_mode: String,              // 交易模式: cross, isolated, cash, spot_isolated
+    pub td_mode: TdMode,              // 交易模式: cross, isolated, cash, spot_isolated
     pub ccy: Option<String>,          // 保证金币种，仅适用于单币种保证金模式下的全仓杠杆订单
     pub px: Option<String>, // 委托价格当不填委托价时，交割和永续会取当前限价计算，其他业务线会按当前最新成交价计算当指定多个产品ID查询时，忽略该参数，当未填写处理
     pub leverage: Option<String>, // 开仓杠杆倍数默认为当前杠杆倍数仅适用于币币杠杆/交割/永续
@@ -58,110 +60,110 @@ pub struct TradingSwapNumResponseData {
     pub max_sell: String, //最大卖出可用数量
 }
 /// 账户余额信息
-/// uTime	String	账户信息的更新时间，Unix时间戳的毫秒数格式，如 1597026383085
-/// totalEq	String	美金层面权益
-/// isoEq	String	美金层面逐仓仓位权益
+/// uTime String 账户信息的更新时间，Unix时间戳的毫秒数格式，如 1597026383085
+/// totalEq String 美金层面权益
+/// isoEq String 美金层面逐仓仓位权益
 /// 适用于合约模式/跨币种保证金模式/组合保证金模式
-/// adjEq	String	美金层面有效保证金
+/// adjEq String 美金层面有效保证金
 /// 适用于现货模式/跨币种保证金模式/组合保证金模式
-/// availEq	String	账户美金层面可用保证金，排除因总质押借币上限而被限制的币种
+/// availEq String 账户美金层面可用保证金，排除因总质押借币上限而被限制的币种
 /// 适用于跨币种保证金模式/组合保证金模式
-/// ordFroz	String	美金层面全仓挂单占用保证金
+/// ordFroz String 美金层面全仓挂单占用保证金
 /// 仅适用于现货模式/跨币种保证金模式/组合保证金模式
-/// imr	String	美金层面占用保证金
+/// imr String 美金层面占用保证金
 /// 适用于现货模式/跨币种保证金模式/组合保证金模式
-/// mmr	String	美金层面维持保证金
+/// mmr String 美金层面维持保证金
 /// 适用于现货模式/跨币种保证金模式/组合保证金模式
-/// borrowFroz	String	账户美金层面潜在借币占用保证金
+/// borrowFroz String 账户美金层面潜在借币占用保证金
 /// 仅适用于现货模式/跨币种保证金模式/组合保证金模式。在其他账户模式下为""。
-/// mgnRatio	String	美金层面维持保证金率
+/// mgnRatio String 美金层面维持保证金率
 /// 适用于现货模式/跨币种保证金模式/组合保证金模式
-/// notionalUsd	String	以美金价值为单位的持仓数量，即仓位美金价值
+/// notionalUsd String 以美金价值为单位的持仓数量，即仓位美金价值
 /// 适用于现货模式/跨币种保证金模式/组合保证金模式
-/// notionalUsdForBorrow	String	借币金额（美元价值）
+/// notionalUsdForBorrow String 借币金额（美元价值）
 /// 适用于现货模式/跨币种保证金模式/组合保证金模式
-/// notionalUsdForSwap	String	永续合约持仓美元价值
+/// notionalUsdForSwap String 永续合约持仓美元价值
 /// 适用于跨币种保证金模式/组合保证金模式
-/// notionalUsdForFutures	String	交割合约持仓美元价值
+/// notionalUsdForFutures String 交割合约持仓美元价值
 /// 适用于跨币种保证金模式/组合保证金模式
-/// notionalUsdForOption	String	期权持仓美元价值
+/// notionalUsdForOption String 期权持仓美元价值
 /// 适用于现货模式/跨币种保证金模式/组合保证金模式
-/// upl	String	账户层面全仓未实现盈亏（美元单位）
+/// upl String 账户层面全仓未实现盈亏（美元单位）
 /// 适用于跨币种保证金模式/组合保证金模式
-/// details	Array of objects	各币种资产详细信息
-/// ccy	String	币种
-/// eq	String	币种总权益
-/// cashBal	String	币种余额
-/// uTime	String	币种余额信息的更新时间，Unix时间戳的毫秒数格式，如 1597026383085
-/// isoEq	String	币种逐仓仓位权益
+/// details Array of objects 各币种资产详细信息
+/// ccy String 币种
+/// eq String 币种总权益
+/// cashBal String 币种余额
+/// uTime String 币种余额信息的更新时间，Unix时间戳的毫秒数格式，如 1597026383085
+/// isoEq String 币种逐仓仓位权益
 /// 适用于合约模式/跨币种保证金模式/组合保证金模式
-/// availEq	String	可用保证金
+/// availEq String 可用保证金
 /// 适用于合约模式/跨币种保证金模式/组合保证金模式
-/// disEq	String	美金层面币种折算权益
+/// disEq String 美金层面币种折算权益
 /// 适用于现货模式(开通了借币功能)/跨币种保证金模式/组合保证金模式
-/// fixedBal	String	抄底宝、逃顶宝功能的币种冻结金额
-/// availBal	String	可用余额
-/// frozenBal	String	币种占用金额
-/// ordFrozen	String	挂单冻结数量
+/// fixedBal String 抄底宝、逃顶宝功能的币种冻结金额
+/// availBal String 可用余额
+/// frozenBal String 币种占用金额
+/// ordFrozen String 挂单冻结数量
 /// 适用于现货模式/合约模式/跨币种保证金模式
-/// liab	String	币种负债额
+/// liab String 币种负债额
 /// 值为正数，如 "21625.64"
 /// 适用于现货模式/跨币种保证金模式/组合保证金模式
-/// upl	String	未实现盈亏
+/// upl String 未实现盈亏
 /// 适用于合约模式/跨币种保证金模式/组合保证金模式
-/// uplLiab	String	由于仓位未实现亏损导致的负债
+/// uplLiab String 由于仓位未实现亏损导致的负债
 /// 适用于跨币种保证金模式/组合保证金模式
-/// crossLiab	String	币种全仓负债额
+/// crossLiab String 币种全仓负债额
 /// 适用于现货模式/跨币种保证金模式/组合保证金模式
-/// isoLiab	String	币种逐仓负债额
+/// isoLiab String 币种逐仓负债额
 /// 适用于跨币种保证金模式/组合保证金模式
-/// rewardBal	String	体验金余额
-/// mgnRatio	String	币种全仓维持保证金率，衡量账户内某项资产风险的指标
+/// rewardBal String 体验金余额
+/// mgnRatio String 币种全仓维持保证金率，衡量账户内某项资产风险的指标
 /// 适用于合约模式且有全仓仓位时
-/// imr	String	币种维度全仓占用保证金
+/// imr String 币种维度全仓占用保证金
 /// 适用于合约模式且有全仓仓位时
-/// mmr	String	币种维度全仓维持保证金
+/// mmr String 币种维度全仓维持保证金
 /// 适用于合约模式且有全仓仓位时
-/// interest	String	计息，应扣未扣利息
+/// interest String 计息，应扣未扣利息
 /// 值为正数，如 9.01
 /// 适用于现货模式/跨币种保证金模式/组合保证金模式
-/// twap	String	当前负债币种触发系统自动换币的风险
+/// twap String 当前负债币种触发系统自动换币的风险
 /// 0、1、2、3、4、5其中之一，数字越大代表您的负债币种触发自动换币概率越高
 /// 适用于现货模式/跨币种保证金模式/组合保证金模式
-/// maxLoan	String	币种最大可借
+/// maxLoan String 币种最大可借
 /// 适用于现货模式/跨币种保证金模式/组合保证金模式 的全仓
-/// eqUsd	String	币种权益美金价值
-/// borrowFroz	String	币种美金层面潜在借币占用保证金
+/// eqUsd String 币种权益美金价值
+/// borrowFroz String 币种美金层面潜在借币占用保证金
 /// 仅适用于现货模式/跨币种保证金模式/组合保证金模式。在其他账户模式下为""。
-/// notionalLever	String	币种杠杆倍数
+/// notionalLever String 币种杠杆倍数
 /// 适用于合约模式
-/// stgyEq	String	策略权益
-/// isoUpl	String	逐仓未实现盈亏
+/// stgyEq String 策略权益
+/// isoUpl String 逐仓未实现盈亏
 /// 适用于合约模式/跨币种保证金模式/组合保证金模式
-/// spotInUseAmt	String	现货对冲占用数量
+/// spotInUseAmt String 现货对冲占用数量
 /// 适用于组合保证金模式
-/// clSpotInUseAmt	String	用户自定义现货占用数量
+/// clSpotInUseAmt String 用户自定义现货占用数量
 /// 适用于组合保证金模式
-/// maxSpotInUse	String	系统计算得到的最大可能现货占用数量
+/// maxSpotInUse String 系统计算得到的最大可能现货占用数量
 /// 适用于组合保证金模式
-/// spotIsoBal	String	现货逐仓余额
+/// spotIsoBal String 现货逐仓余额
 /// 仅适用于现货带单/跟单
 /// 适用于现货模式/合约模式
-/// smtSyncEq	String	合约智能跟单权益
+/// smtSyncEq String 合约智能跟单权益
 /// 默认为0，仅适用于跟单人。
-/// spotCopyTradingEq	String	现货智能跟单权益
+/// spotCopyTradingEq String 现货智能跟单权益
 /// 默认为0，仅适用于跟单人。
-/// spotBal	String	现货余额 ，单位为 币种，比如 BTC。详情
-/// openAvgPx	String	现货开仓成本价 单位 USD。 详情
-/// accAvgPx	String	现货累计成本价 单位 USD。 详情
-/// spotUpl	String	现货未实现收益，单位 USD。 详情
-/// spotUplRatio	String	现货未实现收益率。详情
-/// totalPnl	String	现货累计收益，单位 USD。 详情
-/// totalPnlRatio	String	现货累计收益率。详情
-/// collateralEnabled	Boolean	true：质押币
+/// spotBal String 现货余额 ，单位为 币种，比如 BTC。详情
+/// openAvgPx String 现货开仓成本价 单位 USD。 详情
+/// accAvgPx String 现货累计成本价 单位 USD。 详情
+/// spotUpl String 现货未实现收益，单位 USD。 详情
+/// spotUplRatio String 现货未实现收益率。详情
+/// totalPnl String 现货累计收益，单位 USD。 详情
+/// totalPnlRatio String 现货累计收益率。详情
+/// collateralEnabled Boolean true：质押币
 /// false：非质押币
 /// 适用于跨币种保证金模式
-/// collateralRestrict	Boolean	平台维度的质押借币限制
+/// collateralRestrict Boolean 平台维度的质押借币限制
 /// true
 /// false
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -170,50 +172,50 @@ pub struct Balance {
     #[serde(rename = "uTime")]
     pub u_time: String,
     /// 美金层面权益
-    #[serde(rename = "totalEq")]
-    pub total_eq: String,
+    #[serde(rename = "totalEq", with = "num")]
+    pub total_eq: Num,
     /// 美金层面逐仓仓位权益
-    #[serde(rename = "isoEq")]
-    pub iso_eq: String,
+    #[serde(rename = "isoEq", with = "num")]
+    pub iso_eq: Num,
     /// 美金层面有效保证金
-    #[serde(rename = "adjEq")]
-    pub adj_eq: String,
+    #[serde(rename = "adjEq", with = "num")]
+    pub adj_eq: Num,
     /// 账户美金层面可用保证金，排除因总质押借币上限而被限制的币种
-    #[serde(rename = "availEq")]
-    pub avail_eq: String,
+    #[serde(rename = "availEq", with = "num")]
+    pub avail_eq: Num,
     /// 美金层面全仓挂单占用保证金
-    #[serde(rename = "ordFroz")]
-    pub ord_froz: String,
+    #[serde(rename = "ordFroz", with = "num")]
+    pub ord_froz: Num,
     /// 美金层面占用保证金
-    #[serde(rename = "imr")]
-    pub imr: String,
+    #[serde(rename = "imr", with = "num")]
+    pub imr: Num,
     /// 美金层面维持保证金
-    #[serde(rename = "mmr")]
-    pub mmr: String,
+    #[serde(rename = "mmr", with = "num")]
+    pub mmr: Num,
     /// 美金层面潜在借币占用保证金
-    #[serde(rename = "borrowFroz")]
-    pub borrow_froz: String,
+    #[serde(rename = "borrowFroz", with = "num")]
+    pub borrow_froz: Num,
     /// 美金层面维持保证金率
-    #[serde(rename = "mgnRatio")]
-    pub mgn_ratio: String,
+    #[serde(rename = "mgnRatio", with = "num")]
+    pub mgn_ratio: Num,
     /// 以美金价值为单位的持仓数量，即仓位美金价值
-    #[serde(rename = "notionalUsd")]
-    pub notional_usd: String,
+    #[serde(rename = "notionalUsd", with = "num")]
+    pub notional_usd: Num,
     /// 借币金额（美元价值）
-    #[serde(rename = "notionalUsdForBorrow")]
-    pub notional_usd_for_borrow: String,
+    #[serde(rename = "notionalUsdForBorrow", with = "num")]
+    pub notional_usd_for_borrow: Num,
     /// 永续合约持仓美元价值
-    #[serde(rename = "notionalUsdForSwap")]
-    pub notional_usd_for_swap: String,
+    #[serde(rename = "notionalUsdForSwap", with = "num")]
+    pub notional_usd_for_swap: Num,
     /// 交割合约持仓美元价值
-    #[serde(rename = "notionalUsdForFutures")]
-    pub notional_usd_for_futures: String,
+    #[serde(rename = "notionalUsdForFutures", with = "num")]
+    pub notional_usd_for_futures: Num,
     /// 期权持仓美元价值
-    #[serde(rename = "notionalUsdForOption")]
-    pub notional_usd_for_option: String,
+    #[serde(rename = "notionalUsdForOption", with = "num")]
+    pub notional_usd_for_option: Num,
     /// 账户层面全仓未实现盈亏（美元单位）
-    #[serde(rename = "upl")]
-    pub upl: String,
+    #[serde(rename = "upl", with = "num")]
+    pub upl: Num,
     /// 各币种资产详细信息
     pub details: Vec<BalanceDetail>,
 }
@@ -223,121 +225,122 @@ pub struct BalanceDetail {
     /// 币种
     pub ccy: String,
     /// 币种总权益
-    pub eq: String,
+    #[serde(with = "num")]
+    pub eq: Num,
     /// 币种余额
-    #[serde(rename = "cashBal")]
-    pub cash_bal: String,
+    #[serde(rename = "cashBal", with = "num")]
+    pub cash_bal: Num,
     /// 币种逐仓仓位权益
-    #[serde(rename = "isoEq")]
-    pub iso_eq: String,
+    #[serde(rename = "isoEq", with = "num")]
+    pub iso_eq: Num,
     /// 可用保证金
-    #[serde(rename = "availEq")]
-    pub avail_eq: String,
+    #[serde(rename = "availEq", with = "num")]
+    pub avail_eq: Num,
     /// 美金层面币种折算权益
-    #[serde(rename = "disEq")]
-    pub dis_eq: String,
+    #[serde(rename = "disEq", with = "num")]
+    pub dis_eq: Num,
     /// 抄底宝、逃顶宝功能的币种冻结金额
-    #[serde(rename = "fixedBal")]
-    pub fixed_bal: String,
+    #[serde(rename = "fixedBal", with = "num")]
+    pub fixed_bal: Num,
     /// 可用余额
-    #[serde(rename = "availBal")]
-    pub avail_bal: String,
+    #[serde(rename = "availBal", with = "num")]
+    pub avail_bal: Num,
     /// 币种占用金额
-    #[serde(rename = "frozenBal")]
-    pub frozen_bal: String,
+    #[serde(rename = "frozenBal", with = "num")]
+    pub frozen_bal: Num,
     /// 挂单冻结数量
-    #[serde(rename = "ordFrozen")]
-    pub ord_frozen: String,
+    #[serde(rename = "ordFrozen", with = "num")]
+    pub ord_frozen: Num,
     /// 币种负债额
-    #[serde(rename = "liab")]
-    pub liab: String,
+    #[serde(rename = "liab", with = "num")]
+    pub liab: Num,
     /// 未实现盈亏
-    #[serde(rename = "upl")]
-    pub upl: String,
+    #[serde(rename = "upl", with = "num")]
+    pub upl: Num,
     /// 由于仓位未实现亏损导致的负债
-    #[serde(rename = "uplLiab")]
-    pub upl_liab: String,
+    #[serde(rename = "uplLiab", with = "num")]
+    pub upl_liab: Num,
     /// 币种全仓负债额  
-    #[serde(rename = "crossLiab")]
-    pub cross_liab: String,
+    #[serde(rename = "crossLiab", with = "num")]
+    pub cross_liab: Num,
     /// 币种逐仓负债额
-    #[serde(rename = "isoLiab")]
-    pub iso_liab: String,
+    #[serde(rename = "isoLiab", with = "num")]
+    pub iso_liab: Num,
     /// 体验金余额
-    #[serde(rename = "rewardBal")]
-    pub reward_bal: String,
+    #[serde(rename = "rewardBal", with = "num")]
+    pub reward_bal: Num,
     /// 币种全仓维持保证金率
-    #[serde(rename = "mgnRatio")]
-    pub mgn_ratio: String,
+    #[serde(rename = "mgnRatio", with = "num")]
+    pub mgn_ratio: Num,
     /// 币种维度全仓占用保证金
-    #[serde(rename = "imr")]
-    pub imr: String,
+    #[serde(rename = "imr", with = "num")]
+    pub imr: Num,
     /// 币种维度全仓维持保证金
-    #[serde(rename = "mmr")]
-    pub mmr: String,
+    #[serde(rename = "mmr", with = "num")]
+    pub mmr: Num,
     /// 计息，应扣未扣利息
-    #[serde(rename = "interest")]
-    pub interest: String,
+    #[serde(rename = "interest", with = "num")]
+    pub interest: Num,
     /// 当前负债币种触发系统自动换币的风险
-    #[serde(rename = "twap")]
-    pub twap: String,
+    #[serde(rename = "twap", with = "num")]
+    pub twap: Num,
     /// 币种最大可借
-    #[serde(rename = "maxLoan")]
-    pub max_loan: String,
+    #[serde(rename = "maxLoan", with = "num")]
+    pub max_loan: Num,
     /// 币种权益美金价值
-    #[serde(rename = "eqUsd")]
-    pub eq_usd: String,
+    #[serde(rename = "eqUsd", with = "num")]
+    pub eq_usd: Num,
     /// 币种美金层面潜在借币占用保证金
-    #[serde(rename = "borrowFroz")]
-    pub borrow_froz: String,
+    #[serde(rename = "borrowFroz", with = "num")]
+    pub borrow_froz: Num,
     /// 币种杠杆倍数
-    #[serde(rename = "notionalLever")]
-    pub notional_lever: String,
+    #[serde(rename = "notionalLever", with = "num")]
+    pub notional_lever: Num,
     /// 策略权益
-    #[serde(rename = "stgyEq")]
-    pub stgy_eq: String,
+    #[serde(rename = "stgyEq", with = "num")]
+    pub stgy_eq: Num,
     /// 逐仓未实现盈亏
-    #[serde(rename = "isoUpl")]
-    pub iso_upl: String,
+    #[serde(rename = "isoUpl", with = "num")]
+    pub iso_upl: Num,
     /// 现货对冲占用数量
-    #[serde(rename = "spotInUseAmt")]
-    pub spot_in_use_amt: String,
+    #[serde(rename = "spotInUseAmt", with = "num")]
+    pub spot_in_use_amt: Num,
     /// 用户自定义现货占用数量
-    #[serde(rename = "clSpotInUseAmt")]
-    pub cl_spot_in_use_amt: String,
+    #[serde(rename = "clSpotInUseAmt", with = "num")]
+    pub cl_spot_in_use_amt: Num,
     /// 系统计算得到的最大可能现货占用数量
-    #[serde(rename = "maxSpotInUse")]
-    pub max_spot_in_use: String,
+    #[serde(rename = "maxSpotInUse", with = "num")]
+    pub max_spot_in_use: Num,
     /// 现货逐仓余额
-    #[serde(rename = "spotIsoBal")]
-    pub spot_iso_bal: String,
+    #[serde(rename = "spotIsoBal", with = "num")]
+    pub spot_iso_bal: Num,
     /// 合约智能跟单权益
-    #[serde(rename = "smtSyncEq")]
-    pub smt_sync_eq: String,
+    #[serde(rename = "smtSyncEq", with = "num")]
+    pub smt_sync_eq: Num,
     /// 现货智能跟单权益
-    #[serde(rename = "spotCopyTradingEq")]
-    pub spot_copy_trading_eq: String,
+    #[serde(rename = "spotCopyTradingEq", with = "num")]
+    pub spot_copy_trading_eq: Num,
     /// 现货余额
-    #[serde(rename = "spotBal")]
-    pub spot_bal: String,
+    #[serde(rename = "spotBal", with = "num")]
+    pub spot_bal: Num,
     /// 现货开仓成本价
-    #[serde(rename = "openAvgPx")]
-    pub open_avg_px: String,
+    #[serde(rename = "openAvgPx", with = "num")]
+    pub open_avg_px: Num,
     /// 现货累计成本价
-    #[serde(rename = "accAvgPx")]
-    pub acc_avg_px: String,
+    #[serde(rename = "accAvgPx", with = "num")]
+    pub acc_avg_px: Num,
     /// 现货未实现收益
-    #[serde(rename = "spotUpl")]
-    pub spot_upl: String,
+    #[serde(rename = "spotUpl", with = "num")]
+    pub spot_upl: Num,
     /// 现货未实现收益率
-    #[serde(rename = "spotUplRatio")]
-    pub spot_upl_ratio: String,
+    #[serde(rename = "spotUplRatio", with = "num")]
+    pub spot_upl_ratio: Num,
     /// 现货累计收益
-    #[serde(rename = "totalPnl")]
-    pub total_pnl: String,
+    #[serde(rename = "totalPnl", with = "num")]
+    pub total_pnl: Num,
     /// 现货累计收益率
-    #[serde(rename = "totalPnlRatio")]
-    pub total_pnl_ratio: String,
+    #[serde(rename = "totalPnlRatio", with = "num")]
+    pub total_pnl_ratio: Num,
     /// 质押币
     #[serde(rename = "collateralEnabled")]
     pub collateral_enabled: bool,
@@ -506,6 +509,7 @@ mod tests {
     }
 }
 
+/// 设置杠杆倍数请求参数
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct SetLeverageRequest {
@@ -514,17 +518,24 @@ pub struct SetLeverageRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ccy: Option<String>,
     pub lever: String,
-    pub mgn_mode: String,
+    pub mgn_mode: MarginMode,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pos_side: Option<String>,
+    pub pos_side: Option<PositionSide>,
 }
 
+/// 设置杠杆倍数响应数据
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SetLeverageData {
+    /// 杠杆倍数
     pub lever: String,
+    /// 保证金模式 isolated / cross
     pub mgn_mode: String,
+    /// 产品ID
+    #[serde(default)]
     pub inst_id: String,
+    /// 持仓方向
+    #[serde(default)]
     pub pos_side: String,
 }
 
@@ -541,97 +552,136 @@ pub struct Position {
     /// 持仓方向 (long: 开平仓模式开多, short: 开平仓模式开空, net: 买卖模式)
     pub pos_side: String,
     /// 持仓数量
-    pub pos: String,
+    #[serde(with = "num")]
+    pub pos: Num,
     /// 仓位资产币种，仅适用于币币杠杆仓位
     pub pos_ccy: Option<String>,
     /// 可平仓数量，适用于币币杠杆, 交割/永续（开平仓模式），期权
-    pub avail_pos: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub avail_pos: OptNum,
     /// 开仓平均价
-    pub avg_px: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub avg_px: OptNum,
     /// 未实现收益（以标记价格计算）
-    pub upl: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub upl: OptNum,
     /// 未实现收益率（以标记价格计算）
-    pub upl_ratio: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub upl_ratio: OptNum,
     /// 以最新成交价格计算的未实现收益
-    pub upl_last_px: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub upl_last_px: OptNum,
     /// 以最新成交价格计算的未实现收益率
-    pub upl_ratio_last_px: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub upl_ratio_last_px: OptNum,
     /// 产品ID，如 BTC-USD-180216
     pub inst_id: String,
     /// 杠杆倍数，不适用于期权以及组合保证金模式下的全仓仓位
-    pub lever: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub lever: OptNum,
     /// 预估强平价，不适用于期权
-    pub liq_px: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub liq_px: OptNum,
     /// 最新标记价格
-    pub mark_px: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub mark_px: OptNum,
     /// 初始保证金，仅适用于全仓
-    pub imr: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub imr: OptNum,
     /// 保证金余额，可增减，仅适用于逐仓
-    pub margin: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub margin: OptNum,
     /// 保证金率
-    pub mgn_ratio: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub mgn_ratio: OptNum,
     /// 维持保证金
-    pub mmr: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub mmr: OptNum,
     /// 负债额，仅适用于币币杠杆
-    pub liab: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub liab: OptNum,
     /// 负债币种，仅适用于币币杠杆
     pub liab_ccy: Option<String>,
     /// 利息，已经生成的未扣利息
-    pub interest: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub interest: OptNum,
     /// 最新成交ID
     pub trade_id: Option<String>,
     /// 期权市值，仅适用于期权
-    pub opt_val: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub opt_val: OptNum,
     /// 逐仓杠杆负债对应平仓挂单的数量
-    pub pending_close_ord_liab_val: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub pending_close_ord_liab_val: OptNum,
     /// 以美金价值为单位的持仓数量
-    pub notional_usd: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub notional_usd: OptNum,
     /// 信号区，分为5档，从1到5，数字越小代表adl强度越弱
     pub adl: Option<String>,
     /// 占用保证金的币种
     pub ccy: Option<String>,
     /// 最新成交价
-    pub last: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub last: OptNum,
     /// 最新指数价格
-    pub idx_px: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub idx_px: OptNum,
     /// 美金价格
-    pub usd_px: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub usd_px: OptNum,
     /// 盈亏平衡价
-    pub be_px: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub be_px: OptNum,
     /// 美金本位持仓仓位delta，仅适用于期权
-    pub delta_bs: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub delta_bs: OptNum,
     /// 币本位持仓仓位delta，仅适用于期权
-    pub delta_pa: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub delta_pa: OptNum,
     /// 美金本位持仓仓位gamma，仅适用于期权
-    pub gamma_bs: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub gamma_bs: OptNum,
     /// 币本位持仓仓位gamma，仅适用于期权
-    pub gamma_pa: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub gamma_pa: OptNum,
     /// 美金本位持仓仓位theta，仅适用于期权
-    pub theta_bs: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub theta_bs: OptNum,
     /// 币本位持仓仓位theta，仅适用于期权
-    pub theta_pa: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub theta_pa: OptNum,
     /// 美金本位持仓仓位vega，仅适用于期权
-    pub vega_bs: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub vega_bs: OptNum,
     /// 币本位持仓仓位vega，仅适用于期权
-    pub vega_pa: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub vega_pa: OptNum,
     /// 现货对冲占用数量，适用于组合保证金模式
-    pub spot_in_use_amt: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub spot_in_use_amt: OptNum,
     /// 现货对冲占用币种，适用于组合保证金模式
     pub spot_in_use_ccy: Option<String>,
     /// 用户自定义现货占用数量，适用于组合保证金模式
-    pub cl_spot_in_use_amt: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub cl_spot_in_use_amt: OptNum,
     /// 系统计算得到的最大可能现货占用数量，适用于组合保证金模式
-    pub max_spot_in_use_amt: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub max_spot_in_use_amt: OptNum,
     /// 已实现收益
-    pub realized_pnl: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub realized_pnl: OptNum,
     /// 平仓订单累计收益额
-    pub pnl: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub pnl: OptNum,
     /// 累计手续费金额
-    pub fee: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub fee: OptNum,
     /// 累计资金费用
-    pub funding_fee: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub funding_fee: OptNum,
     /// 累计爆仓罚金
-    pub liq_penalty: Option<String>,
+    #[serde(default, with = "opt_num")]
+    pub liq_penalty: OptNum,
     /// 平仓策略委托订单
     pub close_order_algo: Option<Vec<CloseOrderAlgo>>,
     /// 持仓创建时间，Unix时间戳的毫秒数格式
@@ -643,3 +693,125 @@ pub struct Position {
     /// 外部业务类型
     pub biz_ref_type: Option<String>,
 }
+
+/// 账单流水
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BillRespDto {
+    /// 账单ID
+    pub bill_id: String,
+    /// 产品类型
+    pub inst_type: String,
+    /// 产品ID
+    pub inst_id: String,
+    /// 币种
+    pub ccy: String,
+    /// 账单类型
+    #[serde(rename = "type")]
+    pub bill_type: String,
+    /// 账单子类型
+    pub sub_type: String,
+    /// 账单创建时间，Unix时间戳的毫秒数格式
+    pub ts: String,
+    /// 账户层面的余额变动数量
+    #[serde(with = "num")]
+    pub bal_chg: Num,
+    /// 仓位层面的余额变动数量
+    #[serde(with = "num")]
+    pub pos_bal_chg: Num,
+    /// 账户层面的余额数量
+    #[serde(with = "num")]
+    pub bal: Num,
+    /// 仓位层面的余额数量
+    #[serde(with = "num")]
+    pub pos_bal: Num,
+    /// 数量
+    #[serde(with = "num")]
+    pub sz: Num,
+    /// 价格
+    #[serde(with = "num")]
+    pub px: Num,
+    /// 收益
+    #[serde(with = "num")]
+    pub pnl: Num,
+    /// 手续费
+    #[serde(with = "num")]
+    pub fee: Num,
+    /// 利息
+    #[serde(with = "num")]
+    pub interest: Num,
+    /// 保证金模式 isolated / cross / cash
+    pub mgn_mode: String,
+    /// 订单ID
+    pub ord_id: String,
+    /// 客户自定义订单ID
+    pub cl_ord_id: String,
+    /// 最新成交ID
+    pub trade_id: String,
+    /// 流动性方向 T：taker，M：maker
+    pub exec_type: String,
+    /// 转出账户
+    pub from: String,
+    /// 转入账户
+    pub to: String,
+    /// 备注
+    pub notes: String,
+    /// 订单标签
+    pub tag: String,
+    /// 成交时间
+    pub fill_time: String,
+}
+
+/// 账单查询参数
+///
+/// `after` / `before` 为账单ID游标，`begin` / `end` 为毫秒时间戳。
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BillsReqDto {
+    /// 产品类型
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_type: Option<InstrumentType>,
+    /// 产品ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<String>,
+    /// 币种
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<String>,
+    /// 保证金模式
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mgn_mode: Option<MarginMode>,
+    /// 合约类型 linear：正向合约，inverse：反向合约
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ct_type: Option<ContractType>,
+    /// 账单类型
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub bill_type: Option<BillType>,
+    /// 账单子类型
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub_type: Option<String>,
+    /// 请求此账单ID之前（更旧的数据）的分页内容
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// 请求此账单ID之后（更新的数据）的分页内容
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    /// 筛选的开始时间戳，Unix时间戳的毫秒数格式
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub begin: Option<String>,
+    /// 筛选的结束时间戳，Unix时间戳的毫秒数格式
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<String>,
+    /// 返回结果的数量，最大为100，默认100条
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl PageItem for BillRespDto {
+    fn cursor(&self) -> &str {
+        &self.bill_id
+    }
+
+    fn timestamp_ms(&self) -> Option<i64> {
+        self.ts.parse().ok()
+    }
+}
@@ -1,4 +1,5 @@
-use crate::enums::account_enums::AccountType;
+use crate::dto::common::PageItem;
+use crate::enums::{AccountType, TransferType, WithdrawalDest};
 use serde::{Deserialize, Serialize};
 
 /// 资产余额信息
@@ -48,10 +49,11 @@ pub struct AssetBalance {
 // 字母（区分大小写）与数字的组合，可以是纯字母、纯数字且长度要在1-32位之间。
 ///资金划转
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TransferOkxReqDto {
     /// 划转类型
-    #[serde(rename = "type")]
-    pub transfer_type: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub transfer_type: Option<TransferType>,
     /// 币种
     pub ccy: String,
     /// 划转数量
@@ -61,37 +63,208 @@ pub struct TransferOkxReqDto {
     /// 转入账户
     pub to: AccountType,
     /// 子账户名称
-    #[serde(rename = "subAcct")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sub_acct: Option<String>,
-    // /// 是否支持借币转出
-    // #[serde(rename = "loanTrans")]
-    // pub loan_trans: bool,
-    // /// 是否忽略仓位风险
-    // #[serde(rename = "omitPosRisk")]
-    // pub omit_pos_risk: bool,
-    // /// 客户自定义ID
-    // #[serde(rename = "clientId")]
-    // pub client_id: String,
+    /// 是否支持借币转出
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loan_trans: Option<bool>,
+    /// 是否忽略仓位风险，仅适用于组合保证金模式
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub omit_pos_risk: Option<String>,
+    /// 客户自定义ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
 }
 
-/// 资金划转记录
+/// 资金划转响应数据
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferRespDto {
+    /// 划转ID
+    pub trans_id: String,
+    /// 客户自定义ID
+    #[serde(default)]
+    pub client_id: String,
+    /// 划转币种
+    pub ccy: String,
+    /// 转出账户
+    pub from: String,
+    /// 划转数量
+    pub amt: String,
+    /// 转入账户
+    pub to: String,
+}
+
+/// 资金划转状态查询参数，`trans_id` 和 `client_id` 至少填写一个
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferStateReqDto {
+    /// 划转ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trans_id: Option<String>,
+    /// 客户自定义ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    /// 划转类型，默认为0账户内划转
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub transfer_type: Option<TransferType>,
+}
+
+/// 资金划转状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TransferRecord {
     /// 划转ID
     #[serde(rename = "transId")]
     pub transfer_id: String,
+    /// 客户自定义ID
+    #[serde(default)]
+    pub client_id: String,
     /// 币种
     pub ccy: String,
     /// 划转数量
     pub amt: String,
-    /// 转入账户
-    pub from: String,
+    /// 划转类型
+    #[serde(rename = "type", default)]
+    pub transfer_type: String,
     /// 转出账户
+    pub from: String,
+    /// 转入账户
     pub to: String,
-    /// 划转状态
+    /// 子账户名称
+    #[serde(default)]
+    pub sub_acct: String,
+    /// 划转状态 success：成功，pending：处理中，failed：失败
     pub state: String,
-    /// 划转时间
-    pub ts: String,
+}
+
+/// 提币请求参数
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalReqDto {
+    /// 币种，如 USDT
+    pub ccy: String,
+    /// 提币数量，不包含手续费
+    pub amt: String,
+    /// 提币方式 3：内部转账，4：链上提币
+    pub dest: WithdrawalDest,
+    /// 提币地址；内部转账时为接收方的邮箱、手机号或账户名
+    pub to_addr: String,
+    /// 网络手续费
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee: Option<String>,
+    /// 币种链信息，如 USDT-ERC20
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain: Option<String>,
+    /// 手机区号，内部转账且 `to_addr` 为手机号时必填
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area_code: Option<String>,
+    /// 客户自定义ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+}
+
+/// 提币响应数据
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalRespDto {
+    /// 币种
+    pub ccy: String,
+    /// 提币数量
+    pub amt: String,
+    /// 提币申请ID
+    pub wd_id: String,
+    /// 客户自定义ID
+    #[serde(default)]
+    pub client_id: String,
+    /// 币种链信息
+    #[serde(default)]
+    pub chain: String,
+}
+
+/// 充值地址
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositAddress {
+    /// 币种
+    pub ccy: String,
+    /// 币种链信息
+    pub chain: String,
+    /// 充值地址
+    pub addr: String,
+    /// 部分币种充值需要的标签
+    #[serde(default)]
+    pub tag: String,
+    /// 部分币种充值需要的 memo
+    #[serde(default)]
+    pub memo: String,
+    /// 部分币种充值需要的 payment_id
+    #[serde(default)]
+    pub pmt_id: String,
+    /// 合约地址后6位
+    #[serde(default)]
+    pub ct_addr: String,
+    /// 该地址是否为页面选中的地址
+    #[serde(default)]
+    pub selected: bool,
+    /// 转入账户 6：资金账户，18：交易账户
+    #[serde(default)]
+    pub to: String,
+}
+
+/// 币种信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrencyInfo {
+    /// 币种
+    pub ccy: String,
+    /// 币种名称
+    #[serde(default)]
+    pub name: String,
+    /// 币种链信息
+    pub chain: String,
+    /// 是否可充值
+    pub can_dep: bool,
+    /// 是否可提币
+    pub can_wd: bool,
+    /// 是否可内部转账
+    pub can_internal: bool,
+    /// 最小充值数量
+    #[serde(default)]
+    pub min_dep: String,
+    /// 最小提币数量
+    #[serde(default)]
+    pub min_wd: String,
+    /// 最大提币数量
+    #[serde(default)]
+    pub max_wd: String,
+    /// 提币精度，表示小数点后的位数
+    #[serde(default)]
+    pub wd_tick_sz: String,
+    /// 过去24小时内提币额度（USD）
+    #[serde(default)]
+    pub wd_quota: String,
+    /// 过去24小时内已用提币额度（USD）
+    #[serde(default)]
+    pub used_wd_quota: String,
+    /// 最小提币手续费
+    #[serde(default)]
+    pub min_fee: String,
+    /// 最大提币手续费
+    #[serde(default)]
+    pub max_fee: String,
+    /// 是否为主网
+    #[serde(default)]
+    pub main_net: bool,
+    /// 提币是否需要标签
+    #[serde(default)]
+    pub need_tag: bool,
+    /// 充值到账的最小网络确认数
+    #[serde(default)]
+    pub min_dep_arrival_confirm: String,
+    /// 提币解锁的最小网络确认数
+    #[serde(default)]
+    pub min_wd_unlock_confirm: String,
 }
 
 /// 提币记录
@@ -135,3 +308,23 @@ pub struct DepositRecord {
     /// 充值时间
     pub ts: String,
 }
+
+impl PageItem for WithdrawalRecord {
+    fn cursor(&self) -> &str {
+        &self.ts
+    }
+
+    fn timestamp_ms(&self) -> Option<i64> {
+        self.ts.parse().ok()
+    }
+}
+
+impl PageItem for DepositRecord {
+    fn cursor(&self) -> &str {
+        &self.ts
+    }
+
+    fn timestamp_ms(&self) -> Option<i64> {
+        self.ts.parse().ok()
+    }
+}
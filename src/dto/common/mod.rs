@@ -1,5 +1,8 @@
+use crate::error::{ApiErrorCode, Error, OkxItemError};
 use serde::{Deserialize, Serialize};
 
+pub use crate::enums::{InstrumentType, MarginMode, OrderState, OrderType, PositionSide, Side};
+
 /// 枚举转字符串
 pub trait EnumToStrTrait {
     fn as_str(&self) -> &'static str;
@@ -34,111 +37,116 @@ pub struct InstrumentInfo {
     pub base_currency: Option<String>,
 }
 
-/// 方向枚举
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
-pub enum Side {
-    #[serde(rename = "buy")]
-    Buy,
-    #[serde(rename = "sell")]
-    Sell,
+/// 分页信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaginationInfo {
+    /// 此次分页的最后一条数据的ID
+    #[serde(rename = "after", skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// 此次分页的第一条数据的ID
+    #[serde(rename = "before", skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    /// 请求的数据条数
+    #[serde(rename = "limit", skip_serializing_if = "Option::is_none")]
+    pub limit: Option<String>,
+}
+
+/// 分页查询中的单条记录，用于自动翻页
+pub trait PageItem {
+    /// 作为 `after` / `before` 参数的分页游标（记录ID或时间戳）
+    fn cursor(&self) -> &str;
+    /// 记录时间（毫秒时间戳），用于按时间范围停止翻页
+    fn timestamp_ms(&self) -> Option<i64>;
 }
-/// 方向枚举转字符串
-impl EnumToStrTrait for Side {
-    fn as_str(&self) -> &'static str {
-        match self {
-            Side::Buy => "buy",
-            Side::Sell => "sell",
+
+/// 批量操作中的单项结果
+pub trait BatchItem {
+    /// 事件执行结果的code，0代表成功
+    fn s_code(&self) -> &str;
+    /// 事件执行失败或成功时的msg
+    fn s_msg(&self) -> &str;
+
+    /// 该项是否执行成功
+    fn is_success(&self) -> bool {
+        self.s_code() == "0"
+    }
+
+    /// 转换为单项错误信息
+    fn item_error(&self) -> OkxItemError {
+        OkxItemError {
+            code: ApiErrorCode::from_code_str(self.s_code()),
+            s_code: self.s_code().to_string(),
+            s_msg: self.s_msg().to_string(),
         }
     }
 }
 
-/// 持仓方向枚举
-///
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
-pub enum PositionSide {
-    #[serde(rename = "long")]
-    Long,
-    #[serde(rename = "short")]
-    Short,
-    #[serde(rename = "net")]
-    Net, //买卖模式（交割/永续/期权：pos为正代表开多，pos为负代表开空。币币杠杆时，pos均为正，posCcy为交易货币时，代表开多；posCcy为计价货币时，代表开空。
+/// 批量操作结果，按 `sCode` 拆分为成功项和失败项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchResult<T> {
+    /// 响应code：0全部成功，1全部失败，2部分成功
+    pub code: String,
+    /// 响应msg
+    pub msg: String,
+    /// 执行成功的项
+    pub succeeded: Vec<T>,
+    /// 执行失败的项
+    pub failed: Vec<T>,
 }
 
-/// 持仓方向枚举转字符串
-impl EnumToStrTrait for PositionSide {
-    fn as_str(&self) -> &'static str {
-        match self {
-            PositionSide::Long => "long",
-            PositionSide::Short => "short",
-            PositionSide::Net => "net",
+impl<T: BatchItem> BatchResult<T> {
+    /// 根据响应的每一项结果创建批量结果
+    pub fn new(code: impl Into<String>, msg: impl Into<String>, items: Vec<T>) -> Self {
+        let (succeeded, failed) = items.into_iter().partition(|item| item.is_success());
+        Self {
+            code: code.into(),
+            msg: msg.into(),
+            succeeded,
+            failed,
         }
     }
-}
 
-/// 订单类型枚举
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
-pub enum OrderType {
-    #[serde(rename = "market")]
-    Market,
-    #[serde(rename = "limit")]
-    Limit,
-    #[serde(rename = "post_only")]
-    PostOnly,
-    #[serde(rename = "fok")]
-    FillOrKill,
-    #[serde(rename = "ioc")]
-    ImmediateOrCancel,
-    #[serde(rename = "optimal_limit_ioc")]
-    OptimalLimitIoc,
-}
+    /// 是否全部成功
+    pub fn is_all_success(&self) -> bool {
+        self.failed.is_empty()
+    }
 
-/// 订单状态枚举
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
-pub enum OrderState {
-    #[serde(rename = "canceled")]
-    Canceled,
-    #[serde(rename = "live")]
-    Live,
-    #[serde(rename = "partially_filled")]
-    PartiallyFilled,
-    #[serde(rename = "filled")]
-    Filled,
-}
+    /// 是否部分成功
+    pub fn is_partial_success(&self) -> bool {
+        !self.succeeded.is_empty() && !self.failed.is_empty()
+    }
 
-/// 杠杆方式
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
-pub enum MarginMode {
-    #[serde(rename = "isolated")]
-    Isolated,
-    #[serde(rename = "cross")]
-    Cross,
-}
+    /// 结果总数
+    pub fn len(&self) -> usize {
+        self.succeeded.len() + self.failed.len()
+    }
 
-/// 产品类型
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
-pub enum InstrumentType {
-    #[serde(rename = "SPOT")]
-    Spot,
-    #[serde(rename = "MARGIN")]
-    Margin,
-    #[serde(rename = "SWAP")]
-    Swap,
-    #[serde(rename = "FUTURES")]
-    Futures,
-    #[serde(rename = "OPTION")]
-    Option,
-}
+    /// 是否没有任何结果
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 
-/// 分页信息
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PaginationInfo {
-    /// 此次分页的最后一条数据的ID
-    #[serde(rename = "after", skip_serializing_if = "Option::is_none")]
-    pub after: Option<String>,
-    /// 此次分页的第一条数据的ID
-    #[serde(rename = "before", skip_serializing_if = "Option::is_none")]
-    pub before: Option<String>,
-    /// 请求的数据条数
-    #[serde(rename = "limit", skip_serializing_if = "Option::is_none")]
-    pub limit: Option<String>,
+    /// 失败项的错误信息
+    pub fn errors(&self) -> Vec<OkxItemError> {
+        self.failed.iter().map(BatchItem::item_error).collect()
+    }
+
+    /// 全部成功时返回成功项，否则返回包含每个失败项错误码的 `OkxApiError`
+    pub fn into_result(self) -> Result<Vec<T>, Error> {
+        if self.failed.is_empty() {
+            return Ok(self.succeeded);
+        }
+        let item_errors = self.errors();
+        let smg = item_errors
+            .first()
+            .map(|item| item.s_msg.clone())
+            .unwrap_or_default();
+        Err(Error::OkxApiError {
+            api_code: ApiErrorCode::from_code_str(&self.code),
+            code: self.code,
+            message: self.msg,
+            smg,
+            item_errors,
+        })
+    }
 }
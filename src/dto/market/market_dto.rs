@@ -1,3 +1,5 @@
+use crate::decimal::{num, num_levels, to_num, Num};
+use crate::dto::common::PageItem;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -7,27 +9,32 @@ pub struct TickerOkxResDto {
     pub inst_type: String,
     #[serde(rename = "instId")]
     pub inst_id: String,
-    pub last: String,
-    #[serde(rename = "lastSz")]
-    pub last_sz: String,
-    #[serde(rename = "askPx")]
-    pub ask_px: String,
-    #[serde(rename = "askSz")]
-    pub ask_sz: String,
-    #[serde(rename = "bidPx")]
-    pub bid_px: String,
-    #[serde(rename = "bidSz")]
-    pub bid_sz: String,
-    pub open24h: String,
-    pub high24h: String,
-    pub low24h: String,
-    #[serde(rename = "volCcy24h")]
-    pub vol_ccy24h: String,
-    pub vol24h: String,
-    #[serde(rename = "sodUtc0")]
-    pub sod_utc0: String,
-    #[serde(rename = "sodUtc8")]
-    pub sod_utc8: String,
+    #[serde(with = "num")]
+    pub last: Num,
+    #[serde(rename = "lastSz", with = "num")]
+    pub last_sz: Num,
+    #[serde(rename = "askPx", with = "num")]
+    pub ask_px: Num,
+    #[serde(rename = "askSz", with = "num")]
+    pub ask_sz: Num,
+    #[serde(rename = "bidPx", with = "num")]
+    pub bid_px: Num,
+    #[serde(rename = "bidSz", with = "num")]
+    pub bid_sz: Num,
+    #[serde(with = "num")]
+    pub open24h: Num,
+    #[serde(with = "num")]
+    pub high24h: Num,
+    #[serde(with = "num")]
+    pub low24h: Num,
+    #[serde(rename = "volCcy24h", with = "num")]
+    pub vol_ccy24h: Num,
+    #[serde(with = "num")]
+    pub vol24h: Num,
+    #[serde(rename = "sodUtc0", with = "num")]
+    pub sod_utc0: Num,
+    #[serde(rename = "sodUtc8", with = "num")]
+    pub sod_utc8: Num,
     pub ts: String,
 }
 
@@ -37,36 +44,53 @@ pub struct CandleOkxRespDto {
     /// 开盘时间
     pub ts: String,
     /// 开盘价格
-    pub o: String,
+    #[serde(with = "num")]
+    pub o: Num,
     /// 最高价格
-    pub h: String,
+    #[serde(with = "num")]
+    pub h: Num,
     /// 最低价格
-    pub l: String,
+    #[serde(with = "num")]
+    pub l: Num,
     /// 收盘价格
-    pub c: String,
+    #[serde(with = "num")]
+    pub c: Num,
     /// 成交量
-    pub v: String,
+    #[serde(with = "num")]
+    pub v: Num,
     /// 成交量，以货币计量
-    pub vol_ccy: String,
+    #[serde(with = "num")]
+    pub vol_ccy: Num,
     /// 成交量，以货币计量
-    pub vol_ccy_quote: String,
+    #[serde(with = "num")]
+    pub vol_ccy_quote: Num,
     /// 是否已确认
     pub confirm: String,
 }
 
+impl PageItem for CandleOkxRespDto {
+    fn cursor(&self) -> &str {
+        &self.ts
+    }
+
+    fn timestamp_ms(&self) -> Option<i64> {
+        self.ts.parse().ok()
+    }
+}
+
 impl CandleOkxRespDto {
     pub fn from_vec(v: Vec<String>) -> Self {
         // 这里请根据你的结构体字段实际情况进行赋值
         // 例如：
         Self {
             ts: v[0].clone(),
-            o: v[1].clone(),
-            h: v[2].clone(),
-            l: v[3].clone(),
-            c: v[4].clone(),
-            v: v[5].clone(),
-            vol_ccy: v[6].clone(),
-            vol_ccy_quote: v[7].clone(),
+            o: to_num(&v[1]),
+            h: to_num(&v[2]),
+            l: to_num(&v[3]),
+            c: to_num(&v[4]),
+            v: to_num(&v[5]),
+            vol_ccy: to_num(&v[6]),
+            vol_ccy_quote: to_num(&v[7]),
             confirm: v[8].clone(),
         }
     }
@@ -79,9 +103,11 @@ pub struct Depth {
     #[serde(rename = "instId")]
     pub inst_id: String,
     /// 卖方深度
-    pub asks: Vec<Vec<String>>,
+    #[serde(with = "num_levels")]
+    pub asks: Vec<Vec<Num>>,
     /// 买方深度
-    pub bids: Vec<Vec<String>>,
+    #[serde(with = "num_levels")]
+    pub bids: Vec<Vec<Num>>,
     /// 时间戳
     pub ts: String,
 }
@@ -105,14 +131,23 @@ pub struct InstrumentOkxResDto {
     #[serde(rename = "quoteCcy", skip_serializing_if = "Option::is_none")]
     pub quote_currency: Option<String>,
     /// 下单价格精度
-    #[serde(rename = "tickSz")]
-    pub tick_size: String,
+    #[serde(rename = "tickSz", with = "num")]
+    pub tick_size: Num,
     /// 下单数量精度
-    #[serde(rename = "lotSz")]
-    pub lot_size: String,
+    #[serde(rename = "lotSz", with = "num")]
+    pub lot_size: Num,
     /// 最小下单数量
-    #[serde(rename = "minSz")]
-    pub min_size: String,
+    #[serde(rename = "minSz", with = "num")]
+    pub min_size: Num,
+    /// 限价单的单笔最大委托数量
+    #[serde(rename = "maxLmtSz", default, with = "num")]
+    pub max_limit_size: Num,
+    /// 市价单的单笔最大委托数量，币币和杠杆为计价货币数量
+    #[serde(rename = "maxMktSz", default, with = "num")]
+    pub max_market_size: Num,
+    /// 合约面值，仅适用于交割、永续、期权
+    #[serde(rename = "ctVal", default, with = "num")]
+    pub contract_value: Num,
     /// 产品状态
     pub state: String,
 }
@@ -128,11 +163,11 @@ pub struct TradeOkxResDto {
     #[serde(rename = "tradeId")]
     pub trade_id: String,
     /// 成交价格
-    #[serde(rename = "px")]
-    pub px: String,
+    #[serde(rename = "px", with = "num")]
+    pub px: Num,
     /// 成交数量
-    #[serde(rename = "sz")]
-    pub sz: String,
+    #[serde(rename = "sz", with = "num")]
+    pub sz: Num,
     /// 成交方向 (buy, sell)
     pub side: String,
     /// 成交时间
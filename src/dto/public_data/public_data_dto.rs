@@ -1,3 +1,4 @@
+use crate::dto::common::PageItem;
 use serde::{Deserialize, Serialize};
 
 /// 系统时间信息
@@ -175,3 +176,13 @@ pub struct FundingRateHistoryOkxRespDto {
     /// 资金费收取逻辑
     pub method: String,
 }
+
+impl PageItem for FundingRateHistoryOkxRespDto {
+    fn cursor(&self) -> &str {
+        &self.funding_time
+    }
+
+    fn timestamp_ms(&self) -> Option<i64> {
+        self.funding_time.parse().ok()
+    }
+}
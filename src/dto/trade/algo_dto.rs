@@ -0,0 +1,666 @@
+use crate::decimal::{num, num_param, opt_num_param, Num, NumParam};
+use crate::dto::common::BatchItem;
+use crate::enums::{
+    AlgoOrderState, AlgoOrderType, InstrumentType, PositionSide, Side, TargetCurrency, TdMode,
+    TriggerPxType,
+};
+use serde::{Deserialize, Serialize};
+
+/// 策略委托下单请求参数结构体
+///
+/// 各策略类型特有的参数由 `kind` 给出，序列化时展开到请求体中，并根据取值写入 `ordType`。
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoOrderReqDto {
+    /// 产品ID，如 BTC-USDT
+    pub inst_id: String,
+    /// 交易模式
+    /// 保证金模式：isolated：逐仓 ；cross：全仓
+    /// 非保证金模式：cash：非保证金
+    pub td_mode: TdMode,
+    /// 保证金币种，仅适用于单币种保证金模式下的全仓杠杆订单
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<String>,
+    /// 订单方向
+    /// buy：买， sell：卖
+    pub side: Side,
+    /// 持仓方向
+    /// 在开平仓模式下必填，且仅可选择 long 或 short。 仅适用交割、永续。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pos_side: Option<PositionSide>,
+    /// 委托数量，sz 和 closeFraction 必须填写其中一个
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub sz: Option<NumParam>,
+    /// 策略委托触发时平仓的百分比，1 代表100%，仅适用于交割、永续的单向止盈止损
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub close_fraction: Option<NumParam>,
+    /// 订单标签
+    /// 字母（区分大小写）与数字的组合，可以是纯字母、纯数字，且长度在1-16位之间。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    /// 委托数量的类型，仅适用于币币市价订单
+    /// base_ccy: 交易货币 ；quote_ccy：计价货币
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tgt_ccy: Option<TargetCurrency>,
+    /// 客户自定义策略订单ID
+    /// 字母（区分大小写）与数字的组合，可以是纯字母、纯数字且长度要在1-32位之间。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algo_cl_ord_id: Option<String>,
+    /// 是否只减仓，true 或 false，默认false
+    /// 仅适用于币币杠杆，以及买卖模式下的交割/永续
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+    /// 策略类型及其参数
+    #[serde(flatten)]
+    pub kind: AlgoOrderKind,
+}
+
+impl AlgoOrderReqDto {
+    /// 创建策略委托请求，其余可选参数默认为空
+    pub fn new(
+        inst_id: impl Into<String>,
+        td_mode: TdMode,
+        side: Side,
+        sz: Option<NumParam>,
+        kind: AlgoOrderKind,
+    ) -> Self {
+        Self {
+            inst_id: inst_id.into(),
+            td_mode,
+            ccy: None,
+            side,
+            pos_side: None,
+            sz,
+            close_fraction: None,
+            tag: None,
+            tgt_ccy: None,
+            algo_cl_ord_id: None,
+            reduce_only: None,
+            kind,
+        }
+    }
+
+    /// 策略委托类型
+    pub fn ord_type(&self) -> AlgoOrderType {
+        self.kind.ord_type()
+    }
+}
+
+/// 策略委托类型及其特有参数
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "ordType")]
+pub enum AlgoOrderKind {
+    /// 单向止盈止损
+    #[serde(rename = "conditional")]
+    Conditional(TpSlAlgoParams),
+    /// 双向止盈止损，需要同时填写止盈和止损参数
+    #[serde(rename = "oco")]
+    Oco(TpSlAlgoParams),
+    /// 计划委托
+    #[serde(rename = "trigger")]
+    Trigger(TriggerAlgoParams),
+    /// 移动止盈止损
+    #[serde(rename = "move_order_stop")]
+    MoveOrderStop(MoveOrderStopParams),
+    /// 冰山委托
+    #[serde(rename = "iceberg")]
+    Iceberg(IcebergParams),
+    /// 时间加权委托
+    #[serde(rename = "twap")]
+    Twap(TwapParams),
+}
+
+impl AlgoOrderKind {
+    /// 对应的 `ordType`
+    pub fn ord_type(&self) -> AlgoOrderType {
+        match self {
+            AlgoOrderKind::Conditional(_) => AlgoOrderType::Conditional,
+            AlgoOrderKind::Oco(_) => AlgoOrderType::Oco,
+            AlgoOrderKind::Trigger(_) => AlgoOrderType::Trigger,
+            AlgoOrderKind::MoveOrderStop(_) => AlgoOrderType::MoveOrderStop,
+            AlgoOrderKind::Iceberg(_) => AlgoOrderType::Iceberg,
+            AlgoOrderKind::Twap(_) => AlgoOrderType::Twap,
+        }
+    }
+}
+
+/// 止盈止损参数，适用于 `conditional` 和 `oco`
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TpSlAlgoParams {
+    /// 止盈触发价，如果填写此参数，必须填写 止盈委托价
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub tp_trigger_px: Option<NumParam>,
+    /// 止盈触发价类型，默认为last
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_trigger_px_type: Option<TriggerPxType>,
+    /// 止盈委托价，委托价格为-1时，执行市价止盈
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub tp_ord_px: Option<NumParam>,
+    /// 止损触发价，如果填写此参数，必须填写 止损委托价
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub sl_trigger_px: Option<NumParam>,
+    /// 止损触发价类型，默认为last
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_trigger_px_type: Option<TriggerPxType>,
+    /// 止损委托价，委托价格为-1时，执行市价止损
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub sl_ord_px: Option<NumParam>,
+    /// 仓位全平时是否撤销该策略委托，默认false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cxl_on_close_pos: Option<bool>,
+}
+
+/// 计划委托参数
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerAlgoParams {
+    /// 计划委托触发价格
+    #[serde(with = "num_param")]
+    pub trigger_px: NumParam,
+    /// 委托价格，委托价格为-1时，执行市价委托
+    #[serde(with = "num_param")]
+    pub order_px: NumParam,
+    /// 计划委托触发价格类型，默认为last
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_px_type: Option<TriggerPxType>,
+}
+
+/// 移动止盈止损参数
+///
+/// `callback_ratio` 和 `callback_spread` 只能填写其中一个。
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveOrderStopParams {
+    /// 回调幅度的比例，如 0.05 代表 5%
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub callback_ratio: Option<NumParam>,
+    /// 回调幅度的价距
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub callback_spread: Option<NumParam>,
+    /// 激活价格，不填写时委托后立即激活
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub active_px: Option<NumParam>,
+}
+
+/// 冰山委托参数
+///
+/// `px_var` 和 `px_spread` 只能填写其中一个。
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct IcebergParams {
+    /// 距离盘口的比例价距
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub px_var: Option<NumParam>,
+    /// 距离盘口的价距
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub px_spread: Option<NumParam>,
+    /// 单笔数量
+    #[serde(with = "num_param")]
+    pub sz_limit: NumParam,
+    /// 挂单限制价
+    #[serde(with = "num_param")]
+    pub px_limit: NumParam,
+}
+
+/// 时间加权委托参数
+///
+/// `px_var` 和 `px_spread` 只能填写其中一个。
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TwapParams {
+    /// 距离盘口的比例价距
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub px_var: Option<NumParam>,
+    /// 距离盘口的价距
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub px_spread: Option<NumParam>,
+    /// 单笔数量
+    #[serde(with = "num_param")]
+    pub sz_limit: NumParam,
+    /// 挂单限制价
+    #[serde(with = "num_param")]
+    pub px_limit: NumParam,
+    /// 下单间隔，单位为秒
+    pub time_interval: String,
+}
+
+/// 策略委托下单响应数据
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoOrderResDto {
+    /// 策略委托单ID
+    pub algo_id: String,
+    /// 客户自定义订单ID（已弃用）
+    #[serde(default)]
+    pub cl_ord_id: Option<String>,
+    /// 客户自定义策略订单ID
+    #[serde(default)]
+    pub algo_cl_ord_id: Option<String>,
+    /// 订单标签
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// 事件执行结果的code，0代表成功
+    pub s_code: String,
+    /// 事件执行失败时的msg
+    #[serde(default)]
+    pub s_msg: Option<String>,
+}
+
+impl BatchItem for AlgoOrderResDto {
+    fn s_code(&self) -> &str {
+        &self.s_code
+    }
+
+    fn s_msg(&self) -> &str {
+        self.s_msg.as_deref().unwrap_or_default()
+    }
+}
+
+/// 撤销策略委托请求参数结构体
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAlgoOrderReqDto {
+    /// 产品ID，如 BTC-USDT
+    pub inst_id: String,
+    /// 策略委托单ID。algoId 和 algoClOrdId 必须传一个
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algo_id: Option<String>,
+    /// 客户自定义策略订单ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algo_cl_ord_id: Option<String>,
+}
+
+/// 修改策略委托请求参数结构体
+///
+/// 仅支持修改单向止盈止损和计划委托（交割、永续）。
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendAlgoOrderReqDto {
+    /// 产品ID，如 BTC-USDT-SWAP
+    pub inst_id: String,
+    /// 策略委托单ID。algoId 和 algoClOrdId 必须传一个
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algo_id: Option<String>,
+    /// 客户自定义策略订单ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algo_cl_ord_id: Option<String>,
+    /// 当订单修改失败时，该订单是否需要自动撤销，默认为false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cxl_on_fail: Option<bool>,
+    /// 用户自定义修改事件ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub req_id: Option<String>,
+    /// 修改的新数量
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_sz: Option<NumParam>,
+    /// 止盈触发价，填写0时删除止盈
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_tp_trigger_px: Option<NumParam>,
+    /// 止盈委托价，委托价格为-1时，执行市价止盈
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_tp_ord_px: Option<NumParam>,
+    /// 止盈触发价类型
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_tp_trigger_px_type: Option<TriggerPxType>,
+    /// 止损触发价，填写0时删除止损
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_sl_trigger_px: Option<NumParam>,
+    /// 止损委托价，委托价格为-1时，执行市价止损
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_sl_ord_px: Option<NumParam>,
+    /// 止损触发价类型
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_sl_trigger_px_type: Option<TriggerPxType>,
+    /// 计划委托的新触发价
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_trigger_px: Option<NumParam>,
+    /// 计划委托的新委托价，委托价格为-1时，执行市价委托
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_ord_px: Option<NumParam>,
+    /// 计划委托的新触发价类型
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_trigger_px_type: Option<TriggerPxType>,
+}
+
+/// 修改策略委托响应数据
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendAlgoOrderResDto {
+    /// 策略委托单ID
+    pub algo_id: String,
+    /// 客户自定义策略订单ID
+    #[serde(default)]
+    pub algo_cl_ord_id: Option<String>,
+    /// 用户自定义修改事件ID
+    #[serde(default)]
+    pub req_id: Option<String>,
+    /// 事件执行结果的code，0代表成功
+    pub s_code: String,
+    /// 事件执行失败时的msg
+    #[serde(default)]
+    pub s_msg: Option<String>,
+}
+
+impl BatchItem for AmendAlgoOrderResDto {
+    fn s_code(&self) -> &str {
+        &self.s_code
+    }
+
+    fn s_msg(&self) -> &str {
+        self.s_msg.as_deref().unwrap_or_default()
+    }
+}
+
+/// 策略委托列表查询参数，用于未完成策略委托和历史策略委托
+///
+/// 查询历史策略委托时 `state` 和 `algo_id` 必须填写一个。
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoOrderListReqDto {
+    /// 策略委托类型
+    pub ord_type: AlgoOrderType,
+    /// 策略委托单ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algo_id: Option<String>,
+    /// 策略委托订单状态，仅适用于历史策略委托
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<AlgoOrderState>,
+    /// 产品类型
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_type: Option<InstrumentType>,
+    /// 产品ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<String>,
+    /// 请求此ID之前（更旧的数据）的分页内容，传的值为对应接口的algoId
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// 请求此ID之后（更新的数据）的分页内容，传的值为对应接口的algoId
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    /// 返回结果的数量，最大为100，默认100条
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl AlgoOrderListReqDto {
+    /// 按策略委托类型查询，其余条件为空
+    pub fn new(ord_type: AlgoOrderType) -> Self {
+        Self {
+            ord_type,
+            algo_id: None,
+            state: None,
+            inst_type: None,
+            inst_id: None,
+            after: None,
+            before: None,
+            limit: None,
+        }
+    }
+}
+
+/// 策略委托详情
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AlgoOrderDetailRespDto {
+    /// 产品类型
+    pub inst_type: String,
+    /// 产品ID
+    pub inst_id: String,
+    /// 保证金币种
+    pub ccy: String,
+    /// 最新一笔订单ID，即将废弃
+    pub ord_id: String,
+    /// 订单ID列表，当止盈止损存在市价拆单时，会有多个
+    pub ord_id_list: Vec<String>,
+    /// 策略委托单ID
+    pub algo_id: String,
+    /// 客户自定义订单ID
+    pub cl_ord_id: String,
+    /// 客户自定义策略订单ID
+    pub algo_cl_ord_id: String,
+    /// 委托数量
+    #[serde(with = "num")]
+    pub sz: Num,
+    /// 策略委托触发时平仓的百分比
+    #[serde(with = "num")]
+    pub close_fraction: Num,
+    /// 策略委托类型
+    pub ord_type: String,
+    /// 订单方向
+    pub side: String,
+    /// 持仓方向
+    pub pos_side: String,
+    /// 交易模式
+    pub td_mode: String,
+    /// 币币市价单委托数量sz的单位
+    pub tgt_ccy: String,
+    /// 订单状态
+    pub state: String,
+    /// 杠杆倍数，仅适用于币币杠杆/交割/永续
+    #[serde(with = "num")]
+    pub lever: Num,
+    /// 止盈触发价
+    #[serde(with = "num")]
+    pub tp_trigger_px: Num,
+    /// 止盈触发价类型
+    pub tp_trigger_px_type: String,
+    /// 止盈委托价
+    #[serde(with = "num")]
+    pub tp_ord_px: Num,
+    /// 止损触发价
+    #[serde(with = "num")]
+    pub sl_trigger_px: Num,
+    /// 止损触发价类型
+    pub sl_trigger_px_type: String,
+    /// 止损委托价
+    #[serde(with = "num")]
+    pub sl_ord_px: Num,
+    /// 计划委托触发价格
+    #[serde(with = "num")]
+    pub trigger_px: Num,
+    /// 计划委托触发价格类型
+    pub trigger_px_type: String,
+    /// 计划委托委托价格
+    #[serde(with = "num")]
+    pub ord_px: Num,
+    /// 实际委托量
+    #[serde(with = "num")]
+    pub actual_sz: Num,
+    /// 实际委托价
+    #[serde(with = "num")]
+    pub actual_px: Num,
+    /// 实际触发方向，tp：止盈；sl：止损，仅适用于单向止盈止损委托和双向止盈止损委托
+    pub actual_side: String,
+    /// 策略委托触发时间，Unix时间戳的毫秒数格式
+    pub trigger_time: String,
+    /// 距离盘口的比例价距，仅适用于冰山委托和时间加权委托
+    #[serde(with = "num")]
+    pub px_var: Num,
+    /// 距离盘口的价距，仅适用于冰山委托和时间加权委托
+    #[serde(with = "num")]
+    pub px_spread: Num,
+    /// 单笔数量，仅适用于冰山委托和时间加权委托
+    #[serde(with = "num")]
+    pub sz_limit: Num,
+    /// 挂单限制价，仅适用于冰山委托和时间加权委托
+    #[serde(with = "num")]
+    pub px_limit: Num,
+    /// 下单间隔，仅适用于时间加权委托
+    pub time_interval: String,
+    /// 回调幅度的比例，仅适用于移动止盈止损
+    #[serde(with = "num")]
+    pub callback_ratio: Num,
+    /// 回调幅度的价距，仅适用于移动止盈止损
+    #[serde(with = "num")]
+    pub callback_spread: Num,
+    /// 移动止盈止损激活价格，仅适用于移动止盈止损
+    #[serde(with = "num")]
+    pub active_px: Num,
+    /// 移动止盈止损触发价格，仅适用于移动止盈止损
+    #[serde(with = "num")]
+    pub move_trigger_px: Num,
+    /// 是否只减仓，true 或 false
+    pub reduce_only: String,
+    /// 下单时的最新成交价
+    #[serde(with = "num")]
+    pub last: Num,
+    /// 委托失败的错误码，默认为""
+    pub fail_code: String,
+    /// 订单标签
+    pub tag: String,
+    /// 订单创建时间，Unix时间戳的毫秒数格式
+    pub c_time: String,
+    /// 订单更新时间，Unix时间戳的毫秒数格式
+    pub u_time: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_algo_order_kind_as_ord_type() {
+        let mut order = AlgoOrderReqDto::new(
+            "BTC-USDT-SWAP",
+            TdMode::Cross,
+            Side::Sell,
+            Some("1".parse().unwrap()),
+            AlgoOrderKind::Conditional(TpSlAlgoParams {
+                sl_trigger_px: Some("25000".parse().unwrap()),
+                sl_ord_px: Some("-1".parse().unwrap()),
+                ..Default::default()
+            }),
+        );
+        order.pos_side = Some(PositionSide::Long);
+
+        let value = serde_json::to_value(&order).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "instId": "BTC-USDT-SWAP",
+                "tdMode": "cross",
+                "side": "sell",
+                "posSide": "long",
+                "sz": "1",
+                "ordType": "conditional",
+                "slTriggerPx": "25000",
+                "slOrdPx": "-1"
+            })
+        );
+
+        let twap = AlgoOrderKind::Twap(TwapParams {
+            px_var: Some("0.01".parse().unwrap()),
+            px_spread: None,
+            sz_limit: "10".parse().unwrap(),
+            px_limit: "30000".parse().unwrap(),
+            time_interval: "10".to_string(),
+        });
+        let value = serde_json::to_value(&twap).unwrap();
+        assert_eq!(value["ordType"], "twap");
+        assert_eq!(value["szLimit"], "10");
+        assert!(value.get("pxSpread").is_none());
+    }
+
+    #[test]
+    fn deserializes_algo_order_request_by_ord_type() {
+        let order: AlgoOrderReqDto = serde_json::from_value(serde_json::json!({
+            "instId": "BTC-USDT",
+            "tdMode": "cash",
+            "side": "buy",
+            "sz": "0.1",
+            "ordType": "move_order_stop",
+            "callbackRatio": "0.05"
+        }))
+        .unwrap();
+
+        assert_eq!(order.ord_type(), AlgoOrderType::MoveOrderStop);
+        match order.kind {
+            AlgoOrderKind::MoveOrderStop(params) => {
+                assert_eq!(params.callback_ratio, Some("0.05".parse().unwrap()));
+                assert!(params.active_px.is_none());
+            }
+            other => panic!("unexpected kind: {:?}", other),
+        }
+    }
+}
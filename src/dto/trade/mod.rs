@@ -1 +1,2 @@
+pub mod algo_dto;
 pub mod trade_dto;
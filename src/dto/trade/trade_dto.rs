@@ -1,36 +1,10 @@
-use crate::dto::common::{MarginMode, OrderType, PositionSide, Side};
-use crate::dto::EnumToStrTrait;
+use crate::decimal::{num, num_param, opt_num, opt_num_param, Num, NumParam, OptNum};
+use crate::dto::common::{BatchItem, PageItem};
+use crate::enums::{
+    InstrumentType, MarginMode, OrderState, OrderType, PositionSide, SelfTradePreventionMode, Side,
+    TargetCurrency, TdMode, TpOrdKind, TriggerPxType,
+};
 use serde::{Deserialize, Serialize};
-///保证金模式
-pub enum TdModeEnum {
-    /// 保证金模式：isolated：逐仓
-    ISOLATED,
-    //保证金模式 ；cross：全仓
-    CROSS,
-    ///非保证模式，现货
-    CASH,
-}
-impl EnumToStrTrait for TdModeEnum {
-    fn as_str(&self) -> &'static str {
-        match self {
-            TdModeEnum::ISOLATED => "isolated",
-            TdModeEnum::CROSS => "cross",
-            TdModeEnum::CASH => "cash",
-        }
-    }
-}
-
-// ///保证金模式
-// impl Display for TdModeEnum {
-//     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-//         match self {
-//             TdModeEnum::ISOLATED => write!(f, "isolated"),
-//             TdModeEnum::CROSS => write!(f, "cross"),
-//             TdModeEnum::CASH => write!(f, "cash"),
-//         }
-//     }
-// }
-
 ///止盈止损请求参数结构体
 #[derive(Serialize, Deserialize, Debug)]
 pub struct AttachAlgoOrdReqDto {
@@ -40,37 +14,42 @@ pub struct AttachAlgoOrdReqDto {
     pub attach_algo_cl_ord_id: Option<String>,
     /// 止盈触发价
     /// 对于条件止盈单，如果填写此参数，必须填写 止盈委托价
-    pub tp_trigger_px: Option<String>,
+    #[serde(default, with = "opt_num_param")]
+    pub tp_trigger_px: Option<NumParam>,
     /// 止盈委托价
     /// 对于条件止盈单，如果填写此参数，必须填写 止盈触发价
     /// 对于限价止盈单，需填写此参数，不需要填写止盈触发价
     /// 委托价格为-1时，执行市价止盈
-    pub tp_ord_px: Option<String>,
+    #[serde(default, with = "opt_num_param")]
+    pub tp_ord_px: Option<NumParam>,
     /// 止盈订单类型
     /// condition: 条件单
     /// limit: 限价单
     /// 默认为condition
-    pub tp_ord_kind: Option<String>,
+    pub tp_ord_kind: Option<TpOrdKind>,
     /// 止盈触发价类型
     /// last：最新价格
     /// index：指数价格
     /// mark：标记价格
     /// 默认为last
-    pub tp_trigger_px_type: Option<String>,
+    pub tp_trigger_px_type: Option<TriggerPxType>,
 
     /// 止损触发价，如果填写此参数，必须填写 止损委托价
-    pub sl_trigger_px: Option<String>,
+    #[serde(default, with = "opt_num_param")]
+    pub sl_trigger_px: Option<NumParam>,
     /// 止损委托价，如果填写此参数，必须填写 止损触发价
     /// 委托价格为-1时，执行市价止损
-    pub sl_ord_px: Option<String>,
+    #[serde(default, with = "opt_num_param")]
+    pub sl_ord_px: Option<NumParam>,
     /// 止损触发价类型
     /// last：最新价格
     /// index：指数价格
     /// mark：标记价格
     /// 默认为last
-    pub sl_trigger_px_type: Option<String>,
+    pub sl_trigger_px_type: Option<TriggerPxType>,
     /// 数量。仅适用于“多笔止盈”的止盈订单，且对于“多笔止盈”的止盈订单必填
-    pub sz: Option<String>,
+    #[serde(default, with = "opt_num_param")]
+    pub sz: Option<NumParam>,
     /// 是否启用开仓价止损，仅适用于分批止盈的止损订单，第一笔止盈触发时，止损触发价格是否移动到开仓均价止损
     /// 0：不开启，默认值
     /// 1：开启，且止损触发价不能为空
@@ -84,21 +63,21 @@ impl AttachAlgoOrdReqDto {
     /// sl_ord_px: 止损委托价
     /// sz: 数量
     pub fn new(
-        tp_trigger_px: Option<String>,
-        tp_ord_px: Option<String>,
-        sl_trigger_px: Option<String>,
-        sl_ord_px: Option<String>,
-        sz: String,
+        tp_trigger_px: Option<NumParam>,
+        tp_ord_px: Option<NumParam>,
+        sl_trigger_px: Option<NumParam>,
+        sl_ord_px: Option<NumParam>,
+        sz: NumParam,
     ) -> Self {
         Self {
             attach_algo_cl_ord_id: None,
-            tp_trigger_px: tp_trigger_px,
-            tp_ord_px: tp_ord_px,
-            tp_ord_kind: Some(TpOrdKindEnum::CONDITION.as_str().to_owned()),
-            sl_trigger_px: sl_trigger_px,
-            sl_ord_px: sl_ord_px,
-            tp_trigger_px_type: Some("last".to_string()),
-            sl_trigger_px_type: Some("last".to_string()),
+            tp_trigger_px,
+            tp_ord_px,
+            tp_ord_kind: Some(TpOrdKind::Condition),
+            sl_trigger_px,
+            sl_ord_px,
+            tp_trigger_px_type: Some(TriggerPxType::Last),
+            sl_trigger_px_type: Some(TriggerPxType::Last),
             sz: Some(sz),
             amend_px_on_trigger_type: Some(0),
         }
@@ -114,7 +93,7 @@ pub struct OrderReqDto {
     /// 保证金模式：isolated：逐仓 ；cross：全仓
     /// 非保证金模式：cash：非保证金
     /// spot_isolated：现货逐仓(仅适用于现货带单) ，现货带单时，tdMode 的值需要指定为spot_isolated
-    pub td_mode: String,
+    pub td_mode: TdMode,
     /// 保证金币种，仅适用于单币种保证金模式下的全仓杠杆订单
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ccy: Option<String>,
@@ -128,11 +107,11 @@ pub struct OrderReqDto {
     pub tag: Option<String>,
     /// 订单方向
     /// buy：买， sell：卖
-    pub side: String,
+    pub side: Side,
     /// 持仓方向
     /// 在开平仓模式下必填，且仅可选择 long 或 short。 仅适用交割、永续。
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pos_side: Option<String>,
+    pub pos_side: Option<PositionSide>,
     /// 订单类型
     /// market：市价单
     /// limit：限价单
@@ -142,21 +121,34 @@ pub struct OrderReqDto {
     /// optimal_limit_ioc：市价委托立即成交并取消剩余（仅适用交割、永续）
     /// mmp：做市商保护(仅适用于组合保证金账户模式下的期权订单)
     /// mmp_and_post_only：做市商保护且只做maker单(仅适用于组合保证金账户模式下的期权订单)
-    pub ord_type: String,
+    pub ord_type: OrderType,
     /// 委托数量
-    pub sz: String,
+    #[serde(with = "num_param")]
+    pub sz: NumParam,
     /// 委托价格，仅适用于limit、post_only、fok、Ioc、mmp、mmp_and_post_only类型的订单
     /// 期权下单时，px/pxUsd/pxVol 只能填一个
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub px: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub px: Option<NumParam>,
     /// 以USD价格进行期权下单，仅适用于期权
     /// 期权下单时 px/pxUsd/pxVol 必填一个，且只能填一个
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub px_usd: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub px_usd: Option<NumParam>,
     /// 以隐含波动率进行期权下单，例如 1 代表 100%，仅适用于期权
     /// 期权下单时 px/pxUsd/pxVol 必填一个，且只能填一个
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub px_vol: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub px_vol: Option<NumParam>,
     /// 是否只减仓，true 或 false，默认false
     /// 仅适用于币币杠杆，以及买卖模式下的交割/永续
     /// 仅适用于单币种保证金模式和跨币种保证金模式
@@ -166,7 +158,7 @@ pub struct OrderReqDto {
     /// base_ccy: 交易货币 ；quote_ccy：计价货币
     /// 买单默认quote_ccy， 卖单默认base_ccy
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tgt_ccy: Option<String>,
+    pub tgt_ccy: Option<TargetCurrency>,
     /// 是否禁止币币市价改单，true 或 false，默认false
     /// 为true时，余额不足时，系统不会改单，下单会失败，仅适用于币币市价单
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -185,7 +177,7 @@ pub struct OrderReqDto {
     /// cancel_maker,cancel_taker, cancel_both
     /// Cancel both不支持FOK
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stp_mode: Option<String>,
+    pub stp_mode: Option<SelfTradePreventionMode>,
     /// 交易报价币种，仅适用于现货统一USD订单簿
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trade_quote_ccy: Option<String>,
@@ -195,7 +187,7 @@ pub struct OrderReqDto {
 }
 
 /// 撤单请求参数结构体
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelOrderReqDto {
     /// 产品ID，如 BTC-USDT
@@ -208,6 +200,151 @@ pub struct CancelOrderReqDto {
     pub cl_ord_id: Option<String>,
 }
 
+/// 修改订单请求参数结构体
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendOrderReqDto {
+    /// 产品ID，如 BTC-USDT
+    pub inst_id: String,
+    /// 订单ID。ordId 和 clOrdId 至少填写一个，两者都传时优先使用 ordId
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ord_id: Option<String>,
+    /// 客户自定义订单ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cl_ord_id: Option<String>,
+    /// 用户自定义修改事件ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub req_id: Option<String>,
+    /// 修改的新数量
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_sz: Option<NumParam>,
+    /// 修改后的新价格
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_px: Option<NumParam>,
+    /// 当订单修改失败时，该订单是否需要自动撤销，默认为false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cxl_on_fail: Option<bool>,
+    /// 以USD价格进行期权改单，仅适用于期权
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_px_usd: Option<NumParam>,
+    /// 以隐含波动率进行期权改单，例如 1 代表 100%，仅适用于期权
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_px_vol: Option<NumParam>,
+    /// 止盈触发价，填写0时删除止盈
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_tp_trigger_px: Option<NumParam>,
+    /// 止盈委托价，委托价格为-1时，执行市价止盈
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_tp_ord_px: Option<NumParam>,
+    /// 止盈触发价类型，默认为last
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_tp_trigger_px_type: Option<TriggerPxType>,
+    /// 止损触发价，填写0时删除止损
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_sl_trigger_px: Option<NumParam>,
+    /// 止损委托价，委托价格为-1时，执行市价止损
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_sl_ord_px: Option<NumParam>,
+    /// 止损触发价类型，默认为last
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_sl_trigger_px_type: Option<TriggerPxType>,
+    /// 修改附带的止盈止损信息
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attach_algo_ords: Option<Vec<AmendAttachAlgoOrdReqDto>>,
+}
+
+/// 修改订单时附带的止盈止损请求参数结构体
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendAttachAlgoOrdReqDto {
+    /// 附带止盈止损的订单ID，attachAlgoId 和 attachAlgoClOrdId 必须传一个
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attach_algo_id: Option<String>,
+    /// 下单附带止盈止损时，客户自定义的策略订单ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attach_algo_cl_ord_id: Option<String>,
+    /// 止盈触发价，填写0时删除止盈
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_tp_trigger_px: Option<NumParam>,
+    /// 止盈委托价，委托价格为-1时，执行市价止盈
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_tp_ord_px: Option<NumParam>,
+    /// 止盈订单类型
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_tp_ord_kind: Option<TpOrdKind>,
+    /// 止盈触发价类型，默认为last
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_tp_trigger_px_type: Option<TriggerPxType>,
+    /// 止损触发价，填写0时删除止损
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_sl_trigger_px: Option<NumParam>,
+    /// 止损委托价，委托价格为-1时，执行市价止损
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub new_sl_ord_px: Option<NumParam>,
+    /// 止损触发价类型，默认为last
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_sl_trigger_px_type: Option<TriggerPxType>,
+    /// 新的止盈数量，仅适用于“多笔止盈”的止盈订单
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num_param"
+    )]
+    pub sz: Option<NumParam>,
+    /// 是否启用开仓价止损，仅适用于分批止盈的止损订单
+    /// 0：不开启，1：开启
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amend_px_on_trigger_type: Option<String>,
+}
+
 ///策略订单响应结构体
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -238,13 +375,13 @@ pub struct AttachAlgoOrds {
     /// condition: 条件单
     /// limit: 限价单
     /// 默认为condition
-    pub tp_ord_kind: Option<String>,
+    pub tp_ord_kind: Option<TpOrdKind>,
     /// 止盈触发价类型
     /// last：最新价格
     /// index：指数价格
     /// mark：标记价格
     /// 默认为last
-    pub tp_trigger_px_type: Option<String>,
+    pub tp_trigger_px_type: Option<TriggerPxType>,
 
     /// 止损触发价，如果填写此参数，必须填写 止损委托价
     pub sl_trigger_px: Option<String>,
@@ -256,7 +393,7 @@ pub struct AttachAlgoOrds {
     /// index：指数价格
     /// mark：标记价格
     /// 默认为last
-    pub sl_trigger_px_type: Option<String>,
+    pub sl_trigger_px_type: Option<TriggerPxType>,
     /// 数量。仅适用于“多笔止盈”的止盈订单，且对于“多笔止盈”的止盈订单必填
     pub sz: Option<String>,
     /// 是否启用开仓价止损，仅适用于分批止盈的止损订单，第一笔止盈触发时，止损触发价格是否移动到开仓均价止损
@@ -297,17 +434,22 @@ pub struct OrderDetailRespDto {
     /// 订单标签
     pub tag: String,
     /// 委托价格
-    pub px: String,
+    #[serde(with = "num")]
+    pub px: Num,
     /// 期权价格
-    pub px_usd: String,
+    #[serde(with = "num")]
+    pub px_usd: Num,
     /// 期权价格
-    pub px_vol: String,
+    #[serde(with = "num")]
+    pub px_vol: Num,
     /// 期权价格类型
     pub px_type: String,
     /// 委托数量
-    pub sz: String,
+    #[serde(with = "num")]
+    pub sz: Num,
     /// 收益
-    pub pnl: String,
+    #[serde(with = "num")]
+    pub pnl: Num,
     /// 订单类型
     // market：市价单
     // limit：限价单
@@ -328,19 +470,23 @@ pub struct OrderDetailRespDto {
     /// 累计成交数量
     /// 对于币币和杠杆，单位为交易货币，如 BTC-USDT, 单位为 BTC；对于市价单，无论tgtCcy是base_ccy，还是quote_ccy，单位均为交易货币；
     /// 对于交割、永续以及期权，单位为张。  
-    pub acc_fill_sz: String,
+    #[serde(with = "num")]
+    pub acc_fill_sz: Num,
     /// 最新成交价格，如果成交数量为0，该字段为""
-    pub fill_px: String,
+    #[serde(with = "num")]
+    pub fill_px: Num,
     /// 最新成交ID
     pub trade_id: String,
     /// 最新成交数量
     /// 对于币币和杠杆，单位为交易货币，如 BTC-USDT, 单位为 BTC；对于市价单，无论tgtCcy是base_ccy，还是quote_ccy，单位均为交易货币；
     /// 对于交割、永续以及期权，单位为张。
-    pub fill_sz: String,
+    #[serde(with = "num")]
+    pub fill_sz: Num,
     /// 最新成交时间
     pub fill_time: String,
     /// 成交均价，如果成交数量为0，该字段也为""
-    pub avg_px: String,
+    #[serde(with = "num")]
+    pub avg_px: Num,
     /// 订单状态
     /// canceled：撤单成功
     /// live：等待成交
@@ -349,11 +495,13 @@ pub struct OrderDetailRespDto {
     /// mmp_canceled：做市商保护机制导致的自动撤单
     pub state: String,
     /// 杠杆倍数，0.01到125之间的数值，仅适用于 币币杠杆/交割/永续
-    pub lever: String,
+    #[serde(with = "num")]
+    pub lever: Num,
     /// 下单附带止盈止损时，客户自定义的策略订单ID
     pub attach_algo_cl_ord_id: String,
     /// 止盈触发价
-    pub tp_trigger_px: String,
+    #[serde(with = "num")]
+    pub tp_trigger_px: Num,
     /**
      * 止盈触发价类型
      * last：最新价格
@@ -362,16 +510,19 @@ pub struct OrderDetailRespDto {
      */
     pub tp_trigger_px_type: String,
     /// 止盈委托价
-    pub tp_ord_px: String,
+    #[serde(with = "num")]
+    pub tp_ord_px: Num,
     /// 止损触发价
-    pub sl_trigger_px: String,
+    #[serde(with = "num")]
+    pub sl_trigger_px: Num,
     /// 止损触发价类型
     // last：最新价格
     // index：指数价格
     // mark：标记价格
     pub sl_trigger_px_type: String,
     /// 止损委托价
-    pub sl_ord_px: String,
+    #[serde(with = "num")]
+    pub sl_ord_px: Num,
     /// 下单附带止盈止损信息
     pub attach_algo_ords: Vec<AttachAlgoOrds>,
     /// 止损订单信息，仅适用于包含限价止盈单的双向止盈止损订单，触发后生成的普通订单
@@ -387,7 +538,8 @@ pub struct OrderDetailRespDto {
      * 对于币币和杠杆，为订单交易累计的手续费，平台向用户收取的交易手续费，为负数。如： -0.01
      * 对于交割、永续和期权，为订单交易累计的手续费和返佣
      */
-    pub fee: String,
+    #[serde(with = "num")]
+    pub fee: Num,
     /// 返佣金币种
     pub rebate_ccy: String,
     /**
@@ -401,7 +553,8 @@ pub struct OrderDetailRespDto {
     /// 6：计划委托策略触发后的生成的普通单
     pub source: String,
     /// 返佣金额，仅适用于币币和杠杆，平台向达到指定lv交易等级的用户支付的挂单奖励（返佣），如果没有返佣金，该字段为“”。手续费返佣为正数，如：0.01
-    pub rebate: String,
+    #[serde(with = "num")]
+    pub rebate: Num,
     /// 订单种类
     /// normal：普通委托
     /// twap：TWAP自动换币
@@ -437,6 +590,16 @@ pub struct OrderDetailRespDto {
     /// 用于交易的计价币种。
     pub trade_quote_ccy: String,
 }
+
+impl PageItem for OrderDetailRespDto {
+    fn cursor(&self) -> &str {
+        &self.ord_id
+    }
+
+    fn timestamp_ms(&self) -> Option<i64> {
+        self.c_time.parse().ok()
+    }
+}
 /// 未成交订单信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderPendingRespDto {
@@ -447,12 +610,14 @@ pub struct OrderPendingRespDto {
     #[serde(rename = "instId")]
     pub inst_id: String,
     /// 杠杆倍数
-    #[serde(rename = "lever")]
-    pub leverage: String,
+    #[serde(rename = "lever", with = "num")]
+    pub leverage: Num,
     /// 委托价格
-    pub px: String,
+    #[serde(with = "num")]
+    pub px: Num,
     /// 委托数量
-    pub sz: String,
+    #[serde(with = "num")]
+    pub sz: Num,
     /// 订单ID
     #[serde(rename = "ordId")]
     pub order_id: String,
@@ -460,11 +625,21 @@ pub struct OrderPendingRespDto {
     #[serde(rename = "clOrdId", skip_serializing_if = "Option::is_none")]
     pub client_order_id: Option<String>,
     /// 成交数量
-    #[serde(rename = "fillSz", skip_serializing_if = "Option::is_none")]
-    pub filled_size: Option<String>,
+    #[serde(
+        rename = "fillSz",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num"
+    )]
+    pub filled_size: OptNum,
     /// 最新成交价格
-    #[serde(rename = "fillPx", skip_serializing_if = "Option::is_none")]
-    pub filled_price: Option<String>,
+    #[serde(
+        rename = "fillPx",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num"
+    )]
+    pub filled_price: OptNum,
     /// 成交时间
     #[serde(rename = "fillTime", skip_serializing_if = "Option::is_none")]
     pub filled_time: Option<String>,
@@ -496,27 +671,34 @@ pub struct PositionRespDto {
     #[serde(rename = "instId")]
     pub inst_id: String,
     /// 杠杆倍数
-    #[serde(rename = "lever")]
-    pub leverage: String,
+    #[serde(rename = "lever", with = "num")]
+    pub leverage: Num,
     /// 持仓数量
-    pub pos: String,
+    #[serde(with = "num")]
+    pub pos: Num,
     /// 持仓方向
     #[serde(rename = "posSide")]
     pub position_side: PositionSide,
     /// 开仓平均价
-    #[serde(rename = "avgPx")]
-    pub average_price: String,
+    #[serde(rename = "avgPx", with = "num")]
+    pub average_price: Num,
     /// 未实现收益
-    pub upl: String,
+    #[serde(with = "num")]
+    pub upl: Num,
     /// 仓位占用保证金
-    #[serde(rename = "margin")]
-    pub margin: String,
+    #[serde(rename = "margin", with = "num")]
+    pub margin: Num,
     /// 杠杆模式
     #[serde(rename = "mgnMode")]
     pub margin_mode: MarginMode,
     /// 预估强平价
-    #[serde(rename = "liqPx", skip_serializing_if = "Option::is_none")]
-    pub liquidation_price: Option<String>,
+    #[serde(
+        rename = "liqPx",
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "opt_num"
+    )]
+    pub liquidation_price: OptNum,
 }
 
 /// 获取持仓信息请求DTO
@@ -524,7 +706,7 @@ pub struct PositionRespDto {
 pub struct GetPositionReqDto {
     /// 产品类型
     #[serde(rename = "instType", skip_serializing_if = "Option::is_none")]
-    pub inst_type: Option<String>,
+    pub inst_type: Option<InstrumentType>,
     /// 产品ID
     #[serde(rename = "instId", skip_serializing_if = "Option::is_none")]
     pub inst_id: Option<String>,
@@ -544,10 +726,10 @@ pub struct SetLeverageReqDto {
     pub leverage: String,
     /// 保证金模式 cross: 全仓, isolated: 逐仓
     #[serde(rename = "mgnMode")]
-    pub margin_mode: String,
+    pub margin_mode: MarginMode,
     /// 持仓方向，仅适用于币币杠杆逐仓和交割/永续逐仓
     #[serde(rename = "posSide", skip_serializing_if = "Option::is_none")]
-    pub position_side: Option<String>,
+    pub position_side: Option<PositionSide>,
 }
 
 /// 设置杠杆倍数响应DTO
@@ -590,23 +772,6 @@ pub struct FeeRate {
     pub taker_fee_rate: String,
 }
 
-// 止盈订单类型
-// 默认为condition
-pub enum TpOrdKindEnum {
-    // : 条件单
-    CONDITION,
-    // : 限价单
-    LIMIT,
-}
-impl EnumToStrTrait for TpOrdKindEnum {
-    fn as_str(&self) -> &'static str {
-        match self {
-            TpOrdKindEnum::CONDITION => "condition",
-            TpOrdKindEnum::LIMIT => "limit",
-        }
-    }
-}
-
 /// 订单响应数据
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -625,6 +790,47 @@ pub struct OrderResDto {
     pub s_msg: Option<String>,
 }
 
+impl BatchItem for OrderResDto {
+    fn s_code(&self) -> &str {
+        &self.s_code
+    }
+
+    fn s_msg(&self) -> &str {
+        self.s_msg.as_deref().unwrap_or_default()
+    }
+}
+
+/// 修改订单响应数据
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendOrderResDto {
+    /// 订单ID
+    pub ord_id: String,
+    /// 客户自定义订单ID
+    #[serde(default)]
+    pub cl_ord_id: Option<String>,
+    /// 用户自定义修改事件ID
+    #[serde(default)]
+    pub req_id: Option<String>,
+    /// 系统完成订单请求处理的时间戳，Unix时间戳的毫秒数格式
+    pub ts: String,
+    /// 事件执行结果的code，0代表成功
+    pub s_code: String,
+    /// 事件执行失败或成功时的msg
+    #[serde(default)]
+    pub s_msg: Option<String>,
+}
+
+impl BatchItem for AmendOrderResDto {
+    fn s_code(&self) -> &str {
+        &self.s_code
+    }
+
+    fn s_msg(&self) -> &str {
+        self.s_msg.as_deref().unwrap_or_default()
+    }
+}
+
 /// 市价平仓请求参数结构体
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -634,10 +840,10 @@ pub struct CloseOrderReqDto {
     /// 持仓方向（可选）
     /// 买卖模式下：可不填写此参数，默认值net，如果填写，仅可以填写net
     /// 开平仓模式下：必须填写此参数，且仅可以填写 long：平多，short：平空
-    pub pos_side: Option<String>,
+    pub pos_side: Option<PositionSide>,
     /// 保证金模式
     /// cross：全仓；isolated：逐仓
-    pub mgn_mode: String,
+    pub mgn_mode: MarginMode,
     /// 保证金币种（可选）
     /// 单币种保证金模式的全仓币币杠杆平仓必填
     pub ccy: Option<String>,
@@ -670,6 +876,74 @@ pub struct CloseOrderResDto {
     pub tag: Option<String>,
 }
 
+/// 撤销MMP订单请求参数结构体
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MassCancelReqDto {
+    /// 产品类型，目前仅支持期权 OPTION
+    pub inst_type: InstrumentType,
+    /// 交易品种，如 BTC-USD
+    pub inst_family: String,
+    /// 锁定时长，单位为毫秒，范围为 [0, 10000]，默认为0
+    /// 锁定期内无法下MMP订单
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_interval: Option<String>,
+}
+
+impl MassCancelReqDto {
+    /// 撤销指定交易品种的全部期权MMP挂单
+    pub fn option(inst_family: impl Into<String>) -> Self {
+        Self {
+            inst_type: InstrumentType::Option,
+            inst_family: inst_family.into(),
+            lock_interval: None,
+        }
+    }
+}
+
+/// 撤销MMP订单响应数据
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MassCancelResDto {
+    /// 请求结果
+    pub result: bool,
+}
+
+/// 倒计时全部撤单请求参数结构体
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllAfterReqDto {
+    /// 取消挂单的倒计时，单位为秒
+    /// 取值范围为 0, [10, 120]，0 代表不使用该功能
+    pub time_out: String,
+    /// CAA订单标签，仅撤销带有该标签的订单
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
+impl CancelAllAfterReqDto {
+    /// 设置倒计时，`timeout_secs` 为0时关闭倒计时全部撤单
+    pub fn new(timeout_secs: u64) -> Self {
+        Self {
+            time_out: timeout_secs.to_string(),
+            tag: None,
+        }
+    }
+}
+
+/// 倒计时全部撤单响应数据
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllAfterResDto {
+    /// 触发撤单的时间，Unix时间戳的毫秒数格式，triggerTime=0 代表取消该功能
+    pub trigger_time: String,
+    /// CAA订单标签
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// 请求被接收到的时间，Unix时间戳的毫秒数格式
+    pub ts: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -677,15 +951,15 @@ mod tests {
     fn sample_order() -> OrderReqDto {
         OrderReqDto {
             inst_id: "BTC-USDT".to_string(),
-            td_mode: "cash".to_string(),
+            td_mode: TdMode::Cash,
             ccy: None,
             cl_ord_id: None,
             tag: None,
-            side: "buy".to_string(),
+            side: Side::Buy,
             pos_side: None,
-            ord_type: "limit".to_string(),
-            sz: "0.001".to_string(),
-            px: Some("20000".to_string()),
+            ord_type: OrderType::Limit,
+            sz: "0.001".parse().unwrap(),
+            px: Some("20000".parse().unwrap()),
             px_usd: None,
             px_vol: None,
             reduce_only: None,
@@ -726,39 +1000,95 @@ mod tests {
     }
 }
 
-pub enum OrdTypeEnum {
-    /// 限价单
-    LIMIT,
-    /// 市价单
-    MARKET,
-    /// 只做make单
-    PostOnly,
-    /// 全部成交或立即取消
-    FOK,
-    /// 立即成交并取消全部
-    Ioc,
-    // 市价委托立即成交并取消剩余（仅适用交割、永续）
-    OptimalLimitIoc,
-}
-impl EnumToStrTrait for OrdTypeEnum {
-    fn as_str(&self) -> &'static str {
-        match self {
-            OrdTypeEnum::LIMIT => "limit",
-            OrdTypeEnum::MARKET => "market",
-            OrdTypeEnum::PostOnly => "post_only",
-            OrdTypeEnum::FOK => "fok",
-            OrdTypeEnum::Ioc => "ioc",
-            OrdTypeEnum::OptimalLimitIoc => "optimal_limit_ioc",
-        }
-    }
-}
-
+/// 历史订单查询参数
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct OrdListReqDto {
-    pub inst_type: String,
+    pub inst_type: InstrumentType,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub inst_id: Option<String>,
-    pub ord_type: Option<String>,
-    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ord_type: Option<OrderType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<OrderState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub after: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
 }
+
+/// 成交明细
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FillRespDto {
+    /// 产品类型
+    pub inst_type: String,
+    /// 产品ID
+    pub inst_id: String,
+    /// 最新成交ID
+    pub trade_id: String,
+    /// 订单ID
+    pub ord_id: String,
+    /// 客户自定义订单ID
+    pub cl_ord_id: String,
+    /// 账单ID
+    pub bill_id: String,
+    /// 成交明细类型
+    pub sub_type: String,
+    /// 订单标签
+    pub tag: String,
+    /// 最新成交价格
+    #[serde(with = "num")]
+    pub fill_px: Num,
+    /// 最新成交数量
+    #[serde(with = "num")]
+    pub fill_sz: Num,
+    /// 交易执行时的指数价格
+    #[serde(with = "num")]
+    pub fill_idx_px: Num,
+    /// 最新成交收益
+    #[serde(with = "num")]
+    pub fill_pnl: Num,
+    /// 成交时的隐含波动率，仅适用于期权
+    #[serde(with = "num")]
+    pub fill_px_vol: Num,
+    /// 成交时的期权价格，以USD为单位，仅适用于期权
+    #[serde(with = "num")]
+    pub fill_px_usd: Num,
+    /// 成交时的标记波动率，仅适用于期权
+    #[serde(with = "num")]
+    pub fill_mark_vol: Num,
+    /// 成交时的远期价格，仅适用于期权
+    #[serde(with = "num")]
+    pub fill_fwd_px: Num,
+    /// 成交时的标记价格
+    #[serde(with = "num")]
+    pub fill_mark_px: Num,
+    /// 订单方向 buy / sell
+    pub side: String,
+    /// 持仓方向 long / short / net
+    pub pos_side: String,
+    /// 流动性方向 T：taker，M：maker
+    pub exec_type: String,
+    /// 手续费币种
+    pub fee_ccy: String,
+    /// 手续费，负数代表平台扣除的手续费，正数代表返佣
+    #[serde(with = "num")]
+    pub fee: Num,
+    /// 成交明细产生时间，Unix时间戳的毫秒数格式
+    pub ts: String,
+    /// 成交时间
+    pub fill_time: String,
+}
+
+impl PageItem for FillRespDto {
+    fn cursor(&self) -> &str {
+        &self.bill_id
+    }
+
+    fn timestamp_ms(&self) -> Option<i64> {
+        self.ts.parse().ok()
+    }
+}
@@ -20,3 +20,103 @@ impl AccountType {
         }
     }
 }
+
+okx_enum! {
+    /// 持仓方式
+    pub enum PositionMode {
+        /// 开平仓模式
+        LongShort => "long_short_mode",
+        /// 买卖模式
+        Net => "net_mode",
+    }
+}
+
+okx_enum! {
+    /// 账单类型
+    pub enum BillType {
+        /// 划转
+        Transfer => "1",
+        /// 交易
+        Trade => "2",
+        /// 交割
+        Delivery => "3",
+        /// 自动换币
+        ForcedRepayment => "4",
+        /// 强平
+        Liquidation => "5",
+        /// 保证金划转
+        MarginTransfer => "6",
+        /// 扣息
+        Interest => "7",
+        /// 资金费
+        FundingFee => "8",
+        /// 自动减仓
+        Adl => "9",
+        /// 穿仓补偿
+        Clawback => "10",
+        /// 系统换币
+        SystemTokenConversion => "11",
+        /// 策略划拨
+        StrategyTransfer => "12",
+        /// 对冲减仓
+        Ddh => "13",
+        /// 大宗交易
+        BlockTrade => "14",
+        /// 一键借币
+        QuickMargin => "15",
+        /// 借币
+        Borrowing => "16",
+        /// 还币
+        Repay => "22",
+        /// 价差交易
+        SpreadTrading => "24",
+        /// 结构化产品
+        StructuredProducts => "26",
+        /// 闪兑
+        Convert => "27",
+        /// 小额兑换
+        EasyConvert => "28",
+        /// 一键还债
+        OneClickRepay => "29",
+        /// 简单交易
+        SimpleTrade => "30",
+        /// 移仓
+        MovePosition => "32",
+        /// 借贷
+        Loans => "33",
+        /// 结算
+        Settlement => "34",
+        /// 带单分润支出
+        ProfitSharingExpenses => "250",
+        /// 带单分润退还
+        ProfitSharingRefund => "251",
+    }
+}
+
+okx_enum! {
+    /// 资金划转类型
+    pub enum TransferType {
+        /// 账户内划转
+        WithinAccount => "0",
+        /// 母账户转子账户（仅适用于母账户API Key）
+        MasterToSub => "1",
+        /// 子账户转母账户（仅适用于母账户API Key）
+        SubToMasterByMaster => "2",
+        /// 子账户转母账户（仅适用于子账户API Key）
+        SubToMasterBySub => "3",
+        /// 子账户转子账户（仅适用于子账户API Key）
+        SubToSub => "4",
+    }
+}
+
+okx_enum! {
+    /// 提币方式
+    #[derive(Default)]
+    pub enum WithdrawalDest {
+        /// 内部转账
+        Internal => "3",
+        /// 链上提币
+        #[default]
+        OnChain => "4",
+    }
+}
@@ -0,0 +1,194 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fmt;
+use std::str::FromStr;
+
+/// OKX交易环境
+///
+/// 每个环境对应一组REST和WebSocket地址。API Key只能在创建它的环境中使用，
+/// 客户端会拒绝把某个环境的凭证发送到另一个环境。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Environment {
+    /// 实盘
+    #[default]
+    #[serde(alias = "prod", alias = "live")]
+    Production,
+    /// 模拟盘，REST请求带 `x-simulated-trading: 1`，WebSocket使用 `wspap` 域名
+    #[serde(alias = "simulated", alias = "paper")]
+    Demo,
+    /// AWS实盘
+    Aws,
+    /// 美国站实盘
+    Us,
+}
+
+impl Environment {
+    /// 所有环境
+    pub const ALL: [Environment; 4] = [
+        Environment::Production,
+        Environment::Demo,
+        Environment::Aws,
+        Environment::Us,
+    ];
+
+    /// 环境名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Environment::Production => "production",
+            Environment::Demo => "demo",
+            Environment::Aws => "aws",
+            Environment::Us => "us",
+        }
+    }
+
+    /// 是否为模拟盘
+    pub fn is_demo(&self) -> bool {
+        matches!(self, Environment::Demo)
+    }
+
+    /// REST API基础URL
+    pub fn rest_url(&self) -> &'static str {
+        match self {
+            Environment::Production | Environment::Demo => "https://www.okx.com",
+            Environment::Aws => "https://aws.okx.com",
+            Environment::Us => "https://us.okx.com",
+        }
+    }
+
+    /// 公共频道WebSocket URL
+    pub fn public_websocket_url(&self) -> &'static str {
+        match self {
+            // ws.okx.com:8443 在部分网络下不可用，443 通常可以连通
+            Environment::Production => "wss://ws.okx.com/ws/v5/public",
+            Environment::Demo => "wss://wspap.okx.com:8443/ws/v5/public",
+            Environment::Aws => "wss://wsaws.okx.com:8443/ws/v5/public",
+            Environment::Us => "wss://wsus.okx.com:8443/ws/v5/public",
+        }
+    }
+
+    /// 私有频道WebSocket URL
+    pub fn private_websocket_url(&self) -> &'static str {
+        match self {
+            Environment::Production => "wss://ws.okx.com/ws/v5/private",
+            Environment::Demo => "wss://wspap.okx.com:8443/ws/v5/private",
+            Environment::Aws => "wss://wsaws.okx.com:8443/ws/v5/private",
+            Environment::Us => "wss://wsus.okx.com:8443/ws/v5/private",
+        }
+    }
+
+    /// 业务频道WebSocket URL
+    pub fn business_websocket_url(&self) -> &'static str {
+        match self {
+            Environment::Production => "wss://ws.okx.com/ws/v5/business",
+            Environment::Demo => "wss://wspap.okx.com:8443/ws/v5/business",
+            Environment::Aws => "wss://wsaws.okx.com:8443/ws/v5/business",
+            Environment::Us => "wss://wsus.okx.com:8443/ws/v5/business",
+        }
+    }
+
+    /// 从环境变量读取环境
+    ///
+    /// 优先读取 `OKX_ENVIRONMENT`（production / demo / aws / us），
+    /// 未设置时兼容 `OKX_SIMULATED_TRADING`（"1" 为模拟盘，"0" 为实盘）。
+    pub fn from_env() -> Result<Self, Error> {
+        crate::config::init_env();
+        Self::from_env_prefix("OKX_")?.ok_or_else(|| {
+            Error::ConfigError("缺少环境变量: OKX_ENVIRONMENT 或 OKX_SIMULATED_TRADING".to_string())
+        })
+    }
+
+    /// 读取 `OkxApiTrait::from_env` 使用的环境
+    ///
+    /// `OKX_ENVIRONMENT` / `OKX_SIMULATED_TRADING` 的值无效时返回错误；都未设置时兼容已弃用的
+    /// `APP_ENV`（`prod` 为实盘，其他值为模拟盘），仍未设置时使用模拟盘。
+    pub(crate) fn from_env_or_legacy() -> Result<Self, Error> {
+        crate::config::init_env();
+        if let Some(environment) = Self::from_env_prefix("OKX_")? {
+            return Ok(environment);
+        }
+        match env::var("APP_ENV") {
+            Ok(value) => {
+                log::warn!("APP_ENV 已弃用，请改用 OKX_ENVIRONMENT");
+                Ok(if value == "prod" {
+                    Environment::Production
+                } else {
+                    Environment::Demo
+                })
+            }
+            Err(_) => Ok(Environment::Demo),
+        }
+    }
+
+    /// 从带前缀的环境变量读取环境，读取 `{prefix}ENVIRONMENT` 或 `{prefix}SIMULATED_TRADING`，都未设置时返回 `None`
+    pub(crate) fn from_env_prefix(prefix: &str) -> Result<Option<Self>, Error> {
+        if let Ok(value) = env::var(format!("{}ENVIRONMENT", prefix)) {
+            return value.parse().map(Some);
+        }
+        match env::var(format!("{}SIMULATED_TRADING", prefix)) {
+            Ok(value) => Self::from_simulated_flag(&value).map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// 从OKX的模拟盘标识（"0" / "1"）转换
+    pub fn from_simulated_flag(flag: &str) -> Result<Self, Error> {
+        match flag.trim() {
+            "0" => Ok(Environment::Production),
+            "1" => Ok(Environment::Demo),
+            other => Err(Error::ConfigError(format!("无效的模拟盘标识: {}", other))),
+        }
+    }
+}
+
+impl fmt::Display for Environment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Environment {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "production" | "prod" | "live" => Ok(Environment::Production),
+            "demo" | "simulated" | "paper" => Ok(Environment::Demo),
+            "aws" => Ok(Environment::Aws),
+            "us" => Ok(Environment::Us),
+            other => Err(Error::ConfigError(format!("无效的交易环境: {}", other))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn environments_have_distinct_hosts() {
+        assert_eq!(
+            Environment::Demo.private_websocket_url(),
+            "wss://wspap.okx.com:8443/ws/v5/private"
+        );
+        assert_eq!(Environment::Aws.rest_url(), "https://aws.okx.com");
+        assert!(Environment::Demo.is_demo());
+        assert!(!Environment::Us.is_demo());
+        for environment in Environment::ALL {
+            assert_eq!(
+                environment.as_str().parse::<Environment>().unwrap(),
+                environment
+            );
+        }
+        assert_eq!(
+            "prod".parse::<Environment>().unwrap(),
+            Environment::Production
+        );
+        assert!("staging".parse::<Environment>().is_err());
+        assert_eq!(
+            Environment::from_simulated_flag("1").unwrap(),
+            Environment::Demo
+        );
+    }
+}
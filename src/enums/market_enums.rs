@@ -0,0 +1,83 @@
+okx_enum! {
+    /// 产品类型
+    pub enum InstrumentType {
+        /// 币币
+        Spot => "SPOT",
+        /// 币币杠杆
+        Margin => "MARGIN",
+        /// 永续合约
+        Swap => "SWAP",
+        /// 交割合约
+        Futures => "FUTURES",
+        /// 期权
+        Option => "OPTION",
+    }
+}
+
+okx_enum! {
+    /// 合约类型
+    pub enum ContractType {
+        /// 正向合约
+        Linear => "linear",
+        /// 反向合约
+        Inverse => "inverse",
+    }
+}
+
+okx_enum! {
+    /// K线周期，也用于交易大数据接口的统计周期
+    ///
+    /// 小时及以上的周期默认按香港时间（UTC+8）开盘，带 `Utc` 后缀的按UTC时间开盘。
+    pub enum Bar {
+        /// 1秒，仅适用于历史K线
+        Sec1 => "1s",
+        /// 1分钟
+        Min1 => "1m",
+        /// 3分钟
+        Min3 => "3m",
+        /// 5分钟
+        Min5 => "5m",
+        /// 15分钟
+        Min15 => "15m",
+        /// 30分钟
+        Min30 => "30m",
+        /// 1小时
+        Hour1 => "1H",
+        /// 2小时
+        Hour2 => "2H",
+        /// 4小时
+        Hour4 => "4H",
+        /// 6小时
+        Hour6 => "6H",
+        /// 12小时
+        Hour12 => "12H",
+        /// 1天
+        Day1 => "1D",
+        /// 2天
+        Day2 => "2D",
+        /// 3天
+        Day3 => "3D",
+        /// 1周
+        Week1 => "1W",
+        /// 1个月
+        Month1 => "1M",
+        /// 3个月
+        Month3 => "3M",
+        /// 6小时（UTC）
+        Hour6Utc => "6Hutc",
+        /// 12小时（UTC）
+        Hour12Utc => "12Hutc",
+        /// 1天（UTC）
+        Day1Utc => "1Dutc",
+        /// 2天（UTC）
+        Day2Utc => "2Dutc",
+        /// 3天（UTC）
+        Day3Utc => "3Dutc",
+        /// 1周（UTC）
+        Week1Utc => "1Wutc",
+        /// 1个月（UTC）
+        Month1Utc => "1Mutc",
+        /// 3个月（UTC）
+        Month3Utc => "3Mutc",
+    }
+}
@@ -1,2 +1,103 @@
+/// 定义OKX请求参数枚举
+///
+/// 每个取值序列化为OKX接口使用的字符串，并实现 `as_str`、`EnumToStrTrait`、`Display` 和 `FromStr`。
+macro_rules! okx_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $value:literal,
+            )+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                #[serde(rename = $value)]
+                $variant,
+            )+
+        }
+
+        impl $name {
+            /// 所有取值
+            pub const ALL: &'static [$name] = &[$($name::$variant),+];
+
+            /// OKX接口中使用的字符串
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $value,)+
+                }
+            }
+        }
+
+        impl crate::dto::common::EnumToStrTrait for $name {
+            fn as_str(&self) -> &'static str {
+                $name::as_str(self)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = crate::error::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($value => Ok($name::$variant),)+
+                    _ => Err(crate::error::Error::ParameterError(format!(
+                        "无效的{}: {}",
+                        stringify!($name),
+                        s
+                    ))),
+                }
+            }
+        }
+    };
+}
+
 pub mod account_enums;
+pub mod environment_enums;
 pub mod language_enums;
+pub mod market_enums;
+pub mod trade_enums;
+
+pub use account_enums::{AccountType, BillType, PositionMode, TransferType, WithdrawalDest};
+pub use market_enums::{Bar, ContractType, InstrumentType};
+pub use trade_enums::{
+    AlgoOrderState, AlgoOrderType, MarginMode, OrderState, OrderType, PositionSide,
+    SelfTradePreventionMode, Side, TargetCurrency, TdMode, TpOrdKind, TriggerPxType,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enums_round_trip_okx_strings() {
+        for bar in Bar::ALL {
+            assert_eq!(bar.to_string().parse::<Bar>().unwrap(), *bar);
+        }
+        assert_eq!(Bar::Hour1.as_str(), "1H");
+        assert_eq!(Bar::Month1Utc.as_str(), "1Mutc");
+        assert_eq!(serde_json::to_string(&BillType::Trade).unwrap(), "\"2\"");
+        assert_eq!(
+            serde_json::from_str::<TdMode>("\"spot_isolated\"").unwrap(),
+            TdMode::SpotIsolated
+        );
+        assert_eq!(
+            "last".parse::<TriggerPxType>().unwrap(),
+            TriggerPxType::Last
+        );
+        assert!(matches!(
+            "1h".parse::<Bar>(),
+            Err(crate::error::Error::ParameterError(_))
+        ));
+    }
+}
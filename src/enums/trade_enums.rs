@@ -0,0 +1,167 @@
+okx_enum! {
+    /// 订单方向
+    pub enum Side {
+        /// 买
+        Buy => "buy",
+        /// 卖
+        Sell => "sell",
+    }
+}
+
+okx_enum! {
+    /// 持仓方向
+    pub enum PositionSide {
+        /// 开平仓模式下的多头
+        Long => "long",
+        /// 开平仓模式下的空头
+        Short => "short",
+        /// 买卖模式（交割/永续/期权：pos为正代表开多，pos为负代表开空。币币杠杆时，pos均为正，posCcy为交易货币时，代表开多；posCcy为计价货币时，代表开空。）
+        Net => "net",
+    }
+}
+
+okx_enum! {
+    /// 订单类型
+    pub enum OrderType {
+        /// 市价单
+        Market => "market",
+        /// 限价单
+        Limit => "limit",
+        /// 只做maker单
+        PostOnly => "post_only",
+        /// 全部成交或立即取消
+        FillOrKill => "fok",
+        /// 立即成交并取消剩余
+        ImmediateOrCancel => "ioc",
+        /// 市价委托立即成交并取消剩余（仅适用交割、永续）
+        OptimalLimitIoc => "optimal_limit_ioc",
+        /// 做市商保护（仅适用于组合保证金账户模式下的期权订单）
+        Mmp => "mmp",
+        /// 做市商保护且只做maker单（仅适用于组合保证金账户模式下的期权订单）
+        MmpAndPostOnly => "mmp_and_post_only",
+        /// 期权简选（全部成交或立即取消）
+        OptionFok => "op_fok",
+    }
+}
+
+okx_enum! {
+    /// 订单状态
+    pub enum OrderState {
+        /// 撤单成功
+        Canceled => "canceled",
+        /// 等待成交
+        Live => "live",
+        /// 部分成交
+        PartiallyFilled => "partially_filled",
+        /// 完全成交
+        Filled => "filled",
+        /// 做市商保护机制导致的自动撤单
+        MmpCanceled => "mmp_canceled",
+    }
+}
+
+okx_enum! {
+    /// 保证金模式
+    pub enum MarginMode {
+        /// 逐仓
+        Isolated => "isolated",
+        /// 全仓
+        Cross => "cross",
+    }
+}
+
+okx_enum! {
+    /// 交易模式
+    pub enum TdMode {
+        /// 保证金模式：逐仓
+        Isolated => "isolated",
+        /// 保证金模式：全仓
+        Cross => "cross",
+        /// 非保证金模式：现货
+        Cash => "cash",
+        /// 现货逐仓，仅适用于现货带单
+        SpotIsolated => "spot_isolated",
+    }
+}
+
+okx_enum! {
+    /// 止盈订单类型
+    pub enum TpOrdKind {
+        /// 条件单
+        Condition => "condition",
+        /// 限价单
+        Limit => "limit",
+    }
+}
+
+okx_enum! {
+    /// 止盈止损触发价类型
+    pub enum TriggerPxType {
+        /// 最新价格
+        Last => "last",
+        /// 指数价格
+        Index => "index",
+        /// 标记价格
+        Mark => "mark",
+    }
+}
+
+okx_enum! {
+    /// 自成交保护模式
+    pub enum SelfTradePreventionMode {
+        /// 撤销maker单
+        CancelMaker => "cancel_maker",
+        /// 撤销taker单
+        CancelTaker => "cancel_taker",
+        /// 撤销双方订单，不支持FOK
+        CancelBoth => "cancel_both",
+    }
+}
+
+okx_enum! {
+    /// 币币市价单委托数量 `sz` 的单位
+    pub enum TargetCurrency {
+        /// 交易货币
+        BaseCcy => "base_ccy",
+        /// 计价货币
+        QuoteCcy => "quote_ccy",
+    }
+}
+
+okx_enum! {
+    /// 策略委托类型
+    pub enum AlgoOrderType {
+        /// 单向止盈止损
+        Conditional => "conditional",
+        /// 双向止盈止损
+        Oco => "oco",
+        /// 计划委托
+        Trigger => "trigger",
+        /// 移动止盈止损
+        MoveOrderStop => "move_order_stop",
+        /// 冰山委托
+        Iceberg => "iceberg",
+        /// 时间加权委托
+        Twap => "twap",
+    }
+}
+
+okx_enum! {
+    /// 策略委托订单状态
+    pub enum AlgoOrderState {
+        /// 待生效
+        Live => "live",
+        /// 暂停生效
+        Pause => "pause",
+        /// 部分生效
+        PartiallyEffective => "partially_effective",
+        /// 已生效
+        Effective => "effective",
+        /// 已撤销
+        Canceled => "canceled",
+        /// 委托失败
+        OrderFailed => "order_failed",
+        /// 部分委托失败
+        PartiallyFailed => "partially_failed",
+    }
+}
@@ -46,6 +46,10 @@ pub enum Error {
         code: String,
         message: String,
         smg: String,
+        /// 解析后的顶层错误码
+        api_code: ApiErrorCode,
+        /// 每一项操作的错误码（data中的sCode）
+        item_errors: Vec<OkxItemError>,
     },
 
     /// 连接错误
@@ -77,6 +81,24 @@ pub enum Error {
     Unknown(String),
 }
 
+/// 单项操作的错误信息（对应响应data中的 `sCode` / `sMsg`）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OkxItemError {
+    /// 解析后的错误码
+    pub code: ApiErrorCode,
+    /// 原始错误码
+    pub s_code: String,
+    /// 错误信息
+    pub s_msg: String,
+}
+
+impl OkxItemError {
+    /// 是否为失败项
+    pub fn is_failure(&self) -> bool {
+        self.code.is_failure()
+    }
+}
+
 /// 错误严重程度
 #[derive(Debug, Clone, PartialEq)]
 pub enum ErrorSeverity {
@@ -91,8 +113,100 @@ pub enum ErrorSeverity {
 }
 
 impl Error {
+    /// 构造OKX API错误，自动解析错误码
+    pub fn okx_api_error(
+        code: impl Into<String>,
+        message: impl Into<String>,
+        smg: impl Into<String>,
+    ) -> Self {
+        let code = code.into();
+        Error::OkxApiError {
+            api_code: ApiErrorCode::from_code_str(&code),
+            code,
+            message: message.into(),
+            smg: smg.into(),
+            item_errors: Vec::new(),
+        }
+    }
+
+    /// 根据OKX响应的 `code` / `msg` / `data` 构造错误，解析data中每一项的 `sCode` / `sMsg`
+    pub fn from_okx_response(
+        code: impl Into<String>,
+        message: impl Into<String>,
+        data: &serde_json::Value,
+    ) -> Self {
+        let item_errors: Vec<OkxItemError> = data
+            .as_array()
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| {
+                        let s_code = item.get("sCode")?.as_str()?.to_string();
+                        let s_msg = item
+                            .get("sMsg")
+                            .and_then(|s| s.as_str())
+                            .unwrap_or_default()
+                            .to_string();
+                        Some(OkxItemError {
+                            code: ApiErrorCode::from_code_str(&s_code),
+                            s_code,
+                            s_msg,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let smg = item_errors
+            .iter()
+            .find(|item| item.is_failure())
+            .map(|item| item.s_msg.clone())
+            .unwrap_or_default();
+
+        let code = code.into();
+        Error::OkxApiError {
+            api_code: ApiErrorCode::from_code_str(&code),
+            code,
+            message: message.into(),
+            smg,
+            item_errors,
+        }
+    }
+
+    /// 获取解析后的OKX顶层错误码
+    pub fn api_code(&self) -> Option<ApiErrorCode> {
+        match self {
+            Error::OkxApiError { api_code, .. } => Some(*api_code),
+            _ => None,
+        }
+    }
+
+    /// 获取每一项操作的错误信息
+    pub fn item_errors(&self) -> &[OkxItemError] {
+        match self {
+            Error::OkxApiError { item_errors, .. } => item_errors,
+            _ => &[],
+        }
+    }
+
+    /// 用于分类的错误码：批量操作失败时取第一个失败项的sCode，否则取顶层code
+    fn primary_api_code(&self) -> Option<ApiErrorCode> {
+        let api_code = self.api_code()?;
+        if matches!(
+            api_code,
+            ApiErrorCode::OperationFailed | ApiErrorCode::PartialSuccess
+        ) {
+            if let Some(item) = self.item_errors().iter().find(|item| item.is_failure()) {
+                return Some(item.code);
+            }
+        }
+        Some(api_code)
+    }
+
     /// 获取错误的严重程度
     pub fn severity(&self) -> ErrorSeverity {
+        if let Some(code) = self.primary_api_code() {
+            return code.severity();
+        }
         match self {
             Error::AuthenticationError(_) | Error::ConfigError(_) => ErrorSeverity::Critical,
             Error::NetworkError(_) | Error::ConnectionError(_) => ErrorSeverity::High,
@@ -106,9 +220,23 @@ impl Error {
     /// 判断错误是否可恢复
     pub fn is_recoverable(&self) -> bool {
         match self {
+            Error::OkxApiError {
+                api_code,
+                item_errors,
+                ..
+            } => match api_code {
+                // 部分成功时整体重试会重复执行已成功的项
+                ApiErrorCode::PartialSuccess => false,
+                ApiErrorCode::OperationFailed if !item_errors.is_empty() => item_errors
+                    .iter()
+                    .filter(|item| item.is_failure())
+                    .all(|item| item.code.is_retryable()),
+                code => code.is_retryable(),
+            },
             Error::NetworkError(_) | Error::ConnectionError(_) | Error::TimeoutError(_) => true,
             Error::WebSocketError(_) | Error::SubscriptionError(_) => true,
             Error::RateLimitError(_) => true,
+            Error::HttpError(e) => e.is_timeout() || e.is_connect(),
             Error::AuthenticationError(_) | Error::ConfigError(_) => false,
             _ => false,
         }
@@ -116,6 +244,13 @@ impl Error {
 
     /// 获取建议的重试延迟（秒）
     pub fn retry_delay(&self) -> Option<u64> {
+        if let Some(code) = self.primary_api_code() {
+            return if self.is_recoverable() {
+                code.retry_delay()
+            } else {
+                None
+            };
+        }
         match self {
             Error::NetworkError(_) | Error::ConnectionError(_) => Some(5),
             Error::TimeoutError(_) => Some(3),
@@ -1526,6 +1661,89 @@ impl ApiErrorCode {
         }
     }
 
+    /// 从字符串错误码获取ApiErrorCode枚举，无法解析时返回 `Unknown`
+    pub fn from_code_str(code: &str) -> Self {
+        code.trim()
+            .parse::<u32>()
+            .map(Self::from_code)
+            .unwrap_or(Self::Unknown)
+    }
+
+    /// 根据HTTP状态码推断错误码（响应体中没有OKX错误码时使用）
+    pub fn from_http_status(status: u16) -> Self {
+        match status {
+            429 => Self::TooManyRequests,
+            500..=599 => Self::ServiceUnavailable,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// 是否为限流错误
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(
+            self,
+            Self::TooManyRequests | Self::OperationTooFrequent | Self::OrderRequestRateExceeded
+        )
+    }
+
+    /// 是否可以原样重试
+    pub fn is_retryable(&self) -> bool {
+        self.is_rate_limited()
+            || matches!(
+                self,
+                Self::ServiceUnavailable
+                    | Self::RequestTimeout
+                    | Self::SystemBusy
+                    | Self::SystemError
+                    | Self::RequestTimestampExpired
+            )
+    }
+
+    /// 获取错误码的严重程度
+    pub fn severity(&self) -> ErrorSeverity {
+        match self {
+            Self::Ok | Self::PartialSuccess => ErrorSeverity::Low,
+            Self::UserFrozen
+            | Self::UserMarginFrozen
+            | Self::ApiFrozen
+            | Self::ApiKeyEnvironmentMismatch
+            | Self::MissingOkAccessKey
+            | Self::MissingOkAccessPassphrase
+            | Self::InvalidOkAccessPassphrase
+            | Self::MissingOkAccessSign
+            | Self::MissingOkAccessTimestamp
+            | Self::IpNotInApiKeyBindingIpList
+            | Self::InvalidOkAccessKey
+            | Self::InvalidOkAccessSign
+            | Self::InvalidOkAccessAuthorization
+            | Self::ApiKeyNotFound
+            | Self::ApiKeyPermissionInsufficient
+            | Self::IpAccessDenied
+            | Self::AccountTradingRestricted
+            | Self::AccountRiskControl
+            | Self::AccountPermissionDenied => ErrorSeverity::Critical,
+            Self::OrderFailedAccountBalanceNotEnough
+            | Self::OrderFailedAccountBalanceNotEnoughAndAutoBorrowNotEnabledByImr
+            | Self::OrderAccountFrozen
+            | Self::AccountMarginFrozen
+            | Self::AccountException => ErrorSeverity::High,
+            code if code.is_retryable() => ErrorSeverity::Medium,
+            Self::Unknown => ErrorSeverity::Medium,
+            _ => ErrorSeverity::Low,
+        }
+    }
+
+    /// 获取建议的重试延迟（秒），不可重试时返回 `None`
+    pub fn retry_delay(&self) -> Option<u64> {
+        match self {
+            // OKX限速窗口通常为2秒
+            code if code.is_rate_limited() => Some(2),
+            Self::ServiceUnavailable | Self::SystemError => Some(5),
+            Self::RequestTimeout | Self::SystemBusy | Self::RequestTimestampExpired => Some(1),
+            _ => None,
+        }
+    }
+
     /// 获取错误码对应的数值
     pub fn code(&self) -> u32 {
         *self as u32
@@ -1551,3 +1769,55 @@ impl ApiErrorCode {
 pub fn to_err<E: std::error::Error + Send + Sync + 'static>(err: E) -> Error {
     Error::Unknown(err.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn classifies_top_level_codes() {
+        let rate_limited = Error::okx_api_error("50011", "Too Many Requests", "");
+        assert_eq!(rate_limited.api_code(), Some(ApiErrorCode::TooManyRequests));
+        assert!(rate_limited.is_recoverable());
+        assert_eq!(rate_limited.retry_delay(), Some(2));
+
+        let unavailable = Error::okx_api_error("50001", "Service temporarily unavailable", "");
+        assert!(unavailable.is_recoverable());
+        assert_eq!(unavailable.severity(), ErrorSeverity::Medium);
+
+        let unknown = Error::okx_api_error("not-a-code", "", "");
+        assert_eq!(unknown.api_code(), Some(ApiErrorCode::Unknown));
+        assert!(!unknown.is_recoverable());
+    }
+
+    #[test]
+    fn classifies_by_item_codes() {
+        let data = json!([
+            {"ordId": "", "clOrdId": "a", "sCode": "51008", "sMsg": "Insufficient balance"}
+        ]);
+        let err = Error::from_okx_response("1", "All operations failed", &data);
+
+        assert_eq!(err.api_code(), Some(ApiErrorCode::OperationFailed));
+        assert_eq!(
+            err.item_errors()[0].code,
+            ApiErrorCode::OrderFailedAccountBalanceNotEnough
+        );
+        assert!(!err.is_recoverable());
+        assert_eq!(err.retry_delay(), None);
+        assert_eq!(err.severity(), ErrorSeverity::High);
+        assert!(err.to_string().contains("Insufficient balance"));
+
+        let data = json!([
+            {"sCode": "50011", "sMsg": "Too Many Requests"},
+            {"sCode": "50001", "sMsg": "Service unavailable"}
+        ]);
+        assert!(Error::from_okx_response("1", "", &data).is_recoverable());
+
+        let data = json!([
+            {"sCode": "0", "sMsg": ""},
+            {"sCode": "50011", "sMsg": "Too Many Requests"}
+        ]);
+        assert!(!Error::from_okx_response("2", "", &data).is_recoverable());
+    }
+}
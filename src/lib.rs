@@ -1,24 +1,40 @@
 // OKX SDK - Rust Client Library
 // 提供与OKX交易所API的通信能力
 
+pub mod account_pool;
 pub mod api;
 pub mod client;
+pub mod clock;
 pub mod config;
+pub mod credential_provider;
+pub mod dead_mans_switch;
 pub mod debug_helper;
+pub mod decimal;
 pub mod dto;
 pub mod enums;
 pub mod error;
+pub mod middleware;
+pub mod order_validator;
+pub mod pagination;
+pub mod rate_limiter;
+pub mod retry;
+pub mod secret;
+pub mod transport;
 pub mod utils;
 pub mod websocket;
 
 /// OKX SDK的版本
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Re-export commonly used modules and functions
+pub use account_pool::AccountPool;
 pub use api::{
     account::OkxAccount, asset::OkxAsset, big_data::OkxBigData, market::OkxMarket,
     public_data::OkxPublicData, trade::OkxTrade, websocket::OkxWebsocketApi,
 };
-/// Re-export commonly used modules and functions
-pub use client::OkxClient;
+pub use client::{OkxClient, OkxClientBuilder, OkxPublicClient, ResponseMeta};
+pub use dead_mans_switch::DeadMansSwitch;
+pub use enums::environment_enums::Environment;
 pub use error::Error;
+pub use order_validator::OrderValidator;
 pub use websocket::OkxWebsocketClient;
@@ -0,0 +1,288 @@
+use crate::error::Error;
+use crate::secret::redact_body;
+use crate::transport::HttpRequest;
+use reqwest::header::HeaderMap;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// 响应上下文，供中间件观察
+#[derive(Debug)]
+pub struct ResponseContext<'a> {
+    /// HTTP状态码
+    pub status: u16,
+    /// 响应头
+    pub headers: &'a HeaderMap,
+    /// 原始响应体
+    pub body: &'a str,
+    /// 解析出的OKX响应code，响应体不是OKX格式时为 `None`
+    pub code: Option<&'a str>,
+    /// 从发出请求到收到响应的耗时
+    pub elapsed: Duration,
+}
+
+/// 请求中间件
+///
+/// 按添加顺序调用 `on_request`，按相反顺序调用 `on_response` / `on_error`。
+/// `on_request` 在签名之前调用，对方法、路径、请求体的修改都会参与签名；签名相关的请求头不会暴露给中间件。
+pub trait Middleware: fmt::Debug + Send + Sync {
+    /// 请求发出前调用，可以修改请求；返回错误时请求不会发出
+    fn on_request(&self, _request: &mut HttpRequest) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// 收到响应后调用
+    fn on_response(&self, _request: &HttpRequest, _response: &ResponseContext<'_>) {}
+
+    /// 请求未能得到响应（网络错误等）时调用
+    fn on_error(&self, _request: &HttpRequest, _error: &Error, _elapsed: Duration) {}
+}
+
+/// 日志中间件，记录每个请求的方法、路径、状态码、OKX code和耗时
+#[derive(Debug, Clone)]
+pub struct LoggingMiddleware {
+    level: log::Level,
+    log_body: bool,
+}
+
+impl Default for LoggingMiddleware {
+    fn default() -> Self {
+        Self {
+            level: log::Level::Info,
+            log_body: false,
+        }
+    }
+}
+
+impl LoggingMiddleware {
+    /// 创建日志中间件，默认使用 `Info` 级别且不记录请求体和响应体
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置日志级别
+    pub fn with_level(mut self, level: log::Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// 是否记录请求体和响应体
+    pub fn with_body(mut self, log_body: bool) -> Self {
+        self.log_body = log_body;
+        self
+    }
+}
+
+impl Middleware for LoggingMiddleware {
+    fn on_request(&self, request: &mut HttpRequest) -> Result<(), Error> {
+        if self.log_body && !request.body.is_empty() {
+            log::log!(
+                self.level,
+                "OKX请求 {} {} body={}",
+                request.method,
+                request.path,
+                redact_body(&request.body)
+            );
+        } else {
+            log::log!(self.level, "OKX请求 {} {}", request.method, request.path);
+        }
+        Ok(())
+    }
+
+    fn on_response(&self, request: &HttpRequest, response: &ResponseContext<'_>) {
+        log::log!(
+            self.level,
+            "OKX响应 {} {} status={} code={} 耗时={}ms",
+            request.method,
+            request.path,
+            response.status,
+            response.code.unwrap_or("-"),
+            response.elapsed.as_millis()
+        );
+        if self.log_body {
+            log::log!(self.level, "OKX响应体: {}", redact_body(response.body));
+        }
+    }
+
+    fn on_error(&self, request: &HttpRequest, error: &Error, elapsed: Duration) {
+        log::warn!(
+            "OKX请求失败 {} {} 耗时={}ms: {}",
+            request.method,
+            request.path,
+            elapsed.as_millis(),
+            error
+        );
+    }
+}
+
+/// 单个接口的耗时统计
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LatencyStats {
+    /// 请求次数
+    pub count: u64,
+    /// 失败次数（网络错误或OKX code不为0）
+    pub errors: u64,
+    /// 总耗时
+    pub total: Duration,
+    /// 最短耗时
+    pub min: Duration,
+    /// 最长耗时
+    pub max: Duration,
+}
+
+impl LatencyStats {
+    /// 平均耗时
+    pub fn mean(&self) -> Duration {
+        if self.count == 0 {
+            Duration::ZERO
+        } else {
+            self.total / self.count as u32
+        }
+    }
+
+    fn record(&mut self, elapsed: Duration, is_error: bool) {
+        self.min = if self.count == 0 {
+            elapsed
+        } else {
+            self.min.min(elapsed)
+        };
+        self.max = self.max.max(elapsed);
+        self.total += elapsed;
+        self.count += 1;
+        if is_error {
+            self.errors += 1;
+        }
+    }
+}
+
+/// 耗时统计中间件，按接口路径（不含查询参数）汇总耗时。克隆后共享统计数据。
+#[derive(Debug, Clone, Default)]
+pub struct TimingMiddleware {
+    stats: Arc<Mutex<HashMap<String, LatencyStats>>>,
+}
+
+impl TimingMiddleware {
+    /// 创建耗时统计中间件
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 所有接口的耗时统计
+    pub fn stats(&self) -> HashMap<String, LatencyStats> {
+        self.lock().clone()
+    }
+
+    /// 指定接口的耗时统计
+    pub fn stats_for(&self, path: &str) -> Option<LatencyStats> {
+        self.lock().get(path).cloned()
+    }
+
+    /// 清空统计数据
+    pub fn reset(&self) {
+        self.lock().clear();
+    }
+
+    fn record(&self, request: &HttpRequest, elapsed: Duration, is_error: bool) {
+        let path = request.path.split('?').next().unwrap_or_default();
+        self.lock()
+            .entry(path.to_string())
+            .or_default()
+            .record(elapsed, is_error);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, LatencyStats>> {
+        self.stats.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Middleware for TimingMiddleware {
+    fn on_response(&self, request: &HttpRequest, response: &ResponseContext<'_>) {
+        let is_error = response.status != 200 || response.code.is_some_and(|code| code != "0");
+        self.record(request, response.elapsed, is_error);
+    }
+
+    fn on_error(&self, request: &HttpRequest, _error: &Error, elapsed: Duration) {
+        self.record(request, elapsed, true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::tests::mock_builder;
+    use crate::transport::MockTransport;
+    use reqwest::header::HeaderValue;
+    use reqwest::Method;
+
+    /// 注入请求头并记录调用顺序的测试中间件
+    #[derive(Debug)]
+    struct TagMiddleware {
+        name: &'static str,
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Middleware for TagMiddleware {
+        fn on_request(&self, request: &mut HttpRequest) -> Result<(), Error> {
+            request
+                .headers
+                .insert("x-tag", HeaderValue::from_static(self.name));
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("request:{}", self.name));
+            Ok(())
+        }
+
+        fn on_response(&self, _request: &HttpRequest, response: &ResponseContext<'_>) {
+            self.calls.lock().unwrap().push(format!(
+                "response:{}:{}",
+                self.name,
+                response.code.unwrap_or_default()
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn middlewares_run_in_order_and_can_modify_requests() {
+        let transport = MockTransport::new();
+        transport.on_data(Method::GET, "/api/v5/account/config", serde_json::json!([]));
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let timing = TimingMiddleware::new();
+        let client = mock_builder(&transport)
+            .middleware(TagMiddleware {
+                name: "first",
+                calls: calls.clone(),
+            })
+            .middleware(TagMiddleware {
+                name: "second",
+                calls: calls.clone(),
+            })
+            .middleware(timing.clone())
+            .middleware(LoggingMiddleware::new())
+            .build()
+            .unwrap();
+
+        client
+            .send_get::<Vec<serde_json::Value>, _>("/api/v5/account/config", &())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![
+                "request:first",
+                "request:second",
+                "response:second:0",
+                "response:first:0"
+            ]
+        );
+        let request = transport.last_request().unwrap();
+        assert_eq!(request.header("x-tag"), Some("second"));
+        assert!(request.verify_signature("secret"));
+
+        let stats = timing.stats_for("/api/v5/account/config").unwrap();
+        assert_eq!(stats.count, 1);
+        assert_eq!(stats.errors, 0);
+    }
+}
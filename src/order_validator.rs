@@ -0,0 +1,672 @@
+use crate::api::account::OkxAccount;
+use crate::api::api_trait::{OkxApiTrait, OkxPublicApiTrait};
+use crate::api::public_data::OkxPublicData;
+use crate::client::OkxClient;
+use crate::decimal::{canonical, num_to_string, Num, NumParam};
+use crate::dto::market::market_dto::InstrumentOkxResDto;
+use crate::dto::trade::trade_dto::OrderReqDto;
+use crate::enums::{
+    InstrumentType, OrderType, PositionMode, PositionSide, Side, TargetCurrency, TdMode,
+};
+use crate::error::Error;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+/// 价格或数量不符合精度时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ValidationMode {
+    /// 直接拒绝，不发送订单
+    #[default]
+    Reject,
+    /// 自动取整后发送：买单价格向下、卖单价格向上取整到 `tickSz`，数量向下取整到 `lotSz`
+    Round,
+}
+
+/// 下单前的订单校验
+///
+/// 使用缓存的产品规格（`tickSz`、`lotSz`、`minSz`、`maxLmtSz`、`maxMktSz`、`ctVal`）检查价格和数量，
+/// 并根据账户持仓方式检查 `tdMode` 与 `posSide` 是否匹配，避免订单在OKX端被51000系列错误码拒绝。
+/// 克隆后共享同一份缓存。
+#[derive(Debug, Clone, Default)]
+pub struct OrderValidator {
+    mode: ValidationMode,
+    instruments: Arc<RwLock<HashMap<String, InstrumentSpec>>>,
+    position_mode: Arc<RwLock<Option<PositionMode>>>,
+}
+
+impl OrderValidator {
+    /// 创建校验器，默认拒绝不符合精度的订单
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置价格或数量不符合精度时的处理方式
+    pub fn with_mode(mut self, mode: ValidationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// 处理方式
+    pub fn mode(&self) -> ValidationMode {
+        self.mode
+    }
+
+    /// 缓存产品规格，已有的同名产品会被覆盖
+    pub fn insert_instruments(
+        &self,
+        instruments: impl IntoIterator<Item = InstrumentOkxResDto>,
+    ) -> Result<(), Error> {
+        let specs = instruments
+            .into_iter()
+            .map(|instrument| {
+                InstrumentSpec::from_instrument(&instrument).map(|spec| (instrument.inst_id, spec))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        self.instruments.write().unwrap().extend(specs);
+        Ok(())
+    }
+
+    /// 设置账户持仓方式
+    pub fn set_position_mode(&self, position_mode: PositionMode) {
+        *self.position_mode.write().unwrap() = Some(position_mode);
+    }
+
+    /// 账户持仓方式，未加载时为 `None`，此时不检查交割和永续的 `posSide`
+    pub fn position_mode(&self) -> Option<PositionMode> {
+        *self.position_mode.read().unwrap()
+    }
+
+    /// 是否缓存了产品规格
+    pub fn contains(&self, inst_id: &str) -> bool {
+        self.instruments.read().unwrap().contains_key(inst_id)
+    }
+
+    /// 从 `/api/v5/public/instruments` 加载一类产品的规格，返回加载的产品数量
+    pub async fn load_instruments(
+        &self,
+        client: &OkxClient,
+        inst_type: InstrumentType,
+    ) -> Result<usize, Error> {
+        let instruments = OkxPublicData::new(client.clone())
+            .get_instruments(inst_type, None, None, None)
+            .await?;
+        let count = instruments.len();
+        self.insert_instruments(instruments)?;
+        Ok(count)
+    }
+
+    /// 从 `/api/v5/account/config` 加载账户持仓方式
+    pub async fn load_position_mode(&self, client: &OkxClient) -> Result<PositionMode, Error> {
+        let config = OkxAccount::new(client.clone())
+            .get_config()
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::ParseError("账户配置响应为空".to_string()))?;
+        let position_mode = config.position_mode.parse::<PositionMode>()?;
+        self.set_position_mode(position_mode);
+        Ok(position_mode)
+    }
+
+    /// 校验订单，`Round` 模式下会直接修改订单的价格和数量
+    pub fn validate(&self, order: &mut OrderReqDto) -> Result<(), Error> {
+        self.check(order).map_err(Error::ParameterError)
+    }
+
+    /// 校验批量订单，任意一笔不通过时返回错误
+    pub fn validate_all(&self, orders: &mut [OrderReqDto]) -> Result<(), Error> {
+        for (index, order) in orders.iter_mut().enumerate() {
+            self.check(order)
+                .map_err(|e| Error::ParameterError(format!("第{}笔订单: {}", index + 1, e)))?;
+        }
+        Ok(())
+    }
+
+    /// 按合约面值把交易货币数量换算为合约张数，向下取整到 `lotSz`
+    pub fn contracts(&self, inst_id: &str, quantity: &NumParam) -> Result<NumParam, Error> {
+        let spec = self.spec(inst_id).map_err(Error::ParameterError)?;
+        let contract_value = spec.contract_value.ok_or_else(|| {
+            Error::ParameterError(format!("{} 没有合约面值，不能换算张数", inst_id))
+        })?;
+        let quantity = parse_param(quantity, "数量").map_err(Error::ParameterError)?;
+        let per_lot = contract_value
+            .mul(spec.lot_size)
+            .ok_or_else(|| Error::ParameterError(format!("{} 合约面值溢出", inst_id)))?;
+        let lots = quantity
+            .floor_div(per_lot)
+            .ok_or_else(|| Error::ParameterError(format!("{} 数量溢出", inst_id)))?;
+        let contracts = spec
+            .lot_size
+            .times(lots)
+            .ok_or_else(|| Error::ParameterError(format!("{} 张数溢出", inst_id)))?;
+        to_param(contracts).map_err(Error::ParameterError)
+    }
+
+    fn spec(&self, inst_id: &str) -> Result<InstrumentSpec, String> {
+        self.instruments
+            .read()
+            .unwrap()
+            .get(inst_id)
+            .cloned()
+            .ok_or_else(|| format!("未缓存产品 {} 的规格信息", inst_id))
+    }
+
+    fn check(&self, order: &mut OrderReqDto) -> Result<(), String> {
+        let spec = self.spec(&order.inst_id)?;
+        self.check_trade_mode(order, &spec)?;
+        self.check_price(order, &spec)?;
+        self.check_size(order, &spec)
+    }
+
+    fn check_trade_mode(&self, order: &OrderReqDto, spec: &InstrumentSpec) -> Result<(), String> {
+        let inst_id = &order.inst_id;
+        let is_derivative = matches!(
+            spec.inst_type,
+            InstrumentType::Swap | InstrumentType::Futures | InstrumentType::Option
+        );
+        if is_derivative && matches!(order.td_mode, TdMode::Cash | TdMode::SpotIsolated) {
+            return Err(format!(
+                "{} 为{}产品，交易模式不能为 {}",
+                inst_id, spec.inst_type, order.td_mode
+            ));
+        }
+
+        let has_position_side = matches!(
+            spec.inst_type,
+            InstrumentType::Swap | InstrumentType::Futures
+        );
+        if !has_position_side {
+            return match order.pos_side {
+                None | Some(PositionSide::Net) => Ok(()),
+                Some(pos_side) => Err(format!(
+                    "{} 为{}产品，posSide 不能为 {}",
+                    inst_id, spec.inst_type, pos_side
+                )),
+            };
+        }
+        let is_long_or_short = matches!(
+            order.pos_side,
+            Some(PositionSide::Long | PositionSide::Short)
+        );
+        match self.position_mode() {
+            Some(PositionMode::LongShort) if !is_long_or_short => Err(format!(
+                "{} 在开平仓模式下 posSide 必须为 long 或 short",
+                inst_id
+            )),
+            Some(PositionMode::Net) if is_long_or_short => Err(format!(
+                "{} 在买卖模式下 posSide 只能为 net 或不填",
+                inst_id
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_price(&self, order: &mut OrderReqDto, spec: &InstrumentSpec) -> Result<(), String> {
+        let inst_id = &order.inst_id;
+        if is_market(order.ord_type) {
+            return Ok(());
+        }
+        let Some(px) = &order.px else {
+            if order.px_usd.is_none() && order.px_vol.is_none() {
+                return Err(format!("{} {} 订单缺少价格", inst_id, order.ord_type));
+            }
+            return Ok(());
+        };
+        let price = parse_param(px, "价格")?;
+        if price.units <= 0 {
+            return Err(format!("{} 价格必须大于0: {}", inst_id, price));
+        }
+        if price.is_multiple_of(spec.tick_size) != Some(true) {
+            if self.mode == ValidationMode::Reject {
+                return Err(format!(
+                    "{} 价格 {} 不是下单价格精度 {} 的整数倍",
+                    inst_id, price, spec.tick_size
+                ));
+            }
+            let rounded = price
+                .round_to(spec.tick_size, order.side == Side::Sell)
+                .filter(|rounded| rounded.units > 0)
+                .ok_or_else(|| {
+                    format!("{} 价格 {} 无法取整到 {}", inst_id, price, spec.tick_size)
+                })?;
+            order.px = Some(to_param(rounded)?);
+        }
+        Ok(())
+    }
+
+    fn check_size(&self, order: &mut OrderReqDto, spec: &InstrumentSpec) -> Result<(), String> {
+        let inst_id = &order.inst_id;
+        let mut size = parse_param(&order.sz, "数量")?;
+        if size.units <= 0 {
+            return Err(format!("{} 数量必须大于0: {}", inst_id, size));
+        }
+
+        let is_market = is_market(order.ord_type);
+        let is_spot_like = matches!(
+            spec.inst_type,
+            InstrumentType::Spot | InstrumentType::Margin
+        );
+        if is_spot_like && is_market {
+            // 币币市价买单默认以计价货币数量下单，此时 maxMktSz 与 sz 单位相同，lotSz 和 minSz 不适用
+            let default_ccy = match order.side {
+                Side::Buy => TargetCurrency::QuoteCcy,
+                Side::Sell => TargetCurrency::BaseCcy,
+            };
+            if order.tgt_ccy.unwrap_or(default_ccy) == TargetCurrency::QuoteCcy {
+                return check_max(inst_id, size, spec.max_market_size);
+            }
+        }
+
+        if size.is_multiple_of(spec.lot_size) != Some(true) {
+            if self.mode == ValidationMode::Reject {
+                return Err(format!(
+                    "{} 数量 {} 不是下单数量精度 {} 的整数倍",
+                    inst_id, size, spec.lot_size
+                ));
+            }
+            // minSz 为空时不做最小数量检查，向下取整到0需要单独拒绝
+            size = size
+                .round_to(spec.lot_size, false)
+                .filter(|rounded| rounded.units > 0)
+                .ok_or_else(|| format!("{} 数量 {} 无法取整到 {}", inst_id, size, spec.lot_size))?;
+            order.sz = to_param(size)?;
+        }
+        if size.compare(spec.min_size) == Some(Ordering::Less) {
+            return Err(format!(
+                "{} 数量 {} 小于最小下单数量 {}",
+                inst_id, size, spec.min_size
+            ));
+        }
+        match (is_market, is_spot_like) {
+            (false, _) => check_max(inst_id, size, spec.max_limit_size),
+            (true, false) => check_max(inst_id, size, spec.max_market_size),
+            (true, true) => Ok(()),
+        }
+    }
+}
+
+/// 市价单不使用 `px`
+fn is_market(ord_type: OrderType) -> bool {
+    matches!(ord_type, OrderType::Market | OrderType::OptimalLimitIoc)
+}
+
+fn check_max(inst_id: &str, size: Fixed, max: Option<Fixed>) -> Result<(), String> {
+    match max {
+        Some(max) if size.compare(max) == Some(Ordering::Greater) => Err(format!(
+            "{} 数量 {} 超过单笔最大委托数量 {}",
+            inst_id, size, max
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn parse_param(value: &NumParam, name: &str) -> Result<Fixed, String> {
+    let value = canonical(value);
+    Fixed::parse(&value).ok_or_else(|| format!("无效的{}: {}", name, value))
+}
+
+fn to_param(value: Fixed) -> Result<NumParam, String> {
+    let value = value.to_string();
+    value
+        .parse::<NumParam>()
+        .map_err(|e| format!("无效的数值 {}: {}", value, e))
+}
+
+/// 解析后的产品规格
+#[derive(Debug, Clone)]
+struct InstrumentSpec {
+    inst_type: InstrumentType,
+    tick_size: Fixed,
+    lot_size: Fixed,
+    min_size: Fixed,
+    max_limit_size: Option<Fixed>,
+    max_market_size: Option<Fixed>,
+    contract_value: Option<Fixed>,
+}
+
+impl InstrumentSpec {
+    fn from_instrument(instrument: &InstrumentOkxResDto) -> Result<Self, Error> {
+        let inst_id = &instrument.inst_id;
+        let optional = |value: &Num, name: &str| -> Result<Option<Fixed>, Error> {
+            num_to_string(value)
+                .map(|value| {
+                    Fixed::parse(&value).ok_or_else(|| {
+                        Error::ParseError(format!("产品 {} 的 {} 无效: {}", inst_id, name, value))
+                    })
+                })
+                .transpose()
+        };
+        let step = |value: &Num, name: &str| -> Result<Fixed, Error> {
+            optional(value, name)?
+                .filter(|step| step.units > 0)
+                .ok_or_else(|| Error::ParseError(format!("产品 {} 缺少有效的 {}", inst_id, name)))
+        };
+        Ok(Self {
+            inst_type: instrument.inst_type.parse()?,
+            tick_size: step(&instrument.tick_size, "tickSz")?,
+            lot_size: step(&instrument.lot_size, "lotSz")?,
+            min_size: optional(&instrument.min_size, "minSz")?.unwrap_or(Fixed::ZERO),
+            max_limit_size: optional(&instrument.max_limit_size, "maxLmtSz")?,
+            max_market_size: optional(&instrument.max_market_size, "maxMktSz")?,
+            contract_value: optional(&instrument.contract_value, "ctVal")?
+                .filter(|value| value.units > 0),
+        })
+    }
+}
+
+/// 十进制定点数，`units` 为去掉小数点后的整数，`scale` 为小数位数
+///
+/// 两种数值模式下都按字符串解析，整数倍校验和取整不受浮点误差影响。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Fixed {
+    units: i128,
+    scale: u32,
+}
+
+impl Fixed {
+    const ZERO: Fixed = Fixed { units: 0, scale: 0 };
+
+    fn parse(value: &str) -> Option<Fixed> {
+        let value = value.trim();
+        let (negative, digits) = match value.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, value),
+        };
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        let fraction = fraction.trim_end_matches('0');
+        if integer.is_empty() && fraction.is_empty() {
+            return None;
+        }
+        let mut units: i128 = 0;
+        for byte in integer.bytes().chain(fraction.bytes()) {
+            if !byte.is_ascii_digit() {
+                return None;
+            }
+            units = units
+                .checked_mul(10)?
+                .checked_add(i128::from(byte - b'0'))?;
+        }
+        Some(Fixed {
+            units: if negative { -units } else { units },
+            scale: u32::try_from(fraction.len()).ok()?,
+        })
+    }
+
+    /// 对齐到相同的小数位数
+    fn align(self, other: Fixed) -> Option<(i128, i128, u32)> {
+        let scale = self.scale.max(other.scale);
+        let rescale = |value: Fixed| {
+            10i128
+                .checked_pow(scale - value.scale)
+                .and_then(|factor| value.units.checked_mul(factor))
+        };
+        Some((rescale(self)?, rescale(other)?, scale))
+    }
+
+    fn compare(self, other: Fixed) -> Option<Ordering> {
+        let (a, b, _) = self.align(other)?;
+        Some(a.cmp(&b))
+    }
+
+    fn is_multiple_of(self, step: Fixed) -> Option<bool> {
+        let (value, step, _) = self.align(step)?;
+        Some(value.rem_euclid(step) == 0)
+    }
+
+    /// 取整到 `step` 的整数倍，`up` 为 `true` 时向上取整，否则向下取整
+    fn round_to(self, step: Fixed, up: bool) -> Option<Fixed> {
+        let (value, step_units, scale) = self.align(step)?;
+        let mut count = value.div_euclid(step_units);
+        if up && value.rem_euclid(step_units) != 0 {
+            count += 1;
+        }
+        Some(Fixed {
+            units: count.checked_mul(step_units)?,
+            scale,
+        })
+    }
+
+    /// 向下取整的商
+    fn floor_div(self, divisor: Fixed) -> Option<i128> {
+        let (value, divisor, _) = self.align(divisor)?;
+        (divisor != 0).then(|| value.div_euclid(divisor))
+    }
+
+    fn mul(self, other: Fixed) -> Option<Fixed> {
+        Some(Fixed {
+            units: self.units.checked_mul(other.units)?,
+            scale: self.scale.checked_add(other.scale)?,
+        })
+    }
+
+    fn times(self, count: i128) -> Option<Fixed> {
+        Some(Fixed {
+            units: self.units.checked_mul(count)?,
+            scale: self.scale,
+        })
+    }
+}
+
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.units.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+        let (integer, fraction) = if digits.len() > scale {
+            let (integer, fraction) = digits.split_at(digits.len() - scale);
+            (integer.to_string(), fraction.to_string())
+        } else {
+            (
+                "0".to_string(),
+                format!("{:0>width$}", digits, width = scale),
+            )
+        };
+        let fraction = fraction.trim_end_matches('0');
+        if self.units < 0 {
+            f.write_str("-")?;
+        }
+        f.write_str(&integer)?;
+        if !fraction.is_empty() {
+            write!(f, ".{}", fraction)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instrument(value: serde_json::Value) -> InstrumentOkxResDto {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn validator(mode: ValidationMode) -> OrderValidator {
+        let validator = OrderValidator::new().with_mode(mode);
+        validator
+            .insert_instruments([
+                instrument(serde_json::json!({
+                    "instType": "SPOT", "instId": "BTC-USDT", "tickSz": "0.1", "lotSz": "0.00000001",
+                    "minSz": "0.00001", "maxLmtSz": "9999999999", "maxMktSz": "1000000", "ctVal": "",
+                    "state": "live"
+                })),
+                instrument(serde_json::json!({
+                    "instType": "SWAP", "instId": "BTC-USDT-SWAP", "tickSz": "0.1", "lotSz": "0.01",
+                    "minSz": "0.01", "maxLmtSz": "100000", "maxMktSz": "5000", "ctVal": "0.01",
+                    "state": "live"
+                })),
+            ])
+            .unwrap();
+        validator
+    }
+
+    fn order(inst_id: &str, ord_type: OrderType, sz: &str, px: Option<&str>) -> OrderReqDto {
+        OrderReqDto {
+            inst_id: inst_id.to_string(),
+            td_mode: if inst_id.ends_with("SWAP") {
+                TdMode::Cross
+            } else {
+                TdMode::Cash
+            },
+            ccy: None,
+            cl_ord_id: None,
+            tag: None,
+            side: Side::Buy,
+            pos_side: None,
+            ord_type,
+            sz: sz.parse().unwrap(),
+            px: px.map(|px| px.parse().unwrap()),
+            px_usd: None,
+            px_vol: None,
+            reduce_only: None,
+            tgt_ccy: None,
+            ban_amend: None,
+            quick_mgn_type: None,
+            stp_id: None,
+            stp_mode: None,
+            trade_quote_ccy: None,
+            attach_algo_ords: None,
+        }
+    }
+
+    #[test]
+    fn fixed_point_arithmetic_is_exact() {
+        let value = Fixed::parse("65000.15").unwrap();
+        let tick = Fixed::parse("0.10").unwrap();
+        assert_eq!(value.to_string(), "65000.15");
+        assert_eq!(value.is_multiple_of(tick), Some(false));
+        assert_eq!(value.round_to(tick, false).unwrap().to_string(), "65000.1");
+        assert_eq!(value.round_to(tick, true).unwrap().to_string(), "65000.2");
+        assert_eq!(
+            Fixed::parse("0.00000001").unwrap().to_string(),
+            "0.00000001"
+        );
+        assert!(Fixed::parse("1e-8").is_none());
+        assert!(Fixed::parse("").is_none());
+    }
+
+    #[test]
+    fn rejects_orders_outside_instrument_specs() {
+        let validator = validator(ValidationMode::Reject);
+
+        let mut ok = order("BTC-USDT-SWAP", OrderType::Limit, "1.25", Some("65000.1"));
+        assert!(validator.validate(&mut ok).is_ok());
+
+        let cases = [
+            (
+                order("BTC-USDT-SWAP", OrderType::Limit, "1", Some("65000.15")),
+                "价格精度",
+            ),
+            (
+                order("BTC-USDT-SWAP", OrderType::Limit, "1.255", Some("65000")),
+                "数量精度",
+            ),
+            (
+                order("BTC-USDT-SWAP", OrderType::Limit, "0.001", Some("65000")),
+                "数量精度",
+            ),
+            (
+                order("BTC-USDT", OrderType::Limit, "0.000001", Some("65000")),
+                "最小下单数量",
+            ),
+            (
+                order("BTC-USDT-SWAP", OrderType::Market, "6000", None),
+                "最大委托数量",
+            ),
+            (
+                order("BTC-USDT-SWAP", OrderType::Limit, "1", None),
+                "缺少价格",
+            ),
+            (
+                order("ETH-USDT", OrderType::Limit, "1", Some("3000")),
+                "未缓存产品",
+            ),
+        ];
+        for (mut order, expected) in cases {
+            let err = validator.validate(&mut order).unwrap_err();
+            assert!(err.to_string().contains(expected), "{}: {}", expected, err);
+        }
+
+        // 币币市价买单以计价货币计量，不受 lotSz 和 minSz 限制
+        let mut market_buy = order("BTC-USDT", OrderType::Market, "100.5", None);
+        assert!(validator.validate(&mut market_buy).is_ok());
+    }
+
+    #[test]
+    fn rounds_price_by_side_and_size_down() {
+        let validator = validator(ValidationMode::Round);
+
+        let mut buy = order("BTC-USDT-SWAP", OrderType::Limit, "1.257", Some("65000.15"));
+        validator.validate(&mut buy).unwrap();
+        assert_eq!(canonical(buy.px.as_ref().unwrap()), "65000.1");
+        assert_eq!(canonical(&buy.sz), "1.25");
+
+        let mut sell = order("BTC-USDT-SWAP", OrderType::Limit, "1", Some("65000.15"));
+        sell.side = Side::Sell;
+        validator.validate(&mut sell).unwrap();
+        assert_eq!(canonical(sell.px.as_ref().unwrap()), "65000.2");
+
+        // 取整后低于最小下单数量时仍然拒绝
+        let mut tiny = order("BTC-USDT-SWAP", OrderType::Limit, "0.005", Some("65000"));
+        assert!(validator.validate(&mut tiny).is_err());
+
+        // 没有最小下单数量时，向下取整为0也拒绝
+        validator
+            .insert_instruments([instrument(serde_json::json!({
+                "instType": "SWAP", "instId": "ETH-USDT-SWAP", "tickSz": "0.01", "lotSz": "1",
+                "minSz": "", "maxLmtSz": "", "maxMktSz": "", "ctVal": "0.1", "state": "live"
+            }))])
+            .unwrap();
+        let mut zero = order("ETH-USDT-SWAP", OrderType::Limit, "0.5", Some("3000"));
+        let err = validator.validate(&mut zero).unwrap_err();
+        assert!(err.to_string().contains("无法取整"), "{}", err);
+
+        let mut orders = vec![
+            order("BTC-USDT-SWAP", OrderType::Limit, "1", Some("65000")),
+            order("BTC-USDT-SWAP", OrderType::Limit, "1", None),
+        ];
+        let err = validator.validate_all(&mut orders).unwrap_err();
+        assert!(err.to_string().contains("第2笔订单"), "{}", err);
+    }
+
+    #[test]
+    fn checks_trade_mode_against_position_mode() {
+        let validator = validator(ValidationMode::Reject);
+
+        let mut cash_swap = order("BTC-USDT-SWAP", OrderType::Limit, "1", Some("65000"));
+        cash_swap.td_mode = TdMode::Cash;
+        assert!(validator.validate(&mut cash_swap).is_err());
+
+        let mut spot_long = order("BTC-USDT", OrderType::Limit, "1", Some("65000"));
+        spot_long.pos_side = Some(PositionSide::Long);
+        assert!(validator.validate(&mut spot_long).is_err());
+
+        // 未加载持仓方式时不检查交割和永续的 posSide
+        let mut swap = order("BTC-USDT-SWAP", OrderType::Limit, "1", Some("65000"));
+        assert!(validator.validate(&mut swap).is_ok());
+
+        validator.set_position_mode(PositionMode::LongShort);
+        let err = validator.validate(&mut swap).unwrap_err();
+        assert!(err.to_string().contains("开平仓模式"), "{}", err);
+        swap.pos_side = Some(PositionSide::Long);
+        assert!(validator.validate(&mut swap).is_ok());
+
+        validator.set_position_mode(PositionMode::Net);
+        assert!(validator.validate(&mut swap).is_err());
+        swap.pos_side = Some(PositionSide::Net);
+        assert!(validator.validate(&mut swap).is_ok());
+    }
+
+    #[test]
+    fn converts_base_quantity_to_contracts() {
+        let validator = validator(ValidationMode::Reject);
+        let contracts = validator
+            .contracts("BTC-USDT-SWAP", &"0.123456".parse().unwrap())
+            .unwrap();
+        assert_eq!(canonical(&contracts), "12.34");
+        assert!(validator
+            .contracts("BTC-USDT", &"1".parse().unwrap())
+            .is_err());
+    }
+}
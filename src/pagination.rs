@@ -0,0 +1,254 @@
+use crate::dto::common::PageItem;
+use crate::error::Error;
+use futures::stream::{self, BoxStream, StreamExt};
+use std::future::Future;
+
+/// 自动翻页选项
+///
+/// 按OKX的游标语义从新到旧翻页：每次请求把上一页最后一条记录的游标作为 `after`，
+/// 直到返回空页、遇到早于 `since_ms` 的记录或达到 `max_items` 条为止。
+/// 每一页请求都经过客户端限速器，超出额度时按限速策略排队等待。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PageOptions {
+    /// 起始游标，从早于该游标的记录开始；`None` 表示从最新的记录开始
+    pub after: Option<String>,
+    /// 时间下限（毫秒时间戳），遇到更早的记录时停止
+    pub since_ms: Option<i64>,
+    /// 时间上限（毫秒时间戳），晚于该时间的记录会被跳过
+    pub until_ms: Option<i64>,
+    /// 最多返回的记录条数
+    pub max_items: Option<usize>,
+    /// 每页请求的条数，`None` 使用接口默认值
+    pub page_size: Option<u32>,
+}
+
+impl PageOptions {
+    /// 创建翻页选项，默认从最新记录开始翻到最早的记录
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置起始游标
+    pub fn with_after(mut self, after: impl Into<String>) -> Self {
+        self.after = Some(after.into());
+        self
+    }
+
+    /// 只返回 `[since_ms, until_ms]` 时间范围内的记录
+    pub fn with_time_range(mut self, since_ms: i64, until_ms: i64) -> Self {
+        self.since_ms = Some(since_ms);
+        self.until_ms = Some(until_ms);
+        self
+    }
+
+    /// 设置时间下限
+    pub fn with_since(mut self, since_ms: i64) -> Self {
+        self.since_ms = Some(since_ms);
+        self
+    }
+
+    /// 设置时间上限
+    pub fn with_until(mut self, until_ms: i64) -> Self {
+        self.until_ms = Some(until_ms);
+        self
+    }
+
+    /// 设置最多返回的记录条数
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// 设置每页请求的条数
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// 翻页状态
+struct Pager<F> {
+    fetch: F,
+    options: PageOptions,
+    cursor: Option<String>,
+    returned: usize,
+    done: bool,
+}
+
+/// 按游标自动翻页，`fetch` 接收本页的 `after` 游标和每页条数，返回一页记录
+///
+/// 请求失败时输出错误并结束。
+pub(crate) fn paginate<'a, T, F, Fut>(
+    options: PageOptions,
+    fetch: F,
+) -> BoxStream<'a, Result<T, Error>>
+where
+    T: PageItem + Send + 'a,
+    F: FnMut(Option<String>, Option<u32>) -> Fut + Send + 'a,
+    Fut: Future<Output = Result<Vec<T>, Error>> + Send + 'a,
+{
+    let pager = Pager {
+        fetch,
+        cursor: options.after.clone(),
+        options,
+        returned: 0,
+        done: false,
+    };
+    stream::unfold(pager, |mut pager| async move {
+        if pager.done {
+            return None;
+        }
+        let page = match (pager.fetch)(pager.cursor.clone(), pager.options.page_size).await {
+            Ok(page) => page,
+            Err(e) => {
+                pager.done = true;
+                return Some((vec![Err(e)], pager));
+            }
+        };
+        let next_cursor = page.last().map(|item| item.cursor().to_string());
+        if next_cursor.is_none() || next_cursor == pager.cursor {
+            return None;
+        }
+        if pager
+            .options
+            .page_size
+            .is_some_and(|size| page.len() < size as usize)
+        {
+            // 不足一页说明已经没有更早的记录
+            pager.done = true;
+        }
+        pager.cursor = next_cursor;
+
+        let mut items = Vec::with_capacity(page.len());
+        for item in page {
+            let timestamp = item.timestamp_ms();
+            if let (Some(since), Some(ts)) = (pager.options.since_ms, timestamp) {
+                if ts < since {
+                    pager.done = true;
+                    break;
+                }
+            }
+            if let (Some(until), Some(ts)) = (pager.options.until_ms, timestamp) {
+                if ts > until {
+                    continue;
+                }
+            }
+            if pager
+                .options
+                .max_items
+                .is_some_and(|max| pager.returned >= max)
+            {
+                pager.done = true;
+                break;
+            }
+            pager.returned += 1;
+            items.push(Ok(item));
+        }
+        if pager
+            .options
+            .max_items
+            .is_some_and(|max| pager.returned >= max)
+        {
+            pager.done = true;
+        }
+        Some((items, pager))
+    })
+    .flat_map(stream::iter)
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Record {
+        ts: i64,
+        id: String,
+    }
+
+    fn record(ts: i64) -> Record {
+        Record {
+            ts,
+            id: ts.to_string(),
+        }
+    }
+
+    impl PageItem for Record {
+        fn cursor(&self) -> &str {
+            &self.id
+        }
+
+        fn timestamp_ms(&self) -> Option<i64> {
+            Some(self.ts)
+        }
+    }
+
+    /// 模拟按时间倒序、每页 `size` 条的接口，记录从 100 到 1
+    fn fetcher(
+        calls: Arc<Mutex<Vec<Option<String>>>>,
+    ) -> impl FnMut(Option<String>, Option<u32>) -> futures::future::Ready<Result<Vec<Record>, Error>>
+    {
+        move |after, size| {
+            calls.lock().unwrap().push(after.clone());
+            let start = after.map_or(100, |a| a.parse::<i64>().unwrap() - 1);
+            let size = size.unwrap_or(10) as i64;
+            let page = (0..size)
+                .map(|i| start - i)
+                .filter(|ts| *ts > 0)
+                .map(record)
+                .collect();
+            futures::future::ready(Ok(page))
+        }
+    }
+
+    #[tokio::test]
+    async fn follows_cursors_until_the_time_bound() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let options = PageOptions::new()
+            .with_page_size(10)
+            .with_time_range(75, 95);
+        let records: Vec<Record> = paginate(options, fetcher(calls.clone()))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(records.first(), Some(&record(95)));
+        assert_eq!(records.last(), Some(&record(75)));
+        assert_eq!(records.len(), 21);
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![None, Some("91".to_string()), Some("81".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn stops_at_item_limit_or_last_page() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let options = PageOptions::new().with_after("50").with_max_items(15);
+        let records: Vec<Record> = paginate(options, fetcher(calls.clone()))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(records.len(), 15);
+        assert_eq!(records.last(), Some(&record(35)));
+        assert_eq!(calls.lock().unwrap().len(), 2);
+
+        let all: Vec<Record> = paginate(
+            PageOptions::new().with_page_size(30),
+            fetcher(Arc::default()),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+        assert_eq!(all.len(), 100);
+
+        let mut failing = paginate(PageOptions::new(), |_, _| async {
+            Err::<Vec<Record>, _>(Error::ParameterError("boom".to_string()))
+        });
+        assert!(failing.next().await.unwrap().is_err());
+        assert!(failing.next().await.is_none());
+    }
+}
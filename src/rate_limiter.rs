@@ -0,0 +1,570 @@
+use crate::error::Error;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// 限速规则的作用范围
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateLimitScope {
+    /// 按IP限速，同一进程内所有请求共享额度
+    Ip,
+    /// 按User ID限速，同一API Key共享额度
+    UserId,
+    /// 按User ID + 产品ID限速，每个instId单独计算额度
+    InstrumentId,
+}
+
+/// 单个接口的限速规则，如 `10次/2s`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitRule {
+    /// 时间窗口内允许的请求次数
+    pub capacity: u32,
+    /// 时间窗口
+    pub interval: Duration,
+    /// 限速范围
+    pub scope: RateLimitScope,
+}
+
+impl RateLimitRule {
+    /// 创建限速规则
+    pub fn new(capacity: u32, interval: Duration, scope: RateLimitScope) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            interval,
+            scope,
+        }
+    }
+
+    /// 每2秒 `capacity` 次，OKX文档中最常见的限速形式
+    pub fn per_2s(capacity: u32, scope: RateLimitScope) -> Self {
+        Self::new(capacity, Duration::from_secs(2), scope)
+    }
+
+    /// 每秒 `capacity` 次
+    pub fn per_1s(capacity: u32, scope: RateLimitScope) -> Self {
+        Self::new(capacity, Duration::from_secs(1), scope)
+    }
+
+    /// 每秒补充的令牌数
+    fn refill_per_sec(&self) -> f64 {
+        self.capacity as f64 / self.interval.as_secs_f64().max(f64::EPSILON)
+    }
+}
+
+/// 额度耗尽时的处理策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitStrategy {
+    /// 排队等待直到有可用额度
+    #[default]
+    Wait,
+    /// 直接返回 `Error::RateLimitError`
+    Reject,
+}
+
+/// 清理空闲令牌桶的最短间隔
+const EVICT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 令牌桶
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+    /// 补满额度所需的时间，即规则的时间窗口
+    window: Duration,
+}
+
+impl TokenBucket {
+    fn new(rule: &RateLimitRule) -> Self {
+        Self {
+            tokens: rule.capacity as f64,
+            last_refill: Instant::now(),
+            window: rule.interval,
+        }
+    }
+
+    /// 空闲超过一个时间窗口后额度已补满，与新建的令牌桶等价
+    fn is_idle(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.last_refill) >= self.window
+    }
+
+    /// 尝试扣除令牌，额度不足时返回需要等待的时间
+    fn try_acquire(&mut self, rule: &RateLimitRule, cost: f64) -> Result<(), Duration> {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        let rate = rule.refill_per_sec();
+        self.tokens = (self.tokens + elapsed * rate).min(rule.capacity as f64);
+        self.last_refill = now;
+
+        if self.tokens >= cost {
+            self.tokens -= cost;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((cost - self.tokens) / rate))
+        }
+    }
+}
+
+/// 令牌桶的唯一标识：接口路径 + 限速范围对应的主体
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct BucketKey {
+    path: String,
+    subject: String,
+}
+
+/// 所有令牌桶，定期移除空闲的令牌桶，避免按产品ID或API Key创建的令牌桶无限增长
+#[derive(Debug)]
+struct Buckets {
+    buckets: HashMap<BucketKey, TokenBucket>,
+    last_evict: Instant,
+}
+
+impl Buckets {
+    fn new() -> Self {
+        Self {
+            buckets: HashMap::new(),
+            last_evict: Instant::now(),
+        }
+    }
+
+    /// 距上次清理超过 `EVICT_INTERVAL` 时移除空闲的令牌桶
+    fn evict_idle(&mut self, now: Instant) {
+        if now.saturating_duration_since(self.last_evict) < EVICT_INTERVAL {
+            return;
+        }
+        self.last_evict = now;
+        self.buckets.retain(|_, bucket| !bucket.is_idle(now));
+    }
+}
+
+/// 客户端限速器
+///
+/// 按接口路径匹配限速规则，按IP / User ID / 产品ID划分令牌桶。
+/// 克隆后的实例共享同一份额度，由同一个 `OkxClient` 创建的各个API共用限速状态。
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    rules: Arc<HashMap<String, RateLimitRule>>,
+    strategy: RateLimitStrategy,
+    buckets: Arc<Mutex<Buckets>>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::okx_default()
+    }
+}
+
+impl RateLimiter {
+    /// 创建不包含任何规则的限速器（即不限速）
+    pub fn new() -> Self {
+        Self {
+            rules: Arc::new(HashMap::new()),
+            strategy: RateLimitStrategy::default(),
+            buckets: Arc::new(Mutex::new(Buckets::new())),
+        }
+    }
+
+    /// 使用OKX文档中各接口的限速规则创建限速器
+    pub fn okx_default() -> Self {
+        use RateLimitScope::{InstrumentId, Ip, UserId};
+
+        let rules: &[(&str, RateLimitRule)] = &[
+            // 交易
+            (
+                "/api/v5/trade/order",
+                RateLimitRule::per_2s(60, InstrumentId),
+            ),
+            (
+                "/api/v5/trade/batch-orders",
+                RateLimitRule::per_2s(300, UserId),
+            ),
+            (
+                "/api/v5/trade/cancel-order",
+                RateLimitRule::per_2s(60, InstrumentId),
+            ),
+            (
+                "/api/v5/trade/cancel-batch-orders",
+                RateLimitRule::per_2s(300, UserId),
+            ),
+            (
+                "/api/v5/trade/amend-order",
+                RateLimitRule::per_2s(60, InstrumentId),
+            ),
+            (
+                "/api/v5/trade/amend-batch-orders",
+                RateLimitRule::per_2s(300, UserId),
+            ),
+            (
+                "/api/v5/trade/mass-cancel",
+                RateLimitRule::per_2s(5, UserId),
+            ),
+            (
+                "/api/v5/trade/cancel-all-after",
+                RateLimitRule::per_1s(1, UserId),
+            ),
+            (
+                "/api/v5/trade/close-position",
+                RateLimitRule::per_2s(20, InstrumentId),
+            ),
+            (
+                "/api/v5/trade/order-algo",
+                RateLimitRule::per_2s(20, UserId),
+            ),
+            (
+                "/api/v5/trade/cancel-algos",
+                RateLimitRule::per_2s(20, UserId),
+            ),
+            (
+                "/api/v5/trade/amend-algos",
+                RateLimitRule::per_2s(20, UserId),
+            ),
+            (
+                "/api/v5/trade/orders-algo-pending",
+                RateLimitRule::per_2s(20, UserId),
+            ),
+            (
+                "/api/v5/trade/orders-algo-history",
+                RateLimitRule::per_2s(20, UserId),
+            ),
+            (
+                "/api/v5/trade/orders-pending",
+                RateLimitRule::per_2s(60, UserId),
+            ),
+            (
+                "/api/v5/trade/orders-history",
+                RateLimitRule::per_2s(40, UserId),
+            ),
+            (
+                "/api/v5/trade/orders-history-archive",
+                RateLimitRule::per_2s(20, UserId),
+            ),
+            ("/api/v5/trade/fills", RateLimitRule::per_2s(60, UserId)),
+            (
+                "/api/v5/trade/fills-history",
+                RateLimitRule::per_2s(10, UserId),
+            ),
+            // 账户
+            ("/api/v5/account/balance", RateLimitRule::per_2s(10, UserId)),
+            (
+                "/api/v5/account/positions",
+                RateLimitRule::per_2s(10, UserId),
+            ),
+            (
+                "/api/v5/account/account-risk",
+                RateLimitRule::per_2s(10, UserId),
+            ),
+            ("/api/v5/account/bills", RateLimitRule::per_1s(5, UserId)),
+            (
+                "/api/v5/account/bills-archive",
+                RateLimitRule::per_2s(5, UserId),
+            ),
+            ("/api/v5/account/config", RateLimitRule::per_2s(5, UserId)),
+            (
+                "/api/v5/account/set-position-mode",
+                RateLimitRule::per_2s(5, UserId),
+            ),
+            (
+                "/api/v5/account/set-leverage",
+                RateLimitRule::per_2s(20, UserId),
+            ),
+            (
+                "/api/v5/account/leverage-info",
+                RateLimitRule::per_2s(20, UserId),
+            ),
+            (
+                "/api/v5/account/max-size",
+                RateLimitRule::per_2s(20, UserId),
+            ),
+            (
+                "/api/v5/account/max-avail-size",
+                RateLimitRule::per_2s(20, UserId),
+            ),
+            (
+                "/api/v5/account/trade-fee",
+                RateLimitRule::per_2s(5, UserId),
+            ),
+            // 资金
+            ("/api/v5/asset/currencies", RateLimitRule::per_1s(6, UserId)),
+            ("/api/v5/asset/balances", RateLimitRule::per_1s(6, UserId)),
+            ("/api/v5/asset/transfer", RateLimitRule::per_1s(2, UserId)),
+            (
+                "/api/v5/asset/transfer-state",
+                RateLimitRule::per_1s(10, UserId),
+            ),
+            ("/api/v5/asset/withdrawal", RateLimitRule::per_1s(6, UserId)),
+            (
+                "/api/v5/asset/withdrawal-history",
+                RateLimitRule::per_1s(6, UserId),
+            ),
+            (
+                "/api/v5/asset/deposit-address",
+                RateLimitRule::per_1s(6, UserId),
+            ),
+            (
+                "/api/v5/asset/deposit-history",
+                RateLimitRule::per_1s(6, UserId),
+            ),
+            // 行情
+            ("/api/v5/market/tickers", RateLimitRule::per_2s(20, Ip)),
+            ("/api/v5/market/ticker", RateLimitRule::per_2s(20, Ip)),
+            (
+                "/api/v5/market/index-tickers",
+                RateLimitRule::per_2s(20, Ip),
+            ),
+            ("/api/v5/market/books", RateLimitRule::per_2s(40, Ip)),
+            ("/api/v5/market/candles", RateLimitRule::per_2s(40, Ip)),
+            (
+                "/api/v5/market/history-candles",
+                RateLimitRule::per_2s(20, Ip),
+            ),
+            // 公共数据
+            ("/api/v5/public/instruments", RateLimitRule::per_2s(20, Ip)),
+            ("/api/v5/public/time", RateLimitRule::per_2s(10, Ip)),
+            ("/api/v5/public/funding-rate", RateLimitRule::per_2s(20, Ip)),
+            (
+                "/api/v5/public/funding-rate-history",
+                RateLimitRule::per_2s(10, Ip),
+            ),
+            (
+                "/api/v5/public/economic-calendar",
+                RateLimitRule::new(1, Duration::from_secs(5), Ip),
+            ),
+            ("/api/v5/system/status", RateLimitRule::per_1s(1, Ip)),
+            // 交易大数据
+            (
+                "/api/v5/rubik/stat/trading-data/support-coin",
+                RateLimitRule::per_2s(5, Ip),
+            ),
+            (
+                "/api/v5/rubik/stat/taker-volume",
+                RateLimitRule::per_2s(5, Ip),
+            ),
+            (
+                "/api/v5/rubik/stat/contracts/open-interest-volume",
+                RateLimitRule::per_2s(5, Ip),
+            ),
+            // 公告
+            (
+                "/api/v5/support/announcements",
+                RateLimitRule::per_2s(5, Ip),
+            ),
+        ];
+
+        let mut limiter = Self::new();
+        limiter.rules = Arc::new(
+            rules
+                .iter()
+                .map(|(path, rule)| (path.to_string(), *rule))
+                .collect(),
+        );
+        limiter
+    }
+
+    /// 添加或覆盖某个接口的限速规则
+    pub fn with_rule(mut self, path: impl Into<String>, rule: RateLimitRule) -> Self {
+        Arc::make_mut(&mut self.rules).insert(path.into(), rule);
+        self
+    }
+
+    /// 设置额度耗尽时的处理策略
+    pub fn with_strategy(mut self, strategy: RateLimitStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// 当前的处理策略
+    pub fn strategy(&self) -> RateLimitStrategy {
+        self.strategy
+    }
+
+    /// 获取某个接口的限速规则
+    pub fn rule(&self, path: &str) -> Option<&RateLimitRule> {
+        self.rules.get(strip_query(path))
+    }
+
+    /// 在请求发出前获取额度
+    ///
+    /// - `path`: 请求路径，可包含查询参数
+    /// - `user_id`: 用于User ID范围的标识（通常为API Key）
+    /// - `inst_id`: 用于产品ID范围的标识
+    /// - `cost`: 本次请求消耗的额度，批量接口按订单数计算
+    pub async fn acquire(
+        &self,
+        path: &str,
+        user_id: &str,
+        inst_id: Option<&str>,
+        cost: u32,
+    ) -> Result<(), Error> {
+        let path = strip_query(path);
+        let Some(rule) = self.rules.get(path).copied() else {
+            return Ok(());
+        };
+        let subject = match rule.scope {
+            RateLimitScope::Ip => String::new(),
+            RateLimitScope::UserId => user_id.to_string(),
+            RateLimitScope::InstrumentId => format!("{}:{}", user_id, inst_id.unwrap_or_default()),
+        };
+        let key = BucketKey {
+            path: path.to_string(),
+            subject,
+        };
+        let cost = cost.clamp(1, rule.capacity) as f64;
+
+        loop {
+            let wait = {
+                let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+                buckets.evict_idle(Instant::now());
+                let bucket = buckets
+                    .buckets
+                    .entry(key.clone())
+                    .or_insert_with(|| TokenBucket::new(&rule));
+                match bucket.try_acquire(&rule, cost) {
+                    Ok(()) => return Ok(()),
+                    Err(wait) => wait,
+                }
+            };
+
+            match self.strategy {
+                RateLimitStrategy::Reject => {
+                    return Err(Error::RateLimitError(format!(
+                        "{} 超出客户端限速 {}次/{:?}，需等待 {}ms",
+                        path,
+                        rule.capacity,
+                        rule.interval,
+                        wait.as_millis()
+                    )));
+                }
+                RateLimitStrategy::Wait => {
+                    log::debug!("{} 触发客户端限速，等待 {}ms", path, wait.as_millis());
+                    tokio::time::sleep(wait).await;
+                }
+            }
+        }
+    }
+}
+
+/// 去掉请求路径中的查询参数
+fn strip_query(path: &str) -> &str {
+    path.split_once('?').map_or(path, |(path, _)| path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(rule: RateLimitRule) -> RateLimiter {
+        RateLimiter::new()
+            .with_rule("/api/v5/trade/order", rule)
+            .with_strategy(RateLimitStrategy::Reject)
+    }
+
+    #[tokio::test]
+    async fn rejects_when_bucket_is_empty() {
+        let limiter = limiter(RateLimitRule::per_2s(2, RateLimitScope::UserId));
+
+        for _ in 0..2 {
+            limiter
+                .acquire("/api/v5/trade/order?instId=BTC-USDT", "key", None, 1)
+                .await
+                .unwrap();
+        }
+        let err = limiter
+            .acquire("/api/v5/trade/order", "key", None, 1)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::RateLimitError(_)));
+
+        // 其他User ID不受影响
+        limiter
+            .acquire("/api/v5/trade/order", "other", None, 1)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn instrument_scope_has_separate_buckets() {
+        let limiter = limiter(RateLimitRule::per_2s(1, RateLimitScope::InstrumentId));
+
+        limiter
+            .acquire("/api/v5/trade/order", "key", Some("BTC-USDT"), 1)
+            .await
+            .unwrap();
+        limiter
+            .acquire("/api/v5/trade/order", "key", Some("ETH-USDT"), 1)
+            .await
+            .unwrap();
+        assert!(limiter
+            .acquire("/api/v5/trade/order", "key", Some("BTC-USDT"), 1)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn clones_share_budget() {
+        let limiter = limiter(RateLimitRule::per_2s(1, RateLimitScope::Ip));
+        let cloned = limiter.clone();
+
+        limiter
+            .acquire("/api/v5/trade/order", "", None, 1)
+            .await
+            .unwrap();
+        assert!(cloned
+            .acquire("/api/v5/trade/order", "", None, 1)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn evicts_idle_buckets() {
+        let limiter = RateLimiter::new()
+            .with_rule(
+                "/api/v5/trade/order",
+                RateLimitRule::new(1, Duration::from_millis(50), RateLimitScope::InstrumentId),
+            )
+            .with_rule(
+                "/api/v5/trade/batch-orders",
+                RateLimitRule::new(1, Duration::from_secs(3600), RateLimitScope::UserId),
+            );
+        for inst_id in ["BTC-USDT", "ETH-USDT"] {
+            limiter
+                .acquire("/api/v5/trade/order", "key", Some(inst_id), 1)
+                .await
+                .unwrap();
+        }
+        limiter
+            .acquire("/api/v5/trade/batch-orders", "key", None, 1)
+            .await
+            .unwrap();
+        let count = || limiter.buckets.lock().unwrap().buckets.len();
+        assert_eq!(count(), 3);
+
+        // 未到清理间隔时不清理
+        let now = Instant::now();
+        limiter.buckets.lock().unwrap().evict_idle(now);
+        assert_eq!(count(), 3);
+
+        // 空闲超过时间窗口的令牌桶被移除，仍在补充额度的令牌桶保留
+        limiter
+            .buckets
+            .lock()
+            .unwrap()
+            .evict_idle(now + EVICT_INTERVAL);
+        assert_eq!(count(), 1);
+    }
+
+    #[tokio::test]
+    async fn wait_strategy_queues_until_refilled() {
+        let limiter = RateLimiter::new().with_rule(
+            "/api/v5/trade/order",
+            RateLimitRule::new(1, Duration::from_millis(100), RateLimitScope::Ip),
+        );
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter
+                .acquire("/api/v5/trade/order", "", None, 1)
+                .await
+                .unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(180));
+    }
+}
@@ -0,0 +1,418 @@
+use crate::error::Error;
+use rand::Rng;
+use reqwest::Method;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 默认最大尝试次数（包含首次请求）
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// 默认初始退避时间
+pub const DEFAULT_BASE_DELAY: Duration = Duration::from_millis(200);
+
+/// 默认最大退避时间
+pub const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(5);
+
+/// 带有 `clOrdId` 时允许自动重试的POST接口，OKX按 `clOrdId` 拒绝重复订单，撤单和改单本身幂等
+pub const IDEMPOTENT_POST_PATHS: &[&str] = &[
+    "/api/v5/trade/order",
+    "/api/v5/trade/batch-orders",
+    "/api/v5/trade/cancel-order",
+    "/api/v5/trade/cancel-batch-orders",
+    "/api/v5/trade/amend-order",
+    "/api/v5/trade/amend-batch-orders",
+];
+
+/// 单次重试的信息，用于日志或回调
+#[derive(Debug)]
+pub struct RetryAttempt<'a> {
+    /// 请求方法
+    pub method: &'a Method,
+    /// 请求路径（包含查询参数）
+    pub path: &'a str,
+    /// 已失败的尝试次数，从1开始
+    pub attempt: u32,
+    /// 最大尝试次数
+    pub max_attempts: u32,
+    /// 下次重试前的等待时间
+    pub delay: Duration,
+    /// 本次失败的错误
+    pub error: &'a Error,
+}
+
+/// 重试回调
+pub type RetryCallback = Arc<dyn Fn(&RetryAttempt<'_>) + Send + Sync>;
+
+/// HTTP请求重试策略
+///
+/// 只有 `Error::is_recoverable()` 为真的错误才会重试，退避时间按指数增长并加入随机抖动，
+/// `Error::retry_delay()` 给出的建议值作为下限，整体不超过 `max_delay`。
+/// 默认仅重试GET请求；POST请求只在可证明幂等时重试：接口在 `IDEMPOTENT_POST_PATHS` 中，
+/// 且请求体中每一项都带有 `clOrdId`。提币、划转等资金接口的POST请求不会自动重试。
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// 最大尝试次数（包含首次请求），1表示不重试
+    pub max_attempts: u32,
+    /// 初始退避时间
+    pub base_delay: Duration,
+    /// 最大退避时间
+    pub max_delay: Duration,
+    /// 是否加入随机抖动
+    pub jitter: bool,
+    /// 无条件允许重试的请求方法
+    pub methods: Vec<Method>,
+    /// 是否重试带有 `clOrdId` 的交易类POST请求
+    pub retry_idempotent_post: bool,
+    /// 每次重试前的回调
+    on_retry: Option<RetryCallback>,
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .field("jitter", &self.jitter)
+            .field("methods", &self.methods)
+            .field("retry_idempotent_post", &self.retry_idempotent_post)
+            .field("on_retry", &self.on_retry.is_some())
+            .finish()
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            base_delay: DEFAULT_BASE_DELAY,
+            max_delay: DEFAULT_MAX_DELAY,
+            jitter: true,
+            methods: vec![Method::GET],
+            retry_idempotent_post: true,
+            on_retry: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// 创建默认重试策略
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 不进行任何重试
+    pub fn none() -> Self {
+        Self::default().with_max_attempts(1)
+    }
+
+    /// 设置最大尝试次数
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// 设置初始退避时间
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// 设置最大退避时间
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// 设置是否加入随机抖动
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// 允许某个请求方法无条件重试
+    pub fn with_method(mut self, method: Method) -> Self {
+        if !self.methods.contains(&method) {
+            self.methods.push(method);
+        }
+        self
+    }
+
+    /// 禁止某个请求方法无条件重试
+    pub fn without_method(mut self, method: &Method) -> Self {
+        self.methods.retain(|m| m != method);
+        self
+    }
+
+    /// 设置是否重试带有 `clOrdId` 的交易类POST请求
+    pub fn with_idempotent_post(mut self, enabled: bool) -> Self {
+        self.retry_idempotent_post = enabled;
+        self
+    }
+
+    /// 设置每次重试前的回调
+    pub fn with_on_retry<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&RetryAttempt<'_>) + Send + Sync + 'static,
+    {
+        self.on_retry = Some(Arc::new(callback));
+        self
+    }
+
+    /// 判断请求是否允许重试（与错误类型无关），`path` 可以包含查询参数
+    pub fn is_retryable_request(&self, method: &Method, path: &str, body: &str) -> bool {
+        if self.methods.contains(method) {
+            return true;
+        }
+        let path = path.split('?').next().unwrap_or_default();
+        *method == Method::POST
+            && self.retry_idempotent_post
+            && IDEMPOTENT_POST_PATHS.contains(&path)
+            && is_idempotent_body(body)
+    }
+
+    /// 第 `attempt` 次尝试失败后，返回下次重试前的等待时间；不应重试时返回 `None`
+    pub fn next_delay(
+        &self,
+        method: &Method,
+        path: &str,
+        body: &str,
+        attempt: u32,
+        error: &Error,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts
+            || !error.is_recoverable()
+            || !self.is_retryable_request(method, path, body)
+        {
+            return None;
+        }
+        Some(self.backoff(attempt, error))
+    }
+
+    /// 计算退避时间
+    pub fn backoff(&self, attempt: u32, error: &Error) -> Duration {
+        let exp = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)));
+        let hint = error
+            .retry_delay()
+            .map(Duration::from_secs)
+            .unwrap_or_default();
+        let delay = exp.max(hint).min(self.max_delay);
+        if self.jitter && !delay.is_zero() {
+            // 等量抖动：在 [delay/2, delay] 之间随机
+            let half = delay / 2;
+            half + rand::rng().random_range(Duration::ZERO..=half)
+        } else {
+            delay
+        }
+    }
+
+    /// 记录并回调一次重试
+    pub(crate) fn notify(&self, attempt: &RetryAttempt<'_>) {
+        log::warn!(
+            "OKX API请求失败，{}ms后重试({}/{}): {} {} - {}",
+            attempt.delay.as_millis(),
+            attempt.attempt,
+            attempt.max_attempts,
+            attempt.method,
+            attempt.path,
+            attempt.error
+        );
+        if let Some(callback) = &self.on_retry {
+            callback(attempt);
+        }
+    }
+}
+
+/// 判断POST请求体是否幂等：单个对象或数组中的每一项都带有非空的 `clOrdId`
+fn is_idempotent_body(body: &str) -> bool {
+    fn has_cl_ord_id(value: &serde_json::Value) -> bool {
+        value
+            .get("clOrdId")
+            .and_then(|v| v.as_str())
+            .is_some_and(|s| !s.is_empty())
+    }
+
+    match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(serde_json::Value::Array(items)) => !items.is_empty() && items.iter().all(has_cl_ord_id),
+        Ok(value) => has_cl_ord_id(&value),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::OkxClient;
+    use crate::config::Credentials;
+    use crate::enums::environment_enums::Environment;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn timeout_error() -> Error {
+        Error::TimeoutError("timeout".to_string())
+    }
+
+    #[test]
+    fn get_is_retried_by_default() {
+        let policy = RetryPolicy::default();
+        let path = "/api/v5/account/balance?ccy=BTC";
+        assert!(policy
+            .next_delay(&Method::GET, path, "", 1, &timeout_error())
+            .is_some());
+        assert!(policy
+            .next_delay(
+                &Method::GET,
+                path,
+                "",
+                DEFAULT_MAX_ATTEMPTS,
+                &timeout_error()
+            )
+            .is_none());
+        assert!(policy
+            .next_delay(
+                &Method::GET,
+                path,
+                "",
+                1,
+                &Error::ParameterError("bad".to_string())
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn post_is_retried_only_with_client_ids() {
+        let policy = RetryPolicy::default();
+        let err = timeout_error();
+        let order = "/api/v5/trade/order";
+        let batch = "/api/v5/trade/batch-orders";
+
+        assert!(policy
+            .next_delay(&Method::POST, order, r#"{"instId":"BTC-USDT"}"#, 1, &err)
+            .is_none());
+        assert!(policy
+            .next_delay(
+                &Method::POST,
+                order,
+                r#"{"instId":"BTC-USDT","clOrdId":""}"#,
+                1,
+                &err
+            )
+            .is_none());
+        assert!(policy
+            .next_delay(
+                &Method::POST,
+                order,
+                r#"{"instId":"BTC-USDT","clOrdId":"a1"}"#,
+                1,
+                &err
+            )
+            .is_some());
+        assert!(policy
+            .next_delay(
+                &Method::POST,
+                batch,
+                r#"[{"clOrdId":"a1"},{"ordId":"2"}]"#,
+                1,
+                &err
+            )
+            .is_none());
+        assert!(policy
+            .next_delay(
+                &Method::POST,
+                batch,
+                r#"[{"clOrdId":"a1"},{"clOrdId":"a2"}]"#,
+                1,
+                &err
+            )
+            .is_some());
+
+        let opt_in = RetryPolicy::default().with_method(Method::POST);
+        assert!(opt_in
+            .next_delay(&Method::POST, order, r#"{"instId":"BTC-USDT"}"#, 1, &err)
+            .is_some());
+    }
+
+    #[test]
+    fn funding_posts_are_not_retried() {
+        let policy = RetryPolicy::default();
+        let err = timeout_error();
+
+        // OKX不按 clientId 去重，超时后重发可能导致重复提币或划转
+        assert!(policy
+            .next_delay(
+                &Method::POST,
+                "/api/v5/asset/withdrawal",
+                r#"{"ccy":"USDT","amt":"10","dest":"4","toAddr":"TXaddr","clientId":"w1"}"#,
+                1,
+                &err
+            )
+            .is_none());
+        assert!(policy
+            .next_delay(
+                &Method::POST,
+                "/api/v5/asset/transfer",
+                r#"{"ccy":"USDT","amt":"1","from":"6","to":"18","clientId":"t1"}"#,
+                1,
+                &err
+            )
+            .is_none());
+        // 不在白名单中的接口即使带有 clOrdId 也不重试
+        assert!(policy
+            .next_delay(
+                &Method::POST,
+                "/api/v5/trade/close-position",
+                r#"{"instId":"BTC-USDT-SWAP","clOrdId":"c1"}"#,
+                1,
+                &err
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn backoff_grows_and_is_capped() {
+        let policy = RetryPolicy::default()
+            .with_jitter(false)
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(350));
+        let err = Error::Unknown("x".to_string());
+
+        assert_eq!(policy.backoff(1, &err), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2, &err), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3, &err), Duration::from_millis(350));
+
+        let jittered = policy.with_jitter(true).backoff(2, &err);
+        assert!(jittered >= Duration::from_millis(100) && jittered <= Duration::from_millis(200));
+    }
+
+    #[tokio::test]
+    async fn client_reports_each_retry() {
+        let retries = Arc::new(AtomicU32::new(0));
+        let counter = retries.clone();
+        let policy = RetryPolicy::default()
+            .with_base_delay(Duration::from_millis(1))
+            .with_on_retry(move |attempt| {
+                assert_eq!(attempt.max_attempts, DEFAULT_MAX_ATTEMPTS);
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+
+        let mut client = OkxClient::new(Credentials::new(
+            "key",
+            "secret",
+            "passphrase",
+            Environment::Production,
+        ))
+        .expect("client");
+        // 没有服务监听的端口，连接会立即失败
+        client.set_base_url("http://127.0.0.1:1");
+        client.set_retry_policy(policy);
+
+        let result = client
+            .send_request::<serde_json::Value>(Method::GET, "/api/v5/public/time", "")
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(retries.load(Ordering::SeqCst), DEFAULT_MAX_ATTEMPTS - 1);
+    }
+}
@@ -0,0 +1,149 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::fmt;
+
+/// 脱敏后显示的占位符
+pub const REDACTED: &str = "***";
+
+/// 日志中需要脱敏的字段
+pub const SENSITIVE_FIELDS: &[&str] = &[
+    "apiKey",
+    "api_key",
+    "secretKey",
+    "api_secret",
+    "passphrase",
+    "sign",
+    "toAddr",
+    "addr",
+    "memo",
+    "pmtId",
+];
+
+/// 敏感字符串
+///
+/// `Debug` 和 `Display` 只输出 `***`，需要原值时调用 `expose`。序列化时输出原值，用于发送请求。
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SecretString(String);
+
+impl SecretString {
+    /// 创建敏感字符串
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// 获取原值
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", REDACTED)
+    }
+}
+
+impl fmt::Display for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl Serialize for SecretString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self)
+    }
+}
+
+/// 对JSON请求体或响应体中的敏感字段脱敏，用于日志输出；不是JSON时原样返回
+pub fn redact_body(body: &str) -> String {
+    match serde_json::from_str::<Value>(body) {
+        Ok(mut value) => {
+            redact_value(&mut value);
+            value.to_string()
+        }
+        Err(_) => body.to_string(),
+    }
+}
+
+/// 递归地将JSON中敏感字段的值替换为 `***`
+pub fn redact_value(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                if SENSITIVE_FIELDS.contains(&key.as_str()) && !item.is_null() {
+                    *item = Value::String(REDACTED.to_string());
+                } else {
+                    redact_value(item);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_value),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Credentials;
+    use crate::enums::environment_enums::Environment;
+
+    #[test]
+    fn secrets_are_redacted_in_debug_and_display() {
+        let credentials = Credentials::new("key", "my-secret", "my-pass", Environment::Production);
+        let debug = format!("{:?}", credentials);
+        assert!(!debug.contains("my-secret"));
+        assert!(!debug.contains("my-pass"));
+        assert_eq!(credentials.api_secret.to_string(), REDACTED);
+        assert_eq!(credentials.api_secret.expose(), "my-secret");
+        assert_eq!(
+            serde_json::to_string(&credentials.passphrase).unwrap(),
+            "\"my-pass\""
+        );
+    }
+
+    #[test]
+    fn sensitive_fields_are_redacted_in_bodies() {
+        let body =
+            r#"{"op":"login","args":[{"apiKey":"k","sign":"s","passphrase":"p","timestamp":"1"}]}"#;
+        let redacted: Value = serde_json::from_str(&redact_body(body)).unwrap();
+        assert_eq!(
+            redacted,
+            serde_json::json!({"op":"login","args":[{"apiKey":"***","sign":"***","passphrase":"***","timestamp":"1"}]})
+        );
+
+        let withdrawal = redact_body(r#"{"ccy":"USDT","toAddr":"0xabc","amt":"1"}"#);
+        assert!(!withdrawal.contains("0xabc"));
+        assert!(withdrawal.contains("\"amt\":\"1\""));
+
+        // 订单标签不是敏感信息，保留原值便于排查
+        let order = redact_body(r#"{"instId":"BTC-USDT","tag":"bot"}"#);
+        assert!(order.contains("\"tag\":\"bot\""));
+
+        assert_eq!(redact_body("not json"), "not json");
+    }
+}
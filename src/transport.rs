@@ -0,0 +1,311 @@
+use crate::error::Error;
+use crate::utils;
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Client, Method};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// 发往OKX的HTTP请求
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    /// 请求方法
+    pub method: Method,
+    /// 完整URL
+    pub url: String,
+    /// 请求路径（包含查询参数），即参与签名的requestPath
+    pub path: String,
+    /// 请求头
+    pub headers: HeaderMap,
+    /// 请求体
+    pub body: String,
+}
+
+impl HttpRequest {
+    /// 获取请求头的值
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    /// 使用 `api_secret` 校验请求签名是否正确
+    pub fn verify_signature(&self, api_secret: &str) -> bool {
+        let (Some(timestamp), Some(sign)) = (
+            self.header("OK-ACCESS-TIMESTAMP"),
+            self.header("OK-ACCESS-SIGN"),
+        ) else {
+            return false;
+        };
+        utils::generate_signature(api_secret, timestamp, &self.method, &self.path, &self.body)
+            .is_ok_and(|expected| expected == sign)
+    }
+}
+
+/// OKX返回的HTTP响应
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    /// HTTP状态码
+    pub status: u16,
+    /// 响应头
+    pub headers: HeaderMap,
+    /// 响应体
+    pub body: String,
+}
+
+impl HttpResponse {
+    /// 创建响应
+    pub fn new(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+
+    /// 添加响应头，名称或值无效时忽略
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(value),
+        ) {
+            self.headers.insert(name, value);
+        }
+        self
+    }
+}
+
+/// HTTP传输层
+///
+/// `OkxClient` 负责签名、限速、重试和响应解析，传输层只负责把请求发出去。
+/// 默认使用 `ReqwestTransport`，测试时可以使用 `MockTransport`。
+#[async_trait]
+pub trait HttpTransport: fmt::Debug + Send + Sync {
+    /// 发送请求
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error>;
+}
+
+/// 基于reqwest的传输层
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    /// 使用指定的 `reqwest::Client` 创建传输层
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl HttpTransport for ReqwestTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        let response = self
+            .client
+            .request(request.method, &request.url)
+            .headers(request.headers)
+            .body(request.body)
+            .send()
+            .await
+            .map_err(Error::HttpError)?;
+        let status = response.status().as_u16();
+        let headers = response.headers().clone();
+        let body = response.text().await.map_err(Error::HttpError)?;
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    responses: HashMap<(Method, String), VecDeque<HttpResponse>>,
+    requests: Vec<HttpRequest>,
+}
+
+/// 内存中的传输层，用于单元测试
+///
+/// 按请求方法和路径注册预设响应，并记录收到的所有请求。
+/// 注册路径不带查询参数时匹配该路径的所有请求；同一路径注册多个响应时按顺序返回，最后一个会重复使用。
+/// 未注册的请求返回404。克隆后共享同一份状态。
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockTransport {
+    /// 创建内存传输层
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 注册预设响应
+    pub fn on(&self, method: Method, path: impl Into<String>, response: HttpResponse) -> &Self {
+        self.lock()
+            .responses
+            .entry((method, path.into()))
+            .or_default()
+            .push_back(response);
+        self
+    }
+
+    /// 注册成功响应，`data` 会被包装为 `{"code":"0","msg":"","data":...}`
+    pub fn on_data(
+        &self,
+        method: Method,
+        path: impl Into<String>,
+        data: serde_json::Value,
+    ) -> &Self {
+        let body = serde_json::json!({"code": "0", "msg": "", "data": data});
+        self.on(method, path, HttpResponse::new(200, body.to_string()))
+    }
+
+    /// 注册OKX错误响应
+    pub fn on_error(
+        &self,
+        method: Method,
+        path: impl Into<String>,
+        code: &str,
+        msg: &str,
+    ) -> &Self {
+        let body = serde_json::json!({"code": code, "msg": msg, "data": []});
+        self.on(method, path, HttpResponse::new(200, body.to_string()))
+    }
+
+    /// 收到的所有请求
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// 发往指定路径（不含查询参数）的请求
+    pub fn requests_to(&self, path: &str) -> Vec<HttpRequest> {
+        self.lock()
+            .requests
+            .iter()
+            .filter(|r| r.path.split('?').next() == Some(path))
+            .cloned()
+            .collect()
+    }
+
+    /// 最后一个请求
+    pub fn last_request(&self) -> Option<HttpRequest> {
+        self.lock().requests.last().cloned()
+    }
+
+    /// 清空已记录的请求
+    pub fn clear_requests(&self) {
+        self.lock().requests.clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[async_trait]
+impl HttpTransport for MockTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        let mut state = self.lock();
+        let method = request.method.clone();
+        let path_only = request
+            .path
+            .split('?')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        state.requests.push(request.clone());
+
+        let queue = [request.path, path_only]
+            .into_iter()
+            .find_map(|path| {
+                let key = (method.clone(), path);
+                state.responses.contains_key(&key).then_some(key)
+            })
+            .and_then(|key| state.responses.get_mut(&key));
+
+        let response = match queue {
+            Some(queue) if queue.len() > 1 => queue.pop_front(),
+            Some(queue) => queue.front().cloned(),
+            None => None,
+        };
+        Ok(response.unwrap_or_else(|| HttpResponse::new(404, "")))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::api::api_trait::OkxApiTrait;
+    use crate::client::{OkxClient, OkxClientBuilder};
+    use crate::config::Credentials;
+    use crate::enums::environment_enums::Environment;
+    use crate::retry::RetryPolicy;
+    use crate::OkxAccount;
+    use std::time::Duration;
+
+    /// 测试用的客户端构建器：请求发往 `transport`，凭证为 `key` / `secret` / `passphrase`，
+    /// 不同步服务器时间，重试间隔不超过1ms
+    pub(crate) fn mock_builder(transport: &MockTransport) -> OkxClientBuilder {
+        OkxClient::builder()
+            .credentials(Credentials::new(
+                "key",
+                "secret",
+                "passphrase",
+                Environment::Production,
+            ))
+            .transport(transport.clone())
+            .time_sync_interval(None)
+            .retry_policy(RetryPolicy::default().with_max_delay(Duration::from_millis(1)))
+    }
+
+    /// 使用 `mock_builder` 默认设置的测试客户端
+    pub(crate) fn mock_client(transport: &MockTransport) -> OkxClient {
+        mock_builder(transport).build().unwrap()
+    }
+
+    #[tokio::test]
+    async fn api_runs_against_mock_transport() {
+        let transport = MockTransport::new();
+        transport.on_data(
+            Method::GET,
+            "/api/v5/account/balance",
+            serde_json::json!([]),
+        );
+        let account = OkxAccount::new(mock_client(&transport));
+
+        let balances = account.get_balance(Some("BTC")).await.unwrap();
+
+        assert!(balances.is_empty());
+        let request = transport.last_request().unwrap();
+        assert_eq!(request.path, "/api/v5/account/balance?ccy=BTC");
+        assert_eq!(request.header("OK-ACCESS-KEY"), Some("key"));
+        assert!(request.verify_signature("secret"));
+        assert!(!request.verify_signature("other-secret"));
+    }
+
+    #[tokio::test]
+    async fn canned_responses_are_returned_in_order() {
+        let transport = MockTransport::new();
+        transport
+            .on_error(Method::GET, "/api/v5/account/config", "50001", "busy")
+            .on_data(Method::GET, "/api/v5/account/config", serde_json::json!([]));
+        let client = mock_client(&transport);
+
+        // 50001 可重试，第二次请求拿到成功响应
+        let data = client
+            .send_get::<Vec<serde_json::Value>, _>("/api/v5/account/config", &())
+            .await
+            .unwrap();
+
+        assert!(data.is_empty());
+        assert_eq!(transport.requests_to("/api/v5/account/config").len(), 2);
+
+        let err = client
+            .send_get::<Vec<serde_json::Value>, _>("/api/v5/account/unknown", &())
+            .await
+            .unwrap_err();
+        assert_eq!(err.api_code(), Some(crate::error::ApiErrorCode::Unknown));
+    }
+}
@@ -1,8 +1,10 @@
+use crate::clock::SERVER_CLOCK;
 use crate::error::{self, Error};
 use crate::{api, client};
 use base64::{engine::general_purpose, Engine as _};
 use hmac::{Hmac, Mac};
 use reqwest::Method;
+use serde::Serialize;
 use sha2::Sha256;
 
 /// 生成API请求签名
@@ -24,21 +26,36 @@ pub fn generate_signature(
     Ok(signature)
 }
 
-/// 生成当前ISO 8601格式的时间戳
+/// 将查询参数序列化为URL编码的查询字符串
+/// 值为 `None` 的字段会被跳过
+pub fn build_query_string<Q: Serialize + ?Sized>(params: &Q) -> Result<String, Error> {
+    serde_urlencoded::to_string(params)
+        .map_err(|e| Error::ParameterError(format!("序列化查询参数失败: {}", e)))
+}
+
+/// 拼接请求路径与查询参数，返回参与签名的完整请求路径
+pub fn build_request_path<Q: Serialize + ?Sized>(path: &str, params: &Q) -> Result<String, Error> {
+    let query = build_query_string(params)?;
+    if query.is_empty() {
+        Ok(path.to_string())
+    } else {
+        Ok(format!("{}?{}", path, query))
+    }
+}
+
+/// 生成当前ISO 8601格式的时间戳（已补偿服务器时钟偏差）
 pub fn generate_timestamp() -> String {
-    chrono::Utc::now()
-        .format("%Y-%m-%dT%H:%M:%S.%3fZ")
-        .to_string()
+    SERVER_CLOCK.timestamp()
 }
 
-/// 秒级时间戳
+/// 秒级时间戳（已补偿服务器时钟偏差）
 pub fn generate_timestamp_websocket() -> String {
-    chrono::Utc::now().timestamp().to_string()
+    SERVER_CLOCK.timestamp_secs()
 }
 
-/// 生成请求的截止时间戳（毫秒）
+/// 生成请求的截止时间戳（毫秒，已补偿服务器时钟偏差）
 pub fn generate_expiration_timestamp(expiration_ms: i64) -> i64 {
-    chrono::Utc::now().timestamp_millis() + expiration_ms
+    SERVER_CLOCK.now_ms() + expiration_ms
 }
 
 /// 从字符串解析毫秒时间戳
@@ -64,8 +81,9 @@ pub fn timestamp_to_datetime(timestamp_ms: i64) -> Result<chrono::DateTime<chron
         .ok_or_else(|| Error::ParseError(format!("无法转换时间戳: {}", timestamp_ms)))
 }
 
-/// 验证系统时间，检查本地时间与OKX服务器时间的差异
+/// 验证系统时间，检查本地时间与OKX服务器时间的差异，并更新 `SERVER_CLOCK` 的偏差
 pub async fn validate_system_time() -> Result<i64, error::Error> {
+    let local_before = chrono::Utc::now().timestamp_millis();
     let time_str = api::public_data::OkxPublicData::get_time()
         .await
         .map_err(|e| error::Error::ApiRequestError(format!("获取OKX系统时间失败: {}", e)))?;
@@ -80,6 +98,7 @@ pub async fn validate_system_time() -> Result<i64, error::Error> {
     let now = chrono::Utc::now().timestamp_millis();
     let okx_time = time.timestamp_millis();
     let time_diff = (now - okx_time).abs();
+    SERVER_CLOCK.record_sample(local_before, okx_time, now);
 
     if time_diff < 20000 {
         log::info!("时间间隔相差值: {} 毫秒", time_diff);
@@ -94,3 +113,39 @@ pub async fn validate_system_time() -> Result<i64, error::Error> {
 pub fn create_client() -> Result<client::OkxClient, error::Error> {
     client::OkxClient::from_env()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Query<'a> {
+        inst_id: &'a str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        after: Option<&'a str>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ccy: Option<&'a str>,
+    }
+
+    #[test]
+    fn build_request_path_encodes_and_skips_none() {
+        let path = build_request_path(
+            "/api/v5/trade/fills",
+            &Query {
+                inst_id: "BTC-USDT",
+                after: Some("a b&c"),
+                ccy: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(path, "/api/v5/trade/fills?instId=BTC-USDT&after=a+b%26c");
+    }
+
+    #[test]
+    fn build_request_path_without_params_has_no_question_mark() {
+        let path = build_request_path("/api/v5/account/config", &()).unwrap();
+        assert_eq!(path, "/api/v5/account/config");
+    }
+}
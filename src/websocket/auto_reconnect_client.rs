@@ -5,6 +5,7 @@ use std::time::{Duration, Instant};
 
 use futures::{SinkExt, StreamExt};
 use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tokio::sync::mpsc;
 use tokio::time::sleep;
@@ -12,14 +13,78 @@ use tokio_tungstenite::tungstenite::Error as TungsteniteError;
 use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
 use url::Url;
 
-use crate::config::{Credentials, CONFIG};
+use crate::client::OkxClientBuilder;
+use crate::clock::{DEFAULT_TIME_SYNC_INTERVAL, SERVER_CLOCK};
+use crate::config::{global_config, Credentials};
+use crate::credential_provider::CredentialStore;
+use crate::dto::common::BatchResult;
+use crate::dto::trade_dto::{
+    AmendOrderReqDto, AmendOrderResDto, CancelOrderReqDto, OrderReqDto, OrderResDto,
+};
+use crate::enums::environment_enums::Environment;
 use crate::error::Error;
+use crate::secret::redact_body;
 use crate::utils;
 use crate::websocket::channel::{Args, ChannelType};
 use crate::websocket::models::{
-    WebSocketAuth, WebSocketLoginRequest, WebSocketOperation, WebSocketRequest,
-    WebSocketSubscription,
+    WebSocketAuth, WebSocketLoginRequest, WebSocketOpResponse, WebSocketOperation,
+    WebSocketRequest, WebSocketSubscription,
 };
+use crate::websocket::pending::{self, PendingRequests, DEFAULT_REQUEST_TIMEOUT};
+
+/// 等待登录响应的超时时间
+pub(crate) const LOGIN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 登录时间戳过期(60004)或无效(60006)的错误码，通常由本地时钟偏差导致
+const LOGIN_TIMESTAMP_ERROR_CODES: [&str; 2] = ["60004", "60006"];
+
+/// 登录失败的响应转为错误
+pub(crate) fn login_error(response: &Value) -> Error {
+    Error::WebSocketError(format!(
+        "WebSocket登录失败: code={}, msg={}",
+        response["code"], response["msg"]
+    ))
+}
+
+/// 是否为时间戳导致的登录失败
+pub(crate) fn is_login_timestamp_error(error: &Error) -> bool {
+    let Error::WebSocketError(msg) = error else {
+        return false;
+    };
+    LOGIN_TIMESTAMP_ERROR_CODES
+        .iter()
+        .any(|code| msg.starts_with(&format!("WebSocket登录失败: code=\"{}\"", code)))
+}
+
+/// 全局服务器时钟过期时（或 `force` 时）通过REST公共接口同步，WebSocket登录签名使用该时钟；
+/// 同步失败只记录日志
+pub(crate) async fn sync_server_clock(
+    environment: Environment,
+    interval: Option<Duration>,
+    force: bool,
+) {
+    let Some(interval) = interval else {
+        return;
+    };
+    if force {
+        SERVER_CLOCK.invalidate();
+    }
+    if !SERVER_CLOCK.is_stale(interval) || !SERVER_CLOCK.try_begin_sync() {
+        return;
+    }
+    let result = match OkxClientBuilder::new()
+        .environment(environment)
+        .system_proxy(true)
+        .build_public()
+    {
+        Ok(client) => client.sync_server_time().await,
+        Err(e) => Err(e),
+    };
+    if let Err(e) = &result {
+        warn!("WebSocket登录前同步服务器时间失败: {}", e);
+    }
+    SERVER_CLOCK.end_sync(result.is_ok());
+}
 
 /// 连接状态枚举
 #[derive(Debug, Clone, PartialEq)]
@@ -31,7 +96,8 @@ pub enum ConnectionState {
 }
 
 /// 自动重连配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
 pub struct ReconnectConfig {
     /// 是否启用自动重连
     pub enabled: bool,
@@ -70,8 +136,10 @@ pub struct AutoReconnectWebsocketClient {
     urls: Vec<String>,
     /// 是否使用私有WS (需要认证)
     is_private: bool,
-    /// 认证凭证
-    credentials: Option<Credentials>,
+    /// 认证凭证，每次连接时读取，轮换后下次重连生效
+    credentials: Option<CredentialStore>,
+    /// 交易环境，只使用同一环境的凭证登录
+    environment: Environment,
     /// 连接状态
     connection_state: Arc<Mutex<ConnectionState>>,
     /// 最后消息时间
@@ -88,6 +156,12 @@ pub struct AutoReconnectWebsocketClient {
     is_running: Arc<Mutex<bool>>,
     /// 当前使用的URL索引
     current_url_idx: Arc<Mutex<usize>>,
+    /// 等待响应的交易请求
+    pending: PendingRequests,
+    /// 交易请求的超时时间
+    request_timeout: Duration,
+    /// 登录前同步服务器时间的间隔，`None` 表示不自动同步
+    time_sync_interval: Option<Duration>,
 }
 
 impl AutoReconnectWebsocketClient {
@@ -122,7 +196,14 @@ impl AutoReconnectWebsocketClient {
             if !primary_host.is_empty() {
                 hosts.push(primary_host.clone());
             }
-            for host in ["ws.okx.com"] {
+            // 模拟盘（wspap）和美国站的Key不能发往 ws.okx.com，不添加备用域名
+            let fallback_hosts: &[&str] =
+                if primary_host.contains("pap") || primary_host.starts_with("wsus") {
+                    &[]
+                } else {
+                    &["ws.okx.com"]
+                };
+            for &host in fallback_hosts {
                 if host != primary_host && !hosts.contains(&host.to_string()) {
                     hosts.push(host.to_string());
                 }
@@ -165,39 +246,79 @@ impl AutoReconnectWebsocketClient {
         urls
     }
 
-    /// 创建新的公共频道客户端
-    pub fn new_public() -> Self {
-        Self::new_with_config(&CONFIG.websocket_url, None, ReconnectConfig::default())
+    /// 创建新的公共频道客户端，地址和环境取自全局 `CONFIG`，交易环境无效时返回错误
+    pub fn new_public() -> Result<Self, Error> {
+        let config = global_config()?;
+        Ok(Self::new_with_credential_store(
+            &config.websocket_url,
+            config.environment,
+            None,
+            ReconnectConfig::default(),
+        ))
+    }
+
+    /// 创建指定环境的公共频道客户端
+    pub fn new_public_with_environment(environment: Environment) -> Self {
+        Self::new_with_credential_store(
+            environment.public_websocket_url(),
+            environment,
+            None,
+            ReconnectConfig::default(),
+        )
     }
 
-    /// 创建新的私有频道客户端
+    /// 创建新的私有频道客户端，连接凭证所属环境的私有频道地址
     pub fn new_private(credentials: Credentials) -> Self {
-        Self::new_with_config(
-            &CONFIG.private_websocket_url,
-            Some(credentials),
+        let environment = credentials.environment;
+        Self::new_with_credential_store(
+            environment.private_websocket_url(),
+            environment,
+            Some(CredentialStore::fixed(credentials)),
             ReconnectConfig::default(),
         )
     }
-    /// 创建新的交易频道客户端
+    /// 创建新的交易频道客户端，连接凭证所属环境的业务频道地址
     pub fn new_business(credentials: Credentials) -> Self {
-        Self::new_with_config(
-            &CONFIG.business_websocket_url,
-            Some(credentials),
+        let environment = credentials.environment;
+        Self::new_with_credential_store(
+            environment.business_websocket_url(),
+            environment,
+            Some(CredentialStore::fixed(credentials)),
             ReconnectConfig::default(),
         )
     }
 
-    /// 使用自定义配置创建客户端
+    /// 使用自定义配置创建客户端，环境与凭证一致；没有凭证时使用全局 `CONFIG` 中的环境，交易环境无效时返回错误
     pub fn new_with_config(
         url: &str,
         credentials: Option<Credentials>,
         config: ReconnectConfig,
+    ) -> Result<Self, Error> {
+        let environment = match &credentials {
+            Some(credentials) => credentials.environment,
+            None => global_config()?.environment,
+        };
+        Ok(Self::new_with_credential_store(
+            url,
+            environment,
+            credentials.map(CredentialStore::fixed),
+            config,
+        ))
+    }
+
+    /// 使用可轮换的凭证创建客户端
+    pub fn new_with_credential_store(
+        url: &str,
+        environment: Environment,
+        credentials: Option<CredentialStore>,
+        config: ReconnectConfig,
     ) -> Self {
         let urls = Self::build_url_pool(url);
         Self {
             urls,
             is_private: credentials.is_some(),
             credentials,
+            environment,
             connection_state: Arc::new(Mutex::new(ConnectionState::Disconnected)),
             last_message_time: Arc::new(Mutex::new(Instant::now())),
             subscriptions: Arc::new(Mutex::new(HashMap::new())),
@@ -206,9 +327,22 @@ impl AutoReconnectWebsocketClient {
             ws_sender: Arc::new(Mutex::new(None)),
             is_running: Arc::new(Mutex::new(false)),
             current_url_idx: Arc::new(Mutex::new(0)),
+            pending: PendingRequests::default(),
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            time_sync_interval: Some(DEFAULT_TIME_SYNC_INTERVAL),
         }
     }
 
+    /// 设置下单、撤单、改单等交易请求等待响应的超时时间，默认10秒
+    pub fn set_request_timeout(&mut self, timeout: Duration) {
+        self.request_timeout = timeout;
+    }
+
+    /// 设置登录前同步服务器时间的间隔，`None` 表示不自动同步
+    pub fn set_time_sync_interval(&mut self, interval: Option<Duration>) {
+        self.time_sync_interval = interval;
+    }
+
     /// 启动客户端并返回消息接收器
     pub async fn start(&self) -> Result<mpsc::UnboundedReceiver<Value>, Error> {
         let (tx, rx) = mpsc::unbounded_channel();
@@ -237,6 +371,7 @@ impl AutoReconnectWebsocketClient {
         *self.message_sender.lock().unwrap() = None;
         *self.ws_sender.lock().unwrap() = None;
         *self.connection_state.lock().unwrap() = ConnectionState::Disconnected;
+        self.pending.fail_all("客户端已停止");
         info!("自动重连WebSocket客户端已停止");
     }
 
@@ -288,6 +423,67 @@ impl AutoReconnectWebsocketClient {
         Ok(())
     }
 
+    /// 下单，收到 `id` 相同的响应后返回，结果与REST接口一致
+    pub async fn place_order(&self, order: OrderReqDto) -> Result<Vec<OrderResDto>, Error> {
+        let response = self
+            .send_trade_op(WebSocketOperation::Order, vec![order])
+            .await?;
+        pending::into_data(response)
+    }
+
+    /// 批量下单，部分成功时返回每一笔订单的执行结果
+    pub async fn place_multiple_orders(
+        &self,
+        orders: Vec<OrderReqDto>,
+    ) -> Result<BatchResult<OrderResDto>, Error> {
+        let response = self
+            .send_trade_op(WebSocketOperation::BatchOrders, orders)
+            .await?;
+        pending::into_batch(response)
+    }
+
+    /// 撤单
+    pub async fn cancel_order(&self, params: CancelOrderReqDto) -> Result<Vec<OrderResDto>, Error> {
+        let response = self
+            .send_trade_op(WebSocketOperation::CancelOrder, vec![params])
+            .await?;
+        pending::into_data(response)
+    }
+
+    /// 修改订单
+    pub async fn amend_order(
+        &self,
+        params: AmendOrderReqDto,
+    ) -> Result<Vec<AmendOrderResDto>, Error> {
+        let response = self
+            .send_trade_op(WebSocketOperation::AmendOrder, vec![params])
+            .await?;
+        pending::into_data(response)
+    }
+
+    /// 发送交易请求并等待 `id` 相同的响应，重连期间的请求直接失败，不会在重连后重发
+    async fn send_trade_op<T: Serialize>(
+        &self,
+        op: WebSocketOperation,
+        args: Vec<T>,
+    ) -> Result<WebSocketOpResponse, Error> {
+        if !self.is_private {
+            return Err(Error::WebSocketError(
+                "交易操作需要使用私有WebSocket连接".to_string(),
+            ));
+        }
+        let (id, message, rx) = self.pending.prepare(op, args)?;
+        let sent = match self.ws_sender.lock().unwrap().as_ref() {
+            Some(sender) => sender.send(Message::Text(message.into())).is_ok(),
+            None => false,
+        };
+        if !sent {
+            self.pending.cancel(&id);
+            return Err(Error::ConnectionError("WebSocket连接未建立".to_string()));
+        }
+        self.pending.wait(&id, rx, self.request_timeout).await
+    }
+
     /// 获取连接状态
     pub fn get_connection_state(&self) -> ConnectionState {
         self.connection_state.lock().unwrap().clone()
@@ -305,6 +501,16 @@ impl AutoReconnectWebsocketClient {
         elapsed < Duration::from_secs(self.reconnect_config.message_timeout)
     }
 
+    /// 交易环境
+    pub fn environment(&self) -> Environment {
+        self.environment
+    }
+
+    /// 可轮换的凭证，轮换后下次重连时使用新凭证登录
+    pub fn credential_store(&self) -> Option<&CredentialStore> {
+        self.credentials.as_ref()
+    }
+
     /// 获取活跃订阅数量
     pub fn get_active_subscriptions_count(&self) -> usize {
         self.subscriptions.lock().unwrap().len()
@@ -315,6 +521,8 @@ impl AutoReconnectWebsocketClient {
         let urls = self.urls.clone();
         let is_private = self.is_private;
         let credentials = self.credentials.clone();
+        let environment = self.environment;
+        let time_sync_interval = self.time_sync_interval;
         let connection_state = self.connection_state.clone();
         let last_message_time = self.last_message_time.clone();
         let subscriptions = self.subscriptions.clone();
@@ -322,6 +530,7 @@ impl AutoReconnectWebsocketClient {
         let config = self.reconnect_config.clone();
         let ws_sender = self.ws_sender.clone();
         let current_url_idx = self.current_url_idx.clone();
+        let pending = self.pending.clone();
 
         tokio::spawn(async move {
             let mut reconnect_attempts = 0;
@@ -340,7 +549,15 @@ impl AutoReconnectWebsocketClient {
                     backoff_delay
                 );
                 // 尝试连接
-                match Self::establish_connection(&url, is_private, &credentials).await {
+                match Self::establish_connection(
+                    &url,
+                    is_private,
+                    environment,
+                    time_sync_interval,
+                    &credentials,
+                )
+                .await
+                {
                     Ok((ws_stream, _)) => {
                         info!("WebSocket连接建立成功");
                         *connection_state.lock().unwrap() = ConnectionState::Connected;
@@ -385,6 +602,7 @@ impl AutoReconnectWebsocketClient {
                             &connection_state,
                             &last_message_time,
                             &is_running,
+                            &pending,
                         )
                         .await;
 
@@ -395,6 +613,9 @@ impl AutoReconnectWebsocketClient {
                         *ws_sender.lock().unwrap() = None;
                         send_task.abort();
 
+                        // 未收到响应的交易请求无法确认结果，不随重连重发
+                        pending.fail_all("连接已断开");
+
                         if let Err(e) = handle_result {
                             error!("消息处理错误: {}", e);
                         }
@@ -445,7 +666,9 @@ impl AutoReconnectWebsocketClient {
     async fn establish_connection(
         url: &str,
         is_private: bool,
-        credentials: &Option<Credentials>,
+        environment: Environment,
+        time_sync_interval: Option<Duration>,
+        credentials: &Option<CredentialStore>,
     ) -> Result<
         (
             tokio_tungstenite::WebSocketStream<
@@ -457,15 +680,33 @@ impl AutoReconnectWebsocketClient {
     > {
         let url =
             Url::parse(url).map_err(|e| Error::WebSocketError(format!("Invalid URL: {}", e)))?;
+        let credentials = credentials.as_ref().map(CredentialStore::current);
+        if let Some(creds) = &credentials {
+            if creds.environment != environment {
+                return Err(Error::WebSocketError(format!(
+                    "API凭证属于{}环境，不能用于{}环境",
+                    creds.environment, environment
+                )));
+            }
+        }
 
-        let (ws_stream, response) = connect_async(url.as_str())
+        let (mut ws_stream, response) = connect_async(url.as_str())
             .await
             .map_err(|e| Error::WebSocketError(format!("Connection failed: {}", e)))?;
 
         // 如果是私有频道，需要进行认证
         if is_private {
-            if let Some(creds) = credentials {
-                Self::authenticate(&ws_stream, creds).await?;
+            if let Some(creds) = &credentials {
+                sync_server_clock(environment, time_sync_interval, false).await;
+                if let Err(e) = Self::authenticate(&mut ws_stream, creds).await {
+                    if !is_login_timestamp_error(&e) {
+                        return Err(e);
+                    }
+                    // 时间戳被拒绝，强制同步服务器时间后重试一次
+                    warn!("WebSocket登录时间戳无效，同步服务器时间后重试: {}", e);
+                    sync_server_clock(environment, time_sync_interval, true).await;
+                    Self::authenticate(&mut ws_stream, creds).await?;
+                }
             } else {
                 return Err(Error::WebSocketError(
                     "Private channel requires credentials".to_string(),
@@ -476,17 +717,16 @@ impl AutoReconnectWebsocketClient {
         Ok((ws_stream, response))
     }
 
-    /// 进行WebSocket认证
+    /// 进行WebSocket认证，等待登录成功后才开始订阅和交易
     async fn authenticate(
-        _ws_stream: &tokio_tungstenite::WebSocketStream<
+        ws_stream: &mut tokio_tungstenite::WebSocketStream<
             tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
         >,
         credentials: &Credentials,
     ) -> Result<(), Error> {
         let timestamp = utils::generate_timestamp_websocket();
-        let sign_str = format!("{}GET/users/self/verify", timestamp);
         let signature = utils::generate_signature(
-            &credentials.api_secret,
+            credentials.api_secret.expose(),
             &timestamp,
             &reqwest::Method::GET,
             "/users/self/verify",
@@ -499,18 +739,42 @@ impl AutoReconnectWebsocketClient {
                 api_key: credentials.api_key.clone(),
                 passphrase: credentials.passphrase.clone(),
                 timestamp,
-                sign: signature,
+                sign: signature.into(),
             }],
         };
 
-        let login_message =
-            serde_json::to_string(&login_request).map_err(|e| Error::JsonError(e))?;
+        let login_message = serde_json::to_string(&login_request).map_err(Error::JsonError)?;
 
         // 发送认证消息
-        // 注意：这里需要修改为可变引用，但为了简化示例，我们先跳过实际发送
-        debug!("认证消息: {}", login_message);
-
-        Ok(())
+        debug!("认证消息: {}", redact_body(&login_message));
+        ws_stream
+            .send(Message::Text(login_message.into()))
+            .await
+            .map_err(|e| Error::WebSocketError(format!("发送登录请求失败: {}", e)))?;
+
+        let wait_login = async {
+            while let Some(message) = ws_stream.next().await {
+                let message = message
+                    .map_err(|e| Error::WebSocketError(format!("等待登录响应失败: {}", e)))?;
+                let Message::Text(text) = message else {
+                    continue;
+                };
+                let Ok(value) = serde_json::from_str::<Value>(&text) else {
+                    continue;
+                };
+                match value.get("event").and_then(Value::as_str) {
+                    Some("login") if value["code"] == "0" => return Ok(()),
+                    Some("login") | Some("error") => return Err(login_error(&value)),
+                    _ => {}
+                }
+            }
+            Err(Error::WebSocketError(
+                "等待登录响应时连接已关闭".to_string(),
+            ))
+        };
+        tokio::time::timeout(LOGIN_TIMEOUT, wait_login)
+            .await
+            .map_err(|_| Error::TimeoutError("等待WebSocket登录响应超时".to_string()))?
     }
 
     /// 重新订阅所有频道
@@ -594,6 +858,7 @@ impl AutoReconnectWebsocketClient {
         connection_state: &Arc<Mutex<ConnectionState>>,
         last_message_time: &Arc<Mutex<Instant>>,
         is_running: &Arc<Mutex<bool>>,
+        pending: &PendingRequests,
     ) -> Result<(), Error> {
         while *is_running.lock().unwrap() {
             tokio::select! {
@@ -603,6 +868,10 @@ impl AutoReconnectWebsocketClient {
                             *last_message_time.lock().unwrap() = Instant::now();
 
                             if let Ok(value) = serde_json::from_str::<Value>(&text) {
+                                // 交易请求的响应交给等待中的调用方
+                                if pending.resolve(&value) {
+                                    continue;
+                                }
                                 if tx.send(value).is_err() {
                                     warn!("消息发送失败，接收器可能已关闭");
                                     break;
@@ -747,12 +1016,12 @@ impl AutoReconnectWebsocketClient {
             args: vec![subscription],
         };
 
-        let message = serde_json::to_string(&request).map_err(|e| Error::JsonError(e))?;
+        let message = serde_json::to_string(&request).map_err(Error::JsonError)?;
         debug!("发布{}请求: {}", operation, message);
         // 通过WebSocket发送器发送消息
         if let Some(sender) = ws_sender.lock().unwrap().as_ref() {
             let ws_message = Message::Text(message.into());
-            if let Err(_) = sender.send(ws_message) {
+            if sender.send(ws_message).is_err() {
                 return Err(Error::ConnectionError("无法发送订阅请求".to_string()));
             }
             debug!("订阅请求已发送到WebSocket");
@@ -780,6 +1049,7 @@ impl Clone for AutoReconnectWebsocketClient {
             urls: self.urls.clone(),
             is_private: self.is_private,
             credentials: self.credentials.clone(),
+            environment: self.environment,
             connection_state: self.connection_state.clone(),
             last_message_time: self.last_message_time.clone(),
             subscriptions: self.subscriptions.clone(),
@@ -788,6 +1058,97 @@ impl Clone for AutoReconnectWebsocketClient {
             ws_sender: self.ws_sender.clone(),
             is_running: self.is_running.clone(),
             current_url_idx: self.current_url_idx.clone(),
+            pending: self.pending.clone(),
+            request_timeout: self.request_timeout,
+            time_sync_interval: self.time_sync_interval,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::environment_enums::Environment;
+
+    #[test]
+    fn detects_login_timestamp_errors() {
+        let expired =
+            login_error(&serde_json::json!({"event": "login", "code": "60004", "msg": ""}));
+        let invalid =
+            login_error(&serde_json::json!({"event": "error", "code": "60006", "msg": ""}));
+        let rejected =
+            login_error(&serde_json::json!({"event": "error", "code": "60009", "msg": ""}));
+        assert!(is_login_timestamp_error(&expired));
+        assert!(is_login_timestamp_error(&invalid));
+        assert!(!is_login_timestamp_error(&rejected));
+    }
+
+    #[tokio::test]
+    async fn logs_in_before_placing_orders() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            while let Some(Ok(message)) = ws.next().await {
+                let Message::Text(text) = message else {
+                    continue;
+                };
+                // 心跳 "ping" 不是JSON
+                let Ok(request) = serde_json::from_str::<Value>(&text) else {
+                    continue;
+                };
+                let reply = match request["op"].as_str() {
+                    Some("login") => serde_json::json!({"event": "login", "code": "0", "msg": ""}),
+                    Some("order") => serde_json::json!({
+                        "id": request["id"],
+                        "op": "order",
+                        "code": "0",
+                        "msg": "",
+                        "data": [{"ordId": "7", "clOrdId": "", "tag": "", "ts": "1", "sCode": "0", "sMsg": ""}]
+                    }),
+                    _ => continue,
+                };
+                ws.send(Message::Text(reply.to_string().into()))
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let mut client = AutoReconnectWebsocketClient::new_with_config(
+            &url,
+            Some(Credentials::new(
+                "key",
+                "secret",
+                "passphrase",
+                Environment::Production,
+            )),
+            ReconnectConfig {
+                enabled: false,
+                ..ReconnectConfig::default()
+            },
+        )
+        .unwrap();
+        client.set_time_sync_interval(None);
+        let _rx = client.start().await.unwrap();
+        for _ in 0..100 {
+            if client.get_connection_state() == ConnectionState::Connected {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
         }
+
+        let order: OrderReqDto = serde_json::from_value(serde_json::json!({
+            "instId": "BTC-USDT",
+            "tdMode": "cash",
+            "side": "buy",
+            "ordType": "market",
+            "sz": "10"
+        }))
+        .unwrap();
+        assert_eq!(client.get_connection_state(), ConnectionState::Connected);
+        let result = client.place_order(order).await.unwrap();
+        assert_eq!(result[0].ord_id, "7");
+        client.stop().await;
     }
 }
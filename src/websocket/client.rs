@@ -11,19 +11,28 @@ use tokio::sync::mpsc::{self, Receiver, Sender};
 use tokio::task::JoinHandle;
 use tokio::time::sleep;
 use tokio_tungstenite::tungstenite::Error as WsError;
-use tokio_tungstenite::{
-    connect_async, tungstenite::protocol::Message,
-};
+use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
 use url::Url;
 
-use crate::config::{Credentials, CONFIG};
+use crate::clock::DEFAULT_TIME_SYNC_INTERVAL;
+use crate::config::{global_config, Credentials};
+use crate::dto::common::BatchResult;
+use crate::dto::trade_dto::{
+    AmendOrderReqDto, AmendOrderResDto, CancelOrderReqDto, OrderReqDto, OrderResDto,
+};
+use crate::enums::environment_enums::Environment;
 use crate::error::Error;
+use crate::secret::redact_body;
 use crate::utils;
+use crate::websocket::auto_reconnect_client::{
+    is_login_timestamp_error, login_error, sync_server_clock, LOGIN_TIMEOUT,
+};
 use crate::websocket::channel::{Args, ChannelType};
 use crate::websocket::models::{
-    WebSocketAuth, WebSocketLoginRequest, WebSocketOperation, WebSocketRequest,
-    WebSocketSubscription,
+    WebSocketAuth, WebSocketLoginRequest, WebSocketOpResponse, WebSocketOperation,
+    WebSocketRequest, WebSocketSubscription,
 };
+use crate::websocket::pending::{self, PendingRequests, DEFAULT_REQUEST_TIMEOUT};
 
 /// 连接状态枚举
 #[derive(Debug, Clone, PartialEq)]
@@ -34,7 +43,6 @@ pub enum ConnectionState {
     Reconnecting,
 }
 
-
 type WsMessage = Message;
 
 /// OKX WebSocket客户端
@@ -45,8 +53,8 @@ pub struct OkxWebsocketClient {
     is_private: bool,
     /// 认证凭证
     credentials: Option<Credentials>,
-    /// 是否使用模拟交易
-    is_simulated: String,
+    /// 交易环境
+    environment: Environment,
     /// 已订阅的频道
     subscriptions: Arc<Mutex<HashMap<String, WebSocketSubscription>>>,
     /// 消息发送通道
@@ -63,34 +71,51 @@ pub struct OkxWebsocketClient {
     last_message_time: Arc<Mutex<Instant>>,
     /// 最后一次ping时间
     last_ping_time: Arc<Mutex<Instant>>,
+    /// 等待响应的交易请求
+    pending: PendingRequests,
+    /// 交易请求的超时时间
+    request_timeout: Duration,
+    /// 登录前同步服务器时间的间隔，`None` 表示不自动同步
+    time_sync_interval: Option<Duration>,
 }
 
 impl OkxWebsocketClient {
-    /// 创建新的公共WebSocket客户端
-    pub fn new_public() -> Self {
-        Self {
-            url: CONFIG.websocket_url.clone(),
-            is_private: false,
-            credentials: None,
-            is_simulated: CONFIG.is_simulated_trading.clone(),
-            subscriptions: Arc::new(Mutex::new(HashMap::new())),
-            tx: None,
-            rx: None,
-            connection_task: None,
-            reconnect_task: None,
-            connection_state: Arc::new(Mutex::new(ConnectionState::Disconnected)),
-            last_message_time: Arc::new(Mutex::new(Instant::now())),
-            last_ping_time: Arc::new(Mutex::new(Instant::now())),
-        }
+    /// 创建新的公共WebSocket客户端，地址和环境取自全局 `CONFIG`，交易环境无效时返回错误
+    pub fn new_public() -> Result<Self, Error> {
+        let config = global_config()?;
+        Ok(Self::with_url(
+            config.websocket_url,
+            config.environment,
+            None,
+        ))
     }
 
-    /// 创建新的私有WebSocket客户端
+    /// 创建指定环境的公共WebSocket客户端
+    pub fn new_public_with_environment(environment: Environment) -> Self {
+        Self::with_url(
+            environment.public_websocket_url().to_string(),
+            environment,
+            None,
+        )
+    }
+
+    /// 创建新的私有WebSocket客户端，连接凭证所属环境的私有频道地址
     pub fn new_private(credentials: Credentials) -> Self {
+        let environment = credentials.environment;
+        Self::with_url(
+            environment.private_websocket_url().to_string(),
+            environment,
+            Some(credentials),
+        )
+    }
+
+    /// 有凭证时为私有连接
+    fn with_url(url: String, environment: Environment, credentials: Option<Credentials>) -> Self {
         Self {
-            url: CONFIG.private_websocket_url.clone(),
-            is_private: true,
-            credentials: Some(credentials),
-            is_simulated: CONFIG.is_simulated_trading.clone(),
+            url,
+            is_private: credentials.is_some(),
+            credentials,
+            environment,
             subscriptions: Arc::new(Mutex::new(HashMap::new())),
             tx: None,
             rx: None,
@@ -99,12 +124,15 @@ impl OkxWebsocketClient {
             connection_state: Arc::new(Mutex::new(ConnectionState::Disconnected)),
             last_message_time: Arc::new(Mutex::new(Instant::now())),
             last_ping_time: Arc::new(Mutex::new(Instant::now())),
+            pending: PendingRequests::default(),
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            time_sync_interval: Some(DEFAULT_TIME_SYNC_INTERVAL),
         }
     }
 
-    /// 设置是否使用模拟交易
-    pub fn set_simulated_trading(&mut self, is_simulated: String) {
-        self.is_simulated = is_simulated;
+    /// 交易环境
+    pub fn environment(&self) -> Environment {
+        self.environment
     }
 
     /// 设置WebSocket URL
@@ -112,6 +140,16 @@ impl OkxWebsocketClient {
         self.url = url.into();
     }
 
+    /// 设置下单、撤单、改单等交易请求等待响应的超时时间，默认10秒
+    pub fn set_request_timeout(&mut self, timeout: Duration) {
+        self.request_timeout = timeout;
+    }
+
+    /// 设置登录前同步服务器时间的间隔，`None` 表示不自动同步
+    pub fn set_time_sync_interval(&mut self, interval: Option<Duration>) {
+        self.time_sync_interval = interval;
+    }
+
     /// 获取当前连接状态
     pub fn get_connection_state(&self) -> ConnectionState {
         if let Ok(state) = self.connection_state.lock() {
@@ -149,15 +187,13 @@ impl OkxWebsocketClient {
         let url = Url::parse(&url_string)
             .map_err(|e| Error::WebSocketError(format!("无效的WebSocket URL: {}", e)))?;
 
-        let (ws_stream, _) = connect_async(url.as_str())
-            .await
-            .map_err(|e| {
-                // 连接失败，设置状态为断开
-                if let Ok(mut state) = self.connection_state.lock() {
-                    *state = ConnectionState::Disconnected;
-                }
-                Error::WebSocketError(format!("连接WebSocket失败: {}", e))
-            })?;
+        let (ws_stream, _) = connect_async(url.as_str()).await.map_err(|e| {
+            // 连接失败，设置状态为断开
+            if let Ok(mut state) = self.connection_state.lock() {
+                *state = ConnectionState::Disconnected;
+            }
+            Error::WebSocketError(format!("连接WebSocket失败: {}", e))
+        })?;
 
         info!("已连接到OKX WebSocket服务器");
 
@@ -193,6 +229,7 @@ impl OkxWebsocketClient {
             Duration::from_secs(5),
             last_message_time,
             connection_state,
+            self.pending.clone(),
         ));
 
         // 合并任务
@@ -206,8 +243,11 @@ impl OkxWebsocketClient {
 
         // 如果是私有连接，进行认证
         if self.is_private {
-            if let Some(ref credentials) = self.credentials {
-                self.login(credentials).await?;
+            if let Some(credentials) = self.credentials.clone() {
+                if let Err(e) = self.login_with_time_sync(&credentials).await {
+                    self.close().await;
+                    return Err(e);
+                }
             } else {
                 return Err(Error::AuthenticationError(
                     "私有WebSocket连接需要凭证".to_string(),
@@ -257,6 +297,7 @@ impl OkxWebsocketClient {
         if let Some(handle) = self.reconnect_task.take() {
             handle.abort();
         }
+        self.pending.fail_all("连接已关闭");
 
         // 清理资源
         self.tx = None;
@@ -329,6 +370,63 @@ impl OkxWebsocketClient {
         self.send_message(&request).await
     }
 
+    /// 下单，响应与REST接口 `OkxTrade::place_order` 相同
+    pub async fn place_order(&self, order: OrderReqDto) -> Result<Vec<OrderResDto>, Error> {
+        let response = self
+            .send_trade_op(WebSocketOperation::Order, vec![order])
+            .await?;
+        pending::into_data(response)
+    }
+
+    /// 批量下单，部分成功时返回每一笔订单的执行结果
+    pub async fn place_multiple_orders(
+        &self,
+        orders: Vec<OrderReqDto>,
+    ) -> Result<BatchResult<OrderResDto>, Error> {
+        let response = self
+            .send_trade_op(WebSocketOperation::BatchOrders, orders)
+            .await?;
+        pending::into_batch(response)
+    }
+
+    /// 撤单
+    pub async fn cancel_order(&self, params: CancelOrderReqDto) -> Result<Vec<OrderResDto>, Error> {
+        let response = self
+            .send_trade_op(WebSocketOperation::CancelOrder, vec![params])
+            .await?;
+        pending::into_data(response)
+    }
+
+    /// 修改订单
+    pub async fn amend_order(
+        &self,
+        params: AmendOrderReqDto,
+    ) -> Result<Vec<AmendOrderResDto>, Error> {
+        let response = self
+            .send_trade_op(WebSocketOperation::AmendOrder, vec![params])
+            .await?;
+        pending::into_data(response)
+    }
+
+    /// 发送交易请求并等待 `id` 相同的响应
+    async fn send_trade_op<T: Serialize>(
+        &self,
+        op: WebSocketOperation,
+        args: Vec<T>,
+    ) -> Result<WebSocketOpResponse, Error> {
+        if !self.is_private {
+            return Err(Error::WebSocketError(
+                "交易操作需要使用私有WebSocket连接".to_string(),
+            ));
+        }
+        let (id, message, rx) = self.pending.prepare(op, args)?;
+        if let Err(e) = self.send_text(message).await {
+            self.pending.cancel(&id);
+            return Err(e);
+        }
+        self.pending.wait(&id, rx, self.request_timeout).await
+    }
+
     /// 封装心跳与消息接收的 select! 逻辑
     async fn run_ws_with_heartbeat(
         mut read: impl Stream<Item = Result<WsMessage, WsError>> + Unpin,
@@ -337,6 +435,7 @@ impl OkxWebsocketClient {
         heartbeat_interval: Duration,
         last_message_time: Arc<Mutex<Instant>>,
         connection_state: Arc<Mutex<ConnectionState>>,
+        pending: PendingRequests,
     ) {
         let mut last_msg_time = Instant::now();
         let mut waiting_pong = false;
@@ -345,9 +444,9 @@ impl OkxWebsocketClient {
             tokio::select! {
                 msg_result = read.next() => {
                     if let Some(res) = msg_result {
-                        if let Err(_) = Self::handle_ws_message(
-                            res, &tx_out, &tx_in, &mut last_msg_time, &mut waiting_pong, &mut ping_sent_time, &last_message_time
-                        ).await {
+                        if Self::handle_ws_message(
+                            res, &tx_out, &tx_in, &mut last_msg_time, &mut waiting_pong, &mut ping_sent_time, &last_message_time, &pending
+                        ).await.is_err() {
                             // 连接断开，更新状态
                             if let Ok(mut state) = connection_state.lock() {
                                 *state = ConnectionState::Disconnected;
@@ -378,9 +477,12 @@ impl OkxWebsocketClient {
                 }
             }
         }
+        // 连接中断时，已发出但未收到响应的交易请求结果未知
+        pending.fail_all("连接已断开");
     }
 
     /// 处理单条 WebSocket 消息
+    #[allow(clippy::too_many_arguments)]
     async fn handle_ws_message(
         res: Result<WsMessage, WsError>,
         tx_out: &Sender<serde_json::Value>,
@@ -389,6 +491,7 @@ impl OkxWebsocketClient {
         waiting_pong: &mut bool,
         ping_sent_time: &mut Option<Instant>,
         last_message_time: &Arc<Mutex<Instant>>,
+        pending: &PendingRequests,
     ) -> Result<(), ()> {
         match res {
             Ok(msg) => {
@@ -399,9 +502,13 @@ impl OkxWebsocketClient {
                 }
                 match &msg {
                     WsMessage::Text(text) => {
-                        debug!("收到WebSocket消息: {}", text);
+                        debug!("收到WebSocket消息: {}", redact_body(text));
                         match serde_json::from_str::<serde_json::Value>(text) {
                             Ok(json_value) => {
+                                // 交易请求的响应交给等待中的调用方
+                                if pending.resolve(&json_value) {
+                                    return Ok(());
+                                }
                                 if let Err(e) = tx_out.send(json_value).await {
                                     error!("发送接收的消息到通道错误: {}", e);
                                     return Err(());
@@ -434,11 +541,24 @@ impl OkxWebsocketClient {
         Ok(())
     }
 
-    /// 登录私有WebSocket
-    async fn login(&self, credentials: &Credentials) -> Result<(), Error> {
+    /// 服务器时钟过期时先同步再登录，时间戳被拒绝时强制同步后重试一次
+    async fn login_with_time_sync(&mut self, credentials: &Credentials) -> Result<(), Error> {
+        sync_server_clock(self.environment, self.time_sync_interval, false).await;
+        match self.login(credentials).await {
+            Err(e) if is_login_timestamp_error(&e) => {
+                warn!("WebSocket登录时间戳无效，同步服务器时间后重试: {}", e);
+                sync_server_clock(self.environment, self.time_sync_interval, true).await;
+                self.login(credentials).await
+            }
+            result => result,
+        }
+    }
+
+    /// 登录私有WebSocket，等待服务端的登录响应
+    async fn login(&mut self, credentials: &Credentials) -> Result<(), Error> {
         let timestamp = utils::generate_timestamp_websocket();
         let signature = utils::generate_signature(
-            &credentials.api_secret,
+            credentials.api_secret.expose(),
             &timestamp,
             &Method::GET,
             "/users/self/verify",
@@ -446,7 +566,7 @@ impl OkxWebsocketClient {
         )?;
         let auth = WebSocketAuth {
             api_key: credentials.api_key.clone(),
-            sign: signature,
+            sign: signature.into(),
             timestamp,
             passphrase: credentials.passphrase.clone(),
         };
@@ -456,21 +576,50 @@ impl OkxWebsocketClient {
         };
         self.send_message(&login_request).await?;
         info!("已发送WebSocket登录请求");
-        sleep(Duration::from_millis(500)).await;
+
+        let rx = self
+            .rx
+            .as_mut()
+            .ok_or_else(|| Error::WebSocketError("WebSocket未连接".to_string()))?;
+        tokio::time::timeout(LOGIN_TIMEOUT, Self::wait_login(rx))
+            .await
+            .map_err(|_| Error::TimeoutError("等待WebSocket登录响应超时".to_string()))??;
+        info!("WebSocket登录成功");
         Ok(())
     }
 
+    /// 等待登录响应，`code` 非0或收到 `error` 事件时返回错误
+    async fn wait_login(rx: &mut Receiver<serde_json::Value>) -> Result<(), Error> {
+        while let Some(value) = rx.recv().await {
+            match value.get("event").and_then(serde_json::Value::as_str) {
+                Some("login") if value["code"] == "0" => return Ok(()),
+                Some("login") | Some("error") => return Err(login_error(&value)),
+                _ => debug!("登录完成前收到消息: {}", redact_body(&value.to_string())),
+            }
+        }
+        Err(Error::WebSocketError(
+            "等待登录响应时连接已关闭".to_string(),
+        ))
+    }
+
     /// 发送WebSocket消息
     async fn send_message<T: Serialize>(&self, message: &T) -> Result<(), Error> {
+        let message_str = serde_json::to_string(message).map_err(Error::JsonError)?;
+        self.send_text(message_str).await
+    }
+
+    /// 发送已序列化的WebSocket消息
+    async fn send_text(&self, message_str: String) -> Result<(), Error> {
         // 检查连接状态
         let state = self.get_connection_state();
         if state != ConnectionState::Connected {
-            return Err(Error::WebSocketError(format!("连接已断开，无法发送消息")));
+            return Err(Error::WebSocketError(
+                "连接已断开，无法发送消息".to_string(),
+            ));
         }
 
         if let Some(tx) = &self.tx {
-            let message_str = serde_json::to_string(message).map_err(|e| Error::JsonError(e))?;
-            debug!("发送WebSocket消息: {}", message_str);
+            debug!("发送WebSocket消息: {}", redact_body(&message_str));
             tx.send(Message::Text(message_str.into()))
                 .await
                 .map_err(|e| Error::WebSocketError(format!("发送WebSocket消息失败: {}", e)))?;
@@ -530,8 +679,6 @@ impl OkxWebsocketClient {
             }
         }));
     }
-
-
 }
 
 impl Clone for OkxWebsocketClient {
@@ -540,7 +687,7 @@ impl Clone for OkxWebsocketClient {
             url: self.url.clone(),
             is_private: self.is_private,
             credentials: self.credentials.clone(),
-            is_simulated: self.is_simulated.clone(),
+            environment: self.environment,
             subscriptions: self.subscriptions.clone(),
             tx: self.tx.clone(),
             rx: None,
@@ -549,6 +696,9 @@ impl Clone for OkxWebsocketClient {
             connection_state: self.connection_state.clone(),
             last_message_time: self.last_message_time.clone(),
             last_ping_time: self.last_ping_time.clone(),
+            pending: self.pending.clone(),
+            request_timeout: self.request_timeout,
+            time_sync_interval: self.time_sync_interval,
         }
     }
 }
@@ -572,7 +722,7 @@ mod tests {
     #[tokio::test]
     async fn test_subscribe() {
         let args = Args::new().with_inst_id("BTC-USDT".to_string());
-        let mut client = OkxWebsocketClient::new_public();
+        let mut client = OkxWebsocketClient::new_public().unwrap();
         let mut rx = client.connect().await.unwrap();
         client.subscribe(ChannelType::Tickers, args).await.unwrap();
         tokio::spawn(async move {
@@ -582,14 +732,196 @@ mod tests {
         });
         sleep(Duration::from_secs(100)).await;
     }
+    /// 本地私有WebSocket服务器：依次以 `login_codes` 响应登录请求（用完后重复最后一个），
+    /// 收到交易请求后交给 `respond` 处理，返回 `None` 时断开连接
+    async fn serve_private<F>(login_codes: &'static [&'static str], respond: F) -> String
+    where
+        F: Fn(serde_json::Value) -> Option<Vec<serde_json::Value>> + Send + 'static,
+    {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let mut logins = 0;
+            while let Some(Ok(message)) = ws.next().await {
+                let Message::Text(text) = message else {
+                    continue;
+                };
+                let Ok(request) = serde_json::from_str::<serde_json::Value>(&text) else {
+                    continue;
+                };
+                if request["op"] == "login" {
+                    let login_code = login_codes[logins.min(login_codes.len() - 1)];
+                    logins += 1;
+                    let reply = serde_json::json!({"event": "login", "code": login_code, "msg": "", "connId": "a4d3ae55"});
+                    ws.send(Message::Text(reply.to_string().into()))
+                        .await
+                        .unwrap();
+                    continue;
+                }
+                if request.get("id").is_none() {
+                    continue;
+                }
+                match respond(request) {
+                    Some(replies) => {
+                        for reply in replies {
+                            ws.send(Message::Text(reply.to_string().into()))
+                                .await
+                                .unwrap();
+                        }
+                    }
+                    None => break,
+                }
+            }
+        });
+        url
+    }
+
+    fn private_client(url: String) -> OkxWebsocketClient {
+        let mut client = OkxWebsocketClient::new_private(Credentials::new(
+            "key",
+            "secret",
+            "passphrase",
+            Environment::Production,
+        ));
+        client.set_url(url);
+        client.set_time_sync_interval(None);
+        client
+    }
+
+    fn sample_order() -> OrderReqDto {
+        serde_json::from_value(serde_json::json!({
+            "instId": "BTC-USDT",
+            "tdMode": "cash",
+            "side": "buy",
+            "ordType": "limit",
+            "sz": "0.001",
+            "px": "20000"
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn place_order_resolves_matching_response() {
+        let url = serve_private(&["0"], |request| {
+            assert_eq!(request["op"], "order");
+            assert_eq!(request["args"][0]["instId"], "BTC-USDT");
+            let id = request["id"].as_str().unwrap().to_string();
+            Some(vec![
+                serde_json::json!({"arg": {"channel": "orders"}, "data": []}),
+                serde_json::json!({"id": "unrelated", "op": "order", "code": "0", "msg": "", "data": []}),
+                serde_json::json!({
+                    "id": id,
+                    "op": "order",
+                    "code": "0",
+                    "msg": "",
+                    "data": [{"ordId": "42", "clOrdId": "", "tag": "", "ts": "1", "sCode": "0", "sMsg": ""}],
+                    "inTime": "1695190491421339",
+                    "outTime": "1695190491423240"
+                }),
+            ])
+        })
+        .await;
+        let mut client = private_client(url);
+        let mut rx = client.connect().await.unwrap();
+
+        let result = client.place_order(sample_order()).await.unwrap();
+        assert_eq!(result[0].ord_id, "42");
+
+        // 与请求无关的推送仍然交给应用层
+        let pushed = rx.recv().await.unwrap();
+        assert_eq!(pushed["arg"]["channel"], "orders");
+        client.close().await;
+    }
+
+    #[test]
+    fn explicit_environment_selects_urls_without_global_config() {
+        let demo = Credentials::new("key", "secret", "passphrase", Environment::Demo);
+        let private = OkxWebsocketClient::new_private(demo);
+        assert_eq!(private.url, Environment::Demo.private_websocket_url());
+        assert_eq!(private.environment(), Environment::Demo);
+
+        let public = OkxWebsocketClient::new_public_with_environment(Environment::Aws);
+        assert_eq!(public.url, Environment::Aws.public_websocket_url());
+        assert!(!public.is_private);
+    }
+
+    #[tokio::test]
+    async fn connect_fails_when_login_is_rejected() {
+        let url = serve_private(&["60009"], |_| Some(Vec::new())).await;
+        let mut client = private_client(url);
+
+        let err = client.connect().await.unwrap_err();
+        assert!(
+            matches!(err, Error::WebSocketError(ref msg) if msg.contains("60009")),
+            "{:?}",
+            err
+        );
+        assert_eq!(client.get_connection_state(), ConnectionState::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn connect_retries_login_after_timestamp_error() {
+        let url = serve_private(&["60004", "0"], |_| Some(Vec::new())).await;
+        let mut client = private_client(url);
+
+        let _rx = client.connect().await.unwrap();
+        assert_eq!(client.get_connection_state(), ConnectionState::Connected);
+        client.close().await;
+    }
+
+    #[tokio::test]
+    async fn connect_retries_timestamp_error_only_once() {
+        let url = serve_private(&["60006"], |_| Some(Vec::new())).await;
+        let mut client = private_client(url);
+
+        let err = client.connect().await.unwrap_err();
+        assert!(is_login_timestamp_error(&err), "{:?}", err);
+        assert_eq!(client.get_connection_state(), ConnectionState::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn pending_order_fails_when_connection_drops() {
+        let url = serve_private(&["0"], |_| None).await;
+        let mut client = private_client(url);
+        let _rx = client.connect().await.unwrap();
+
+        let err = client.place_order(sample_order()).await.unwrap_err();
+        assert!(matches!(err, Error::ConnectionError(_)), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn order_request_times_out() {
+        let url = serve_private(&["0"], |_| Some(Vec::new())).await;
+        let mut client = private_client(url);
+        client.set_request_timeout(Duration::from_millis(100));
+        let _rx = client.connect().await.unwrap();
+
+        let err = client
+            .cancel_order(CancelOrderReqDto {
+                inst_id: "BTC-USDT".to_string(),
+                ord_id: Some("1".to_string()),
+                cl_ord_id: None,
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::TimeoutError(_)), "{:?}", err);
+        client.close().await;
+    }
+
     #[tokio::test]
     async fn test_unsubscribe() {
         dotenv::dotenv().ok();
         let api_key = env::var("OKX_API_KEY").expect("OKX_API_KEY 未设置");
         let api_secret = env::var("OKX_API_SECRET").expect("OKX_API_SECRET 未设置");
         let passphrase = env::var("OKX_PASSPHRASE").expect("OKX_PASSPHRASE 未设置");
-        let mut client =
-            OkxWebsocketClient::new_private(Credentials::new(api_key, api_secret, passphrase, "0"));
+        let mut client = OkxWebsocketClient::new_private(Credentials::new(
+            api_key,
+            api_secret,
+            passphrase,
+            Environment::Production,
+        ));
         let mut rx_private = client.connect().await.unwrap();
         let args = Args::new()
             .with_inst_id("BTC-USDT".to_string())
@@ -1,16 +1,19 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{mpsc, Mutex, RwLock};
+use serde::Deserialize;
 use serde_json::Value;
 use log::{debug, info, warn};
 
-use crate::config::Credentials;
+use crate::config::{global_config, Config, Credentials};
+use crate::credential_provider::CredentialStore;
 use crate::error::Error;
 use super::auto_reconnect_client::{AutoReconnectWebsocketClient, ConnectionState, ReconnectConfig};
 use super::channel::{Args, ChannelType};
 
 /// WebSocket连接管理器配置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
 pub struct ManagerConfig {
     /// 重连间隔（秒）
     pub reconnect_interval: u64,
@@ -62,18 +65,32 @@ pub struct OkxWebsocketManager {
 }
 
 impl OkxWebsocketManager {
-    /// 创建新的WebSocket管理器（仅公共频道）
-    pub fn new_public() -> Self {
+    /// 创建新的WebSocket管理器（仅公共频道），地址和环境取自全局 `CONFIG`，交易环境无效时返回错误
+    pub fn new_public() -> Result<Self, Error> {
         Self::new_with_config(None, ManagerConfig::default())
     }
 
-    /// 创建新的WebSocket管理器（包含私有频道）
+    /// 创建新的WebSocket管理器（包含私有频道），所有频道使用凭证所属环境的地址
     pub fn new_with_credentials(credentials: Credentials) -> Self {
-        Self::new_with_config(Some(credentials), ManagerConfig::default())
+        let urls = Config::for_environment(credentials.environment);
+        Self::with_urls(urls, Some(credentials), ManagerConfig::default())
     }
 
     /// 使用自定义配置创建WebSocket管理器
-    pub fn new_with_config(credentials: Option<Credentials>, config: ManagerConfig) -> Self {
+    ///
+    /// 有凭证时所有频道使用凭证所属环境的地址，没有凭证时使用全局 `CONFIG`，交易环境无效时返回错误
+    pub fn new_with_config(
+        credentials: Option<Credentials>,
+        config: ManagerConfig,
+    ) -> Result<Self, Error> {
+        let urls = match &credentials {
+            Some(credentials) => Config::for_environment(credentials.environment),
+            None => global_config()?,
+        };
+        Ok(Self::with_urls(urls, credentials, config))
+    }
+
+    fn with_urls(urls: Config, credentials: Option<Credentials>, config: ManagerConfig) -> Self {
         // 转换配置
         let reconnect_config = ReconnectConfig {
             enabled: config.auto_reconnect,
@@ -84,19 +101,35 @@ impl OkxWebsocketManager {
             heartbeat_interval: config.heartbeat_interval,
             message_timeout: config.message_timeout,
         };
+        let environment = urls.environment;
 
         let public_client = Arc::new(Mutex::new(
-            AutoReconnectWebsocketClient::new_public()
+            AutoReconnectWebsocketClient::new_with_credential_store(
+                &urls.websocket_url,
+                environment,
+                None,
+                reconnect_config.clone(),
+            ),
         ));
 
         let private_client = credentials.clone().map(|creds| {
             Arc::new(Mutex::new(
-                AutoReconnectWebsocketClient::new_private(creds)
+                AutoReconnectWebsocketClient::new_with_credential_store(
+                    &urls.private_websocket_url,
+                    environment,
+                    Some(CredentialStore::fixed(creds)),
+                    reconnect_config.clone(),
+                ),
             ))
         });
         let business_client = credentials.clone().map(|creds| {
             Arc::new(Mutex::new(
-                AutoReconnectWebsocketClient::new_business(creds)
+                AutoReconnectWebsocketClient::new_with_credential_store(
+                    &urls.business_websocket_url,
+                    environment,
+                    Some(CredentialStore::fixed(creds)),
+                    reconnect_config.clone(),
+                ),
             ))
         });
 
@@ -146,6 +179,9 @@ impl OkxWebsocketManager {
         if let Some(private_client) = &self.private_client {
             private_client.lock().await.stop().await;
         }
+        if let Some(business_client) = &self.business_client {
+            business_client.lock().await.stop().await;
+        }
 
         info!("WebSocket管理器已停止");
     }
@@ -196,6 +232,11 @@ impl OkxWebsocketManager {
         Ok(())
     }
 
+    /// 获取管理器配置
+    pub fn config(&self) -> &ManagerConfig {
+        &self.config
+    }
+
     /// 获取连接状态
     pub async fn get_connection_status(&self) -> (ConnectionState, Option<ConnectionState>) {
         let public_state = self.public_client.lock().await.get_connection_state();
@@ -3,11 +3,12 @@ pub mod client;
 pub mod auto_reconnect_client;
 pub mod manager;
 mod models;
+mod pending;
 
 pub use channel::{Args, ChannelType};
 pub use client::OkxWebsocketClient;
 pub use manager::OkxWebsocketManager;
 pub use models::{
-    WebSocketAuth, WebSocketChannel, WebSocketMessage, WebSocketOperation, WebSocketRequest,
-    WebSocketResponse, WebSocketSubscription,
+    WebSocketAuth, WebSocketChannel, WebSocketMessage, WebSocketOpRequest, WebSocketOpResponse,
+    WebSocketOperation, WebSocketRequest, WebSocketResponse, WebSocketSubscription,
 };
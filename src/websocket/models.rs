@@ -1,3 +1,4 @@
+use crate::secret::SecretString;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -11,6 +12,30 @@ pub enum WebSocketOperation {
     Unsubscribe,
     /// 登录
     Login,
+    /// 下单
+    Order,
+    /// 批量下单
+    #[serde(rename = "batch-orders")]
+    BatchOrders,
+    /// 撤单
+    #[serde(rename = "cancel-order")]
+    CancelOrder,
+    /// 修改订单
+    #[serde(rename = "amend-order")]
+    AmendOrder,
+}
+
+impl WebSocketOperation {
+    /// 是否为需要等待响应的交易操作
+    pub fn is_trade(&self) -> bool {
+        matches!(
+            self,
+            WebSocketOperation::Order
+                | WebSocketOperation::BatchOrders
+                | WebSocketOperation::CancelOrder
+                | WebSocketOperation::AmendOrder
+        )
+    }
 }
 
 /// WebSocket认证信息
@@ -20,11 +45,11 @@ pub struct WebSocketAuth {
     #[serde(rename = "apiKey")]
     pub api_key: String,
     /// 签名
-    pub sign: String,
+    pub sign: SecretString,
     /// 时间戳
     pub timestamp: String,
     /// 密码
-    pub passphrase: String,
+    pub passphrase: SecretString,
 }
 
 /// WebSocket通道
@@ -62,6 +87,43 @@ pub struct WebSocketRequest {
     pub args: Vec<WebSocketSubscription>,
 }
 
+/// WebSocket交易请求
+///
+/// `id` 由客户端生成，OKX在响应中原样返回，用于把响应和请求对应起来。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebSocketOpRequest<T> {
+    /// 消息的唯一标识
+    pub id: String,
+    /// 操作类型
+    pub op: WebSocketOperation,
+    /// 请求参数
+    pub args: Vec<T>,
+}
+
+/// WebSocket交易响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebSocketOpResponse {
+    /// 请求中的消息标识
+    pub id: String,
+    /// 操作类型
+    pub op: WebSocketOperation,
+    /// 结果代码，0代表成功，1全部失败，2部分成功
+    pub code: String,
+    /// 错误信息
+    #[serde(default)]
+    pub msg: String,
+    /// 每一项操作的结果
+    #[serde(default)]
+    pub data: serde_json::Value,
+    /// WebSocket网关接收请求时的时间戳，Unix时间戳的微秒数格式
+    #[serde(default)]
+    pub in_time: Option<String>,
+    /// WebSocket网关发送响应时的时间戳，Unix时间戳的微秒数格式
+    #[serde(default)]
+    pub out_time: Option<String>,
+}
+
 /// WebSocket登录请求
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WebSocketLoginRequest {
@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::sync::oneshot;
+
+use crate::dto::common::{BatchItem, BatchResult};
+use crate::error::Error;
+use crate::websocket::models::{WebSocketOpRequest, WebSocketOpResponse, WebSocketOperation};
+
+/// 交易请求的默认超时时间
+pub(crate) const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+type Waiter = oneshot::Sender<Result<WebSocketOpResponse, Error>>;
+pub(crate) type ResponseReceiver = oneshot::Receiver<Result<WebSocketOpResponse, Error>>;
+
+/// 等待响应的WebSocket交易请求
+///
+/// 按请求 `id` 把收到的响应交给对应的调用方，连接断开时所有未完成的请求立即失败。克隆后共享同一份状态。
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PendingRequests {
+    next_id: Arc<AtomicU64>,
+    waiters: Arc<Mutex<HashMap<String, Waiter>>>,
+}
+
+impl PendingRequests {
+    /// 生成请求消息并登记等待响应，返回请求 `id`、序列化后的消息和响应接收端
+    pub(crate) fn prepare<T: Serialize>(
+        &self,
+        op: WebSocketOperation,
+        args: Vec<T>,
+    ) -> Result<(String, String, ResponseReceiver), Error> {
+        let id = (self.next_id.fetch_add(1, Ordering::Relaxed) + 1).to_string();
+        let message = serde_json::to_string(&WebSocketOpRequest {
+            id: id.clone(),
+            op,
+            args,
+        })
+        .map_err(Error::JsonError)?;
+        let (tx, rx) = oneshot::channel();
+        self.lock().insert(id.clone(), tx);
+        Ok((id, message, rx))
+    }
+
+    /// 取消等待
+    pub(crate) fn cancel(&self, id: &str) {
+        self.lock().remove(id);
+    }
+
+    /// 等待响应，超时或连接断开时返回错误
+    pub(crate) async fn wait(
+        &self,
+        id: &str,
+        rx: ResponseReceiver,
+        timeout: Duration,
+    ) -> Result<WebSocketOpResponse, Error> {
+        match tokio::time::timeout(timeout, rx).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(_)) => Err(Error::ConnectionError(format!(
+                "WebSocket请求 {} 未收到响应，连接已关闭",
+                id
+            ))),
+            Err(_) => {
+                self.cancel(id);
+                Err(Error::TimeoutError(format!(
+                    "WebSocket请求 {} 在{}ms内未收到响应",
+                    id,
+                    timeout.as_millis()
+                )))
+            }
+        }
+    }
+
+    /// 把收到的消息交给等待中的请求，返回消息是否为某个请求的响应
+    ///
+    /// OKX对格式错误的请求返回不带 `id` 的 `error` 事件，此时让最早发出的请求失败，
+    /// 事件本身仍交给应用层（订阅失败也使用同样的格式）。
+    pub(crate) fn resolve(&self, message: &Value) -> bool {
+        let Some(id) = message.get("id").and_then(Value::as_str) else {
+            if message.get("event").and_then(Value::as_str) == Some("error") {
+                self.fail_oldest(message);
+            }
+            return false;
+        };
+        let Some(waiter) = self.lock().remove(id) else {
+            return false;
+        };
+        let result = serde_json::from_value::<WebSocketOpResponse>(message.clone())
+            .map_err(Error::JsonError);
+        let _ = waiter.send(result);
+        true
+    }
+
+    /// 以 `error` 事件中的 `code` / `msg` 让最早发出的请求失败
+    fn fail_oldest(&self, message: &Value) {
+        let waiter = {
+            let mut waiters = self.lock();
+            let oldest = waiters
+                .keys()
+                .min_by_key(|id| id.parse::<u64>().unwrap_or(u64::MAX))
+                .cloned();
+            oldest.and_then(|id| waiters.remove(&id))
+        };
+        if let Some(waiter) = waiter {
+            let field = |name: &str| message[name].as_str().unwrap_or_default().to_string();
+            let _ = waiter.send(Err(Error::from_okx_response(
+                field("code"),
+                field("msg"),
+                &Value::Null,
+            )));
+        }
+    }
+
+    /// 连接断开时让所有未完成的请求失败，订单状态需要通过REST或订单频道确认
+    pub(crate) fn fail_all(&self, reason: &str) {
+        let waiters: Vec<(String, Waiter)> = self.lock().drain().collect();
+        for (id, waiter) in waiters {
+            let _ = waiter.send(Err(Error::ConnectionError(format!(
+                "WebSocket请求 {} 未收到响应，{}",
+                id, reason
+            ))));
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Waiter>> {
+        self.waiters.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// 把单笔操作的响应转换为与REST接口相同的结果，失败时返回带有 `sCode` 的 `OkxApiError`
+pub(crate) fn into_data<T: DeserializeOwned>(
+    response: WebSocketOpResponse,
+) -> Result<Vec<T>, Error> {
+    if response.code != "0" {
+        return Err(Error::from_okx_response(
+            response.code,
+            response.msg,
+            &response.data,
+        ));
+    }
+    serde_json::from_value(response.data).map_err(Error::JsonError)
+}
+
+/// 把批量操作的响应按 `sCode` 拆分为成功项和失败项
+pub(crate) fn into_batch<T: DeserializeOwned + BatchItem>(
+    response: WebSocketOpResponse,
+) -> Result<BatchResult<T>, Error> {
+    let is_batch_result = (response.code == "1" || response.code == "2")
+        && response.data.as_array().is_some_and(|items| {
+            !items.is_empty() && items.iter().all(|item| item.get("sCode").is_some())
+        });
+    if response.code != "0" && !is_batch_result {
+        return Err(Error::from_okx_response(
+            response.code,
+            response.msg,
+            &response.data,
+        ));
+    }
+    let items = serde_json::from_value(response.data).map_err(Error::JsonError)?;
+    Ok(BatchResult::new(response.code, response.msg, items))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::trade_dto::OrderResDto;
+    use crate::error::ApiErrorCode;
+
+    #[tokio::test]
+    async fn resolves_responses_by_id() {
+        let pending = PendingRequests::default();
+        let (id, message, rx) = pending
+            .prepare(
+                WebSocketOperation::Order,
+                vec![serde_json::json!({"instId": "BTC-USDT"})],
+            )
+            .unwrap();
+        assert_eq!(
+            message,
+            format!(
+                r#"{{"id":"{}","op":"order","args":[{{"instId":"BTC-USDT"}}]}}"#,
+                id
+            )
+        );
+
+        assert!(!pending.resolve(&serde_json::json!({"id": "other", "op": "order"})));
+        assert!(pending.resolve(&serde_json::json!({
+            "id": id,
+            "op": "order",
+            "code": "1",
+            "msg": "",
+            "data": [{"ordId": "", "clOrdId": "", "tag": "", "ts": "1", "sCode": "51008", "sMsg": "Insufficient balance"}]
+        })));
+
+        let response = pending
+            .wait(&id, rx, DEFAULT_REQUEST_TIMEOUT)
+            .await
+            .unwrap();
+        let err = into_data::<OrderResDto>(response).unwrap_err();
+        assert_eq!(
+            err.item_errors()[0].code,
+            ApiErrorCode::OrderFailedAccountBalanceNotEnough
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_pending_requests_on_disconnect_and_timeout() {
+        let pending = PendingRequests::default();
+        let (id, _, rx) = pending
+            .prepare(WebSocketOperation::CancelOrder, Vec::<Value>::new())
+            .unwrap();
+        pending.fail_all("连接已断开");
+        assert!(matches!(
+            pending.wait(&id, rx, DEFAULT_REQUEST_TIMEOUT).await,
+            Err(Error::ConnectionError(_))
+        ));
+
+        let (id, _, rx) = pending
+            .prepare(WebSocketOperation::AmendOrder, Vec::<Value>::new())
+            .unwrap();
+        assert!(matches!(
+            pending.wait(&id, rx, Duration::from_millis(10)).await,
+            Err(Error::TimeoutError(_))
+        ));
+        assert!(!pending.resolve(&serde_json::json!({"id": id})));
+    }
+
+    #[tokio::test]
+    async fn error_event_without_id_fails_oldest_request() {
+        let pending = PendingRequests::default();
+        let (first, _, first_rx) = pending
+            .prepare(WebSocketOperation::Order, Vec::<Value>::new())
+            .unwrap();
+        let (second, _, second_rx) = pending
+            .prepare(WebSocketOperation::Order, Vec::<Value>::new())
+            .unwrap();
+
+        let event = serde_json::json!({"event": "error", "code": "60013", "msg": "Invalid args", "connId": "a4d3ae55"});
+        assert!(!pending.resolve(&event));
+
+        match pending
+            .wait(&first, first_rx, DEFAULT_REQUEST_TIMEOUT)
+            .await
+        {
+            Err(Error::OkxApiError { code, message, .. }) => {
+                assert_eq!(code, "60013");
+                assert_eq!(message, "Invalid args");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(matches!(
+            pending
+                .wait(&second, second_rx, Duration::from_millis(10))
+                .await,
+            Err(Error::TimeoutError(_))
+        ));
+    }
+}